@@ -0,0 +1,17 @@
+//! Compile time counterpart of `compile_tree_200`, which builds and draws the screen of 200 components
+//! represented as a queue; see `compile_tree_200` for the procedure to compare their compile times.
+
+#![recursion_limit = "256"]
+
+#[path = "queue_draw_helpers/st_queue_components.rs"]
+mod st_queue_components;
+#[path = "queue_draw_helpers/st_queue_screen.rs"]
+mod st_queue_screen;
+
+use st_queue_components::Draw;
+
+fn main() {
+    let (n, screen) = st_queue_screen::new_screen_200();
+    assert_eq!(n, 200);
+    std::hint::black_box(&screen).draw();
+}
@@ -0,0 +1,28 @@
+//! Compile time comparison of the queue and tree representations of the screen of 200 components
+//! used in the `queue_draw_200` benchmark.
+//!
+//! `compile_queue_200` and `compile_tree_200` contain nothing but the components and the screen in
+//! the corresponding representation. Their compile times can be compared as follows:
+//!
+//! ```bash
+//! cargo build --release --benches
+//! touch benches/compile_queue_200.rs benches/compile_tree_200.rs
+//! cargo build --release --bench compile_queue_200 --bench compile_tree_200 --timings -j 1
+//! ```
+//!
+//! The report written to `target/cargo-timings/cargo-timing.html` lists the build duration of each
+//! bench target. Notice also that the queue representation requires increasing the recursion limit,
+//! while the tree representation compiles with the default limit.
+
+#[path = "queue_draw_helpers/st_queue_components.rs"]
+mod st_queue_components;
+#[path = "queue_draw_helpers/st_tree_screen.rs"]
+mod st_tree_screen;
+
+use st_queue_components::Draw;
+
+fn main() {
+    let (n, screen) = st_tree_screen::new_screen_200();
+    assert_eq!(n, 200);
+    std::hint::black_box(&screen).draw();
+}
@@ -7,11 +7,13 @@ use queue_draw_helpers::*;
 
 fn run(c: &mut Criterion) {
     let (n, screen_as_st_queue) = st_queue_screen::new_screen_10();
+    let (n_tree, screen_as_st_tree) = st_tree_screen::new_screen_10();
     let screen_as_trait_objects = trait_objects_screen::new_screen_10();
     let screen_as_enums = enum_screen::new_screen_10();
 
     assert_eq!(screen_as_trait_objects.len(), n);
     assert_eq!(screen_as_enums.len(), n);
+    assert_eq!(n_tree, n);
     assert_eq!(n, 10);
 
     let mut group = c.benchmark_group("queue_draw_10");
@@ -21,6 +23,11 @@ fn run(c: &mut Criterion) {
         b.iter(|| screen_as_st_queue.draw())
     });
 
+    group.bench_with_input(BenchmarkId::new("st_tree_screen", n), &n, |b, _| {
+        use st_queue_components::Draw;
+        b.iter(|| screen_as_st_tree.draw())
+    });
+
     group.bench_with_input(BenchmarkId::new("trait_objects_screen", n), &n, |b, _| {
         b.iter(|| {
             for component in &screen_as_trait_objects {
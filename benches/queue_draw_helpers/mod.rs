@@ -2,5 +2,6 @@ pub mod enum_components;
 pub mod enum_screen;
 pub mod st_queue_components;
 pub mod st_queue_screen;
+pub mod st_tree_screen;
 pub mod trait_objects_components;
 pub mod trait_objects_screen;
@@ -0,0 +1,41 @@
+/// Creates the coproduct type with the given comma-separated variant types.
+///
+/// Recall that there exist two statically-typed coproduct (`StCoproduct`) implementations:
+///
+/// * `CoproductSingle` with exactly one variant, and
+/// * `Coproduct` with multiple (>=2) variants.
+///
+/// Coproducts of all lengths can be represented by these two types:
+/// * `CoproductSingle<T1>` is a coproduct with one variant,
+/// * `Coproduct<T1, CoproductSingle<T2>>` with two variants,
+/// * `Coproduct<T1, Coproduct<T2, CoproductSingle<T3>>>` with three variants,
+/// * and so on, so forth.
+///
+/// `coproduct_of` macro is a helper macro to make such type aliasing convenient.
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::coproduct::*;
+/// use orx_meta::coproduct_of;
+///
+/// // written with recursive type definition
+/// type X1 = Coproduct<i32, Coproduct<bool, CoproductSingle<char>>>;
+///
+/// // alternatively, using with coproduct_of macro as a flat list
+/// type X2 = coproduct_of!(i32, bool, char);
+///
+/// // notice that X1 and X2 are aliases for the same type
+/// let x: X1 = Coproduct::inject(true);
+/// let x: X2 = x;
+/// ```
+#[macro_export]
+macro_rules! coproduct_of {
+    ($t:ty $(,)?) => {
+        $crate::coproduct::CoproductSingle<$t>
+    };
+
+    ($t:ty, $($rest:ty),+ $(,)?) => {
+        $crate::coproduct::Coproduct<$t, $crate::coproduct_of!($($rest),+)>
+    };
+}
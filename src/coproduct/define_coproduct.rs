@@ -0,0 +1,846 @@
+/// Defines statically-typed coproducts, or sum types, of heterogeneous variants all of which
+/// implement a common set of traits.
+///
+/// [orx_meta](https://crates.io/crates/orx-meta) crate defines statically-typed coproducts with
+/// heterogeneous variants; see [`StCoproduct`], [`CoproductSingle`] and [`Coproduct`]. Further, it
+/// provides the [`coproduct_of`] macro for conveniently aliasing coproduct types.
+///
+/// The `define_coproduct` macro re-creates these types with the addition that we can limit the variant
+/// types by a custom trait or traits. This is the counterpart of the [`define_queue`] macro:
+/// * a queue holds one value of each of its element types, and composes their behavior;
+/// * a coproduct holds exactly one value of one of its variant types, and dispatches to its behavior.
+///
+/// The macro contains the following blocks, which can be provided in any order:
+/// * `lt` (optional) is the list of lifetimes, and `generics` (optional) is the list of generic
+///   parameters with their `|` separated bounds, which can be used in the `elements` bounds. They
+///   become the parameters of the coproduct trait, such as `StComponent<'a, T>`; and in this case, the
+///   `elements` bounds are required by the implementations of the coproduct trait rather than by the
+///   variant types.
+/// * `elements` (optional) is the `|` separated list of traits that all variants must implement,
+///   such as `Draw | core::fmt::Debug`. Coproduct types are required to implement these traits as well.
+/// * `coproduct` is giving names to (i) the coproduct trait, (ii) the single-variant coproduct struct and
+///   (iii) the multiple-variant coproduct enum with variants `Inl` and `Inr`.
+/// * `coproduct_of` (optional) is the name of the macro to alias coproduct types with up to 16 variants.
+///
+/// Variants of the defined coproducts are injected and accessed by their types through the [`Variants`]
+/// extension trait, such as `Component::inject(x)` and `component.get::<T>()`.
+///
+/// [`StCoproduct`]: crate::coproduct::StCoproduct
+/// [`CoproductSingle`]: crate::coproduct::CoproductSingle
+/// [`Coproduct`]: crate::coproduct::Coproduct
+/// [`coproduct_of`]: crate::coproduct_of
+/// [`define_queue`]: crate::define_queue
+/// [`Variants`]: crate::coproduct::Variants
+///
+/// # Example - Dispatch
+///
+/// In the following example, all variants implement the `Draw` trait. Since there are two
+/// implementations of the coproduct, we implement `Draw` for these two types:
+/// * Single-variant coproduct: it always holds a value of its only variant, and hence, it exhibits the
+///   behavior of that value. This is the **identity**.
+/// * Multiple-variant coproduct: it holds either the head variant or one of the remaining variants.
+///   We dispatch the call to the active one. This is the **dispatch**.
+///
+/// Then, any coproduct of this family can be used as a `Draw`, such as an enum of components only one
+/// of which is active.
+///
+/// ```
+/// use orx_meta::coproduct::Variants;
+///
+/// pub trait Draw {
+///     fn draw(&self) -> String;
+/// }
+///
+/// pub struct Button(&'static str);
+/// impl Draw for Button {
+///     fn draw(&self) -> String {
+///         format!("button:{}", self.0)
+///     }
+/// }
+///
+/// pub struct Label(&'static str);
+/// impl Draw for Label {
+///     fn draw(&self) -> String {
+///         format!("label:{}", self.0)
+///     }
+/// }
+///
+/// pub struct CheckBox(bool);
+/// impl Draw for CheckBox {
+///     fn draw(&self) -> String {
+///         format!("checkbox:{}", self.0)
+///     }
+/// }
+///
+/// orx_meta::define_coproduct!(
+///     elements => [ Draw ];
+///     coproduct => [ StComponent ; ComponentSingle, Component ];
+///     coproduct_of => component_of;
+/// );
+///
+/// // identity
+/// impl<F: Draw> Draw for ComponentSingle<F> {
+///     fn draw(&self) -> String {
+///         self.0.draw()
+///     }
+/// }
+///
+/// // dispatch
+/// impl<F: Draw, R: StComponent> Draw for Component<F, R> {
+///     fn draw(&self) -> String {
+///         match self {
+///             Self::Inl(x) => x.draw(),
+///             Self::Inr(r) => r.draw(),
+///         }
+///     }
+/// }
+///
+/// type AnyComponent = component_of!(Button, Label, CheckBox);
+///
+/// let components: Vec<AnyComponent> = vec![
+///     Component::inject(Button("home")),
+///     Component::inject(CheckBox(true)),
+///     Component::inject(Label("hello")),
+/// ];
+///
+/// let drawn: Vec<_> = components.iter().map(|x| x.draw()).collect();
+/// assert_eq!(drawn, ["button:home", "checkbox:true", "label:hello"]);
+///
+/// assert!(components[2].get::<Label>().is_some());
+/// assert!(components[2].get::<Button>().is_none());
+/// ```
+///
+/// Variants which do not implement the required traits cannot be included in the coproduct.
+///
+/// ```compile_fail
+/// use orx_meta::coproduct::Variants;
+///
+/// pub trait Draw {
+///     fn draw(&self) -> String;
+/// }
+///
+/// orx_meta::define_coproduct!(
+///     elements => [ Draw ];
+///     coproduct => [ StComponent ; ComponentSingle, Component ];
+///     coproduct_of => component_of;
+/// );
+///
+/// type AnyComponent = component_of!(u32, char); // u32 and char do not implement Draw
+/// let x: AnyComponent = Component::inject('x');
+/// ```
+#[macro_export]
+macro_rules! define_coproduct {
+    ($($options:tt)*) => {
+        $crate::define_coproduct_impl!(
+            @parse
+            lt [] generics [] elements [] coproduct [] coproduct_of []
+            $($options)*
+        );
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! define_coproduct_impl {
+    // options are parsed independently of each other and their order
+
+    (
+        @parse
+        lt []
+        generics [$($generics:tt)*]
+        elements [$($elements:tt)*]
+        coproduct [$($coproduct:tt)*]
+        coproduct_of [$($coproduct_of:tt)*]
+        lt => [$($value:tt)*]; $($rest:tt)*
+    ) => {
+        $crate::define_coproduct_impl!(
+            @parse
+            lt [$($value)*]
+            generics [$($generics)*]
+            elements [$($elements)*]
+            coproduct [$($coproduct)*]
+            coproduct_of [$($coproduct_of)*]
+            $($rest)*
+        );
+    };
+
+    (
+        @parse
+        lt [$($lt:tt)*]
+        generics []
+        elements [$($elements:tt)*]
+        coproduct [$($coproduct:tt)*]
+        coproduct_of [$($coproduct_of:tt)*]
+        generics => [$($value:tt)*]; $($rest:tt)*
+    ) => {
+        $crate::define_coproduct_impl!(
+            @parse
+            lt [$($lt)*]
+            generics [$($value)*]
+            elements [$($elements)*]
+            coproduct [$($coproduct)*]
+            coproduct_of [$($coproduct_of)*]
+            $($rest)*
+        );
+    };
+
+    (
+        @parse
+        lt [$($lt:tt)*]
+        generics [$($generics:tt)*]
+        elements []
+        coproduct [$($coproduct:tt)*]
+        coproduct_of [$($coproduct_of:tt)*]
+        elements => [$($value:tt)*]; $($rest:tt)*
+    ) => {
+        $crate::define_coproduct_impl!(
+            @parse
+            lt [$($lt)*]
+            generics [$($generics)*]
+            elements [$($value)*]
+            coproduct [$($coproduct)*]
+            coproduct_of [$($coproduct_of)*]
+            $($rest)*
+        );
+    };
+
+    (
+        @parse
+        lt [$($lt:tt)*]
+        generics [$($generics:tt)*]
+        elements [$($elements:tt)*]
+        coproduct []
+        coproduct_of [$($coproduct_of:tt)*]
+        coproduct => [$($value:tt)*]; $($rest:tt)*
+    ) => {
+        $crate::define_coproduct_impl!(
+            @parse
+            lt [$($lt)*]
+            generics [$($generics)*]
+            elements [$($elements)*]
+            coproduct [$($value)*]
+            coproduct_of [$($coproduct_of)*]
+            $($rest)*
+        );
+    };
+
+    (
+        @parse
+        lt [$($lt:tt)*]
+        generics [$($generics:tt)*]
+        elements [$($elements:tt)*]
+        coproduct [$($coproduct:tt)*]
+        coproduct_of []
+        coproduct_of => $value:ident; $($rest:tt)*
+    ) => {
+        $crate::define_coproduct_impl!(
+            @parse
+            lt [$($lt)*]
+            generics [$($generics)*]
+            elements [$($elements)*]
+            coproduct [$($coproduct)*]
+            coproduct_of [$value]
+            $($rest)*
+        );
+    };
+
+    (
+        @parse
+        lt [$($lt:tt)*]
+        generics [$($generics:tt)*]
+        elements [$($elements:tt)*]
+        coproduct [$($coproduct:tt)*]
+        coproduct_of [$($coproduct_of:tt)*]
+        $option:ident => $($rest:tt)*
+    ) => {
+        core::compile_error!(core::concat!(
+            "`define_coproduct!` option `",
+            core::stringify!($option),
+            "` is either unknown or provided more than once"
+        ));
+    };
+
+    (
+        @parse
+        lt [$($lt:tt)*]
+        generics [$($generics:tt)*]
+        elements [$($elements:tt)*]
+        coproduct []
+        coproduct_of [$($coproduct_of:tt)*]
+    ) => {
+        core::compile_error!("`define_coproduct!` requires the `coproduct => [Trait ; Single, Multi];` option");
+    };
+
+    (
+        @parse
+        lt [$($lt:tt)*]
+        generics [$($generics:tt)*]
+        elements [$($elements:tt)*]
+        coproduct [$($coproduct:tt)*]
+        coproduct_of [$($coproduct_of:tt)*]
+    ) => {
+        $crate::define_coproduct_impl!(
+            @elements [] [$($elements)*]
+            lt => [$($lt)*];
+            generics => [$($generics)*];
+            coproduct => [$($coproduct)*];
+            coproduct_of => [$($coproduct_of)*];
+        );
+    };
+
+    // elements: `|` separated traits are converted to `+` separated bounds
+
+    (@elements [] [| $($el_bnd:tt)*] $($rest:tt)*) => {
+        $crate::define_coproduct_impl!(@elements [] [$($el_bnd)*] $($rest)*);
+    };
+
+    (@elements [$($acc:tt)*] [| $($el_bnd:tt)*] $($rest:tt)*) => {
+        $crate::define_coproduct_impl!(@elements [$($acc)* +] [$($el_bnd)*] $($rest)*);
+    };
+
+    (@elements [$($acc:tt)*] [$next:tt $($el_bnd:tt)*] $($rest:tt)*) => {
+        $crate::define_coproduct_impl!(@elements [$($acc)* $next] [$($el_bnd)*] $($rest)*);
+    };
+
+    // without lifetimes and generics, the variant types are bounded by the elements as well;
+    // otherwise, the bounds are required by the implementations of the coproduct trait
+
+    (
+        @elements [$($acc:tt)*] []
+        lt => [];
+        generics => [];
+        coproduct => [$c:ident ; $($coproduct:tt)*];
+        coproduct_of => [$($coproduct_of:tt)*];
+    ) => {
+        $crate::define_coproduct_impl!(
+            lt => [];
+            generics => [];
+            elements => [$($acc)*];
+            variant_bounds => [$($acc)*];
+            tail_bound => [$c];
+            coproduct => [$c ; $($coproduct)*];
+            coproduct_of => [$($coproduct_of)*];
+        );
+    };
+
+    (
+        @elements [$($acc:tt)*] []
+        lt => [$($lt:tt)*];
+        generics => [$($generics:tt)*];
+        coproduct => [$($coproduct:tt)*];
+        coproduct_of => [$($coproduct_of:tt)*];
+    ) => {
+        $crate::define_coproduct_impl!(
+            lt => [$($lt)*];
+            generics => [$($generics)*];
+            elements => [$($acc)*];
+            variant_bounds => [];
+            tail_bound => [$crate::coproduct::StCoproduct];
+            coproduct => [$($coproduct)*];
+            coproduct_of => [$($coproduct_of)*];
+        );
+    };
+
+    (
+        lt => [$($g_lt:tt), *];
+        generics => [ $( $g:tt $( : $( $g_bnd:ident $( < $( $g_bnd_g:tt ),* > )? )| * )? ), * ];
+        elements => [ $($el_bnd:tt)* ];
+        variant_bounds => [ $($v_bnd:tt)* ];
+        tail_bound => [ $($tail:tt)* ];
+        coproduct => [$c:ident ; $single:ident, $pair:ident $(,)?];
+        coproduct_of => [$($coproduct_of:ident)?];
+    ) => {
+        $crate::define_coproduct_core!(
+            lt => [$($g_lt), *];
+            generics => [ $( $g $( : $( $g_bnd $( < $( $g_bnd_g ),* > )? )| * )? ), * ];
+            elements => [ $($el_bnd)* ];
+            variant_bounds => [ $($v_bnd)* ];
+            tail_bound => [ $($tail)* ];
+            coproduct => [$c ; $single, $pair];
+        );
+
+        $( $crate::define_coproduct_of!(
+            coproduct => [$c ; $single, $pair];
+            coproduct_of => $coproduct_of;
+        ); )?
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! define_coproduct_core {
+    (
+        lt => [$($g_lt:tt), *];
+        generics => [ $( $g:tt $( : $( $g_bnd:ident $( < $( $g_bnd_g:tt ),* > )? )| * )? ), * ];
+        elements => [ $($el_bnd:tt)* ];
+        variant_bounds => [ $($v_bnd:tt)* ];
+        tail_bound => [ $($tail:tt)* ];
+        coproduct => [$c:ident ; $single:ident, $pair:ident];
+    ) => {
+        /// A strongly typed non-empty coproduct, or sum type, of heterogeneous variants.
+        ///
+        /// There exist two implementations:
+        /// * the single-variant coproduct which always holds a value of its only variant, and
+        /// * the multiple-variant coproduct which holds either its head variant `Inl` or one of the
+        ///   variants of its tail coproduct `Inr`.
+        #[allow(dead_code)]
+        pub trait $c<$($g_lt ,)* $($g ,)*>: $crate::coproduct::StCoproduct
+        where
+            Self: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )*
+        {
+        }
+
+        // # single
+
+        /// A statically-typed coproduct with exactly one variant of type `T`.
+        #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+        pub struct $single<T>(pub T)
+        where
+            T: $($v_bnd)*;
+
+        impl<T> $crate::coproduct::StCoproduct for $single<T>
+        where
+            T: $($v_bnd)*,
+        {
+            const LEN: usize = 1;
+
+            #[inline(always)]
+            fn index(&self) -> usize {
+                0
+            }
+        }
+
+        impl<$($g_lt ,)* $($g ,)* T> $c<$($g_lt ,)* $($g ,)*> for $single<T>
+        where
+            T: $($el_bnd)*,
+            Self: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )*
+        {
+        }
+
+        impl<T> $crate::coproduct::CoproductMember<T, $crate::type_list::Here> for $single<T>
+        where
+            T: $($v_bnd)*,
+        {
+            #[inline(always)]
+            fn from_variant(value: T) -> Self {
+                Self(value)
+            }
+
+            #[inline(always)]
+            fn variant(&self) -> Option<&T> {
+                Some(&self.0)
+            }
+
+            #[inline(always)]
+            fn variant_mut(&mut self) -> Option<&mut T> {
+                Some(&mut self.0)
+            }
+
+            #[inline(always)]
+            fn into_variant(self) -> Option<T> {
+                Some(self.0)
+            }
+        }
+
+        // # pair
+
+        /// A statically-typed coproduct with multiple (>=2) variants which is either `Inl(Head)`
+        /// holding a value of the first variant type, or `Inr(Tail)` holding the coproduct of the
+        /// remaining variant types.
+        #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+        pub enum $pair<Head, Tail>
+        where
+            Head: $($v_bnd)*,
+            Tail: $($tail)*,
+        {
+            /// The coproduct holds a value of the first variant type `Head`.
+            Inl(Head),
+            /// The coproduct holds a value of one of the remaining variant types of the coproduct `Tail`.
+            Inr(Tail),
+        }
+
+        impl<H, R> $crate::coproduct::StCoproduct for $pair<H, R>
+        where
+            H: $($v_bnd)*,
+            R: $($tail)*,
+        {
+            const LEN: usize = 1 + R::LEN;
+
+            #[inline(always)]
+            fn index(&self) -> usize {
+                match self {
+                    Self::Inl(_) => 0,
+                    Self::Inr(r) => 1 + r.index(),
+                }
+            }
+        }
+
+        impl<$($g_lt ,)* $($g ,)* H, R> $c<$($g_lt ,)* $($g ,)*> for $pair<H, R>
+        where
+            H: $($el_bnd)*,
+            R: $c<$($g_lt ,)* $($g ,)*>,
+            Self: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )*
+        {
+        }
+
+        impl<T, R> $crate::coproduct::CoproductMember<T, $crate::type_list::Here> for $pair<T, R>
+        where
+            T: $($v_bnd)*,
+            R: $($tail)*,
+        {
+            #[inline(always)]
+            fn from_variant(value: T) -> Self {
+                Self::Inl(value)
+            }
+
+            #[inline(always)]
+            fn variant(&self) -> Option<&T> {
+                match self {
+                    Self::Inl(x) => Some(x),
+                    Self::Inr(_) => None,
+                }
+            }
+
+            #[inline(always)]
+            fn variant_mut(&mut self) -> Option<&mut T> {
+                match self {
+                    Self::Inl(x) => Some(x),
+                    Self::Inr(_) => None,
+                }
+            }
+
+            #[inline(always)]
+            fn into_variant(self) -> Option<T> {
+                match self {
+                    Self::Inl(x) => Some(x),
+                    Self::Inr(_) => None,
+                }
+            }
+        }
+
+        impl<T, H, R, I> $crate::coproduct::CoproductMember<T, $crate::type_list::There<I>> for $pair<H, R>
+        where
+            H: $($v_bnd)*,
+            R: $($tail)* + $crate::coproduct::CoproductMember<T, I>,
+        {
+            #[inline(always)]
+            fn from_variant(value: T) -> Self {
+                Self::Inr(R::from_variant(value))
+            }
+
+            #[inline(always)]
+            fn variant(&self) -> Option<&T> {
+                match self {
+                    Self::Inl(_) => None,
+                    Self::Inr(r) => r.variant(),
+                }
+            }
+
+            #[inline(always)]
+            fn variant_mut(&mut self) -> Option<&mut T> {
+                match self {
+                    Self::Inl(_) => None,
+                    Self::Inr(r) => r.variant_mut(),
+                }
+            }
+
+            #[inline(always)]
+            fn into_variant(self) -> Option<T> {
+                match self {
+                    Self::Inl(_) => None,
+                    Self::Inr(r) => r.into_variant(),
+                }
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! define_coproduct_of {
+    (
+        coproduct => [$c:ident ; $single:ident, $pair:ident];
+        coproduct_of => $coproduct_of:ident;
+    ) => {
+        /// Creates the coproduct type with the given comma-separated variant types.
+        #[allow(unused_macros)]
+        macro_rules! $coproduct_of {
+            ($t1:ty) => {
+                $single<$t1>
+            };
+
+            ($t1:ty, $t2:ty) => {
+                $pair<$t1, $single<$t2>>
+            };
+
+            ($t1:ty, $t2:ty, $t3:ty) => {
+                $pair<$t1,
+                    $pair<$t2, $single<$t3>>
+                >
+            };
+
+            ($t1:ty, $t2:ty, $t3:ty, $t4:ty) => {
+                $pair<$t1,
+                    $pair<$t2,
+                        $pair<$t3, $single<$t4>>
+                    >
+                >
+            };
+
+            ($t1:ty, $t2:ty, $t3:ty, $t4:ty, $t5:ty) => {
+                $pair<$t1,
+                    $pair<$t2,
+                        $pair<$t3,
+                            $pair<$t4, $single<$t5>>
+                        >
+                    >
+                >
+            };
+
+            ($t1:ty, $t2:ty, $t3:ty, $t4:ty, $t5:ty, $t6:ty) => {
+                $pair<$t1,
+                    $pair<$t2,
+                        $pair<$t3,
+                            $pair<$t4,
+                                $pair<$t5, $single<$t6>>
+                            >
+                        >
+                    >
+                >
+            };
+
+            ($t1:ty, $t2:ty, $t3:ty, $t4:ty, $t5:ty, $t6:ty, $t7:ty) => {
+                $pair<$t1,
+                    $pair<$t2,
+                        $pair<$t3,
+                            $pair<$t4,
+                                $pair<$t5,
+                                    $pair<$t6, $single<$t7>>
+                                >
+                            >
+                        >
+                    >
+                >
+            };
+
+            ($t1:ty, $t2:ty, $t3:ty, $t4:ty, $t5:ty, $t6:ty, $t7:ty, $t8:ty) => {
+                $pair<$t1,
+                    $pair<$t2,
+                        $pair<$t3,
+                            $pair<$t4,
+                                $pair<$t5,
+                                    $pair<$t6,
+                                        $pair<$t7, $single<$t8>>
+                                    >
+                                >
+                            >
+                        >
+                    >
+                >
+            };
+
+            ($t1:ty, $t2:ty, $t3:ty, $t4:ty, $t5:ty, $t6:ty, $t7:ty, $t8:ty, $t9:ty) => {
+                $pair<$t1,
+                    $pair<$t2,
+                        $pair<$t3,
+                            $pair<$t4,
+                                $pair<$t5,
+                                    $pair<$t6,
+                                        $pair<$t7,
+                                            $pair<$t8, $single<$t9>>
+                                        >
+                                    >
+                                >
+                            >
+                        >
+                    >
+                >
+            };
+
+            ($t1:ty, $t2:ty, $t3:ty, $t4:ty, $t5:ty, $t6:ty, $t7:ty, $t8:ty, $t9:ty, $t10:ty) => {
+                $pair<$t1,
+                    $pair<$t2,
+                        $pair<$t3,
+                            $pair<$t4,
+                                $pair<$t5,
+                                    $pair<$t6,
+                                        $pair<$t7,
+                                            $pair<$t8,
+                                                $pair<$t9, $single<$t10>>
+                                            >
+                                        >
+                                    >
+                                >
+                            >
+                        >
+                    >
+                >
+            };
+
+            ($t1:ty, $t2:ty, $t3:ty, $t4:ty, $t5:ty, $t6:ty, $t7:ty, $t8:ty, $t9:ty, $t10:ty, $t11:ty) => {
+                $pair<$t1,
+                    $pair<$t2,
+                        $pair<$t3,
+                            $pair<$t4,
+                                $pair<$t5,
+                                    $pair<$t6,
+                                        $pair<$t7,
+                                            $pair<$t8,
+                                                $pair<$t9,
+                                                    $pair<$t10, $single<$t11>>
+                                                >
+                                            >
+                                        >
+                                    >
+                                >
+                            >
+                        >
+                    >
+                >
+            };
+
+            ($t1:ty, $t2:ty, $t3:ty, $t4:ty, $t5:ty, $t6:ty, $t7:ty, $t8:ty, $t9:ty, $t10:ty, $t11:ty, $t12:ty) => {
+                $pair<$t1,
+                    $pair<$t2,
+                        $pair<$t3,
+                            $pair<$t4,
+                                $pair<$t5,
+                                    $pair<$t6,
+                                        $pair<$t7,
+                                            $pair<$t8,
+                                                $pair<$t9,
+                                                    $pair<$t10,
+                                                        $pair<$t11, $single<$t12>>
+                                                    >
+                                                >
+                                            >
+                                        >
+                                    >
+                                >
+                            >
+                        >
+                    >
+                >
+            };
+
+            ($t1:ty, $t2:ty, $t3:ty, $t4:ty, $t5:ty, $t6:ty, $t7:ty, $t8:ty, $t9:ty, $t10:ty, $t11:ty, $t12:ty, $t13:ty) => {
+                $pair<$t1,
+                    $pair<$t2,
+                        $pair<$t3,
+                            $pair<$t4,
+                                $pair<$t5,
+                                    $pair<$t6,
+                                        $pair<$t7,
+                                            $pair<$t8,
+                                                $pair<$t9,
+                                                    $pair<$t10,
+                                                        $pair<$t11,
+                                                            $pair<$t12, $single<$t13>>
+                                                        >
+                                                    >
+                                                >
+                                            >
+                                        >
+                                    >
+                                >
+                            >
+                        >
+                    >
+                >
+            };
+
+            ($t1:ty, $t2:ty, $t3:ty, $t4:ty, $t5:ty, $t6:ty, $t7:ty, $t8:ty, $t9:ty, $t10:ty, $t11:ty, $t12:ty, $t13:ty, $t14:ty) => {
+                $pair<$t1,
+                    $pair<$t2,
+                        $pair<$t3,
+                            $pair<$t4,
+                                $pair<$t5,
+                                    $pair<$t6,
+                                        $pair<$t7,
+                                            $pair<$t8,
+                                                $pair<$t9,
+                                                    $pair<$t10,
+                                                        $pair<$t11,
+                                                            $pair<$t12,
+                                                                $pair<$t13, $single<$t14>>
+                                                            >
+                                                        >
+                                                    >
+                                                >
+                                            >
+                                        >
+                                    >
+                                >
+                            >
+                        >
+                    >
+                >
+            };
+
+            ($t1:ty, $t2:ty, $t3:ty, $t4:ty, $t5:ty, $t6:ty, $t7:ty, $t8:ty, $t9:ty, $t10:ty, $t11:ty, $t12:ty, $t13:ty, $t14:ty, $t15:ty) => {
+                $pair<$t1,
+                    $pair<$t2,
+                        $pair<$t3,
+                            $pair<$t4,
+                                $pair<$t5,
+                                    $pair<$t6,
+                                        $pair<$t7,
+                                            $pair<$t8,
+                                                $pair<$t9,
+                                                    $pair<$t10,
+                                                        $pair<$t11,
+                                                            $pair<$t12,
+                                                                $pair<$t13,
+                                                                    $pair<$t14, $single<$t15>>
+                                                                >
+                                                            >
+                                                        >
+                                                    >
+                                                >
+                                            >
+                                        >
+                                    >
+                                >
+                            >
+                        >
+                    >
+                >
+            };
+
+            ($t1:ty, $t2:ty, $t3:ty, $t4:ty, $t5:ty, $t6:ty, $t7:ty, $t8:ty, $t9:ty, $t10:ty, $t11:ty, $t12:ty, $t13:ty, $t14:ty, $t15:ty, $t16:ty) => {
+                $pair<$t1,
+                    $pair<$t2,
+                        $pair<$t3,
+                            $pair<$t4,
+                                $pair<$t5,
+                                    $pair<$t6,
+                                        $pair<$t7,
+                                            $pair<$t8,
+                                                $pair<$t9,
+                                                    $pair<$t10,
+                                                        $pair<$t11,
+                                                            $pair<$t12,
+                                                                $pair<$t13,
+                                                                    $pair<$t14,
+                                                                        $pair<$t15, $single<$t16>>
+                                                                    >
+                                                                >
+                                                            >
+                                                        >
+                                                    >
+                                                >
+                                            >
+                                        >
+                                    >
+                                >
+                            >
+                        >
+                    >
+                >
+            };
+        }
+    };
+}
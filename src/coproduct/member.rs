@@ -0,0 +1,273 @@
+use crate::coproduct::{Coproduct, CoproductSingle, StCoproduct};
+use crate::type_list::{Here, There};
+
+/// A coproduct which has a variant of type `T`.
+///
+/// The second generic parameter `I` is an index witness, either [`Here`] or [`There`], which
+/// lets the compiler find the position of the variant without overlapping implementations. It is
+/// always inferred, and is written as `_` in use sites.
+///
+/// Variants are conveniently accessed by the `inject`, `get`, `get_mut` and `take` methods of the
+/// [`Variants`] extension trait, such as `coproduct.get::<T>()`, where the index witness does not
+/// appear. Accessing a variant type which does not exist in the coproduct does not compile.
+///
+/// Notice that the index witness can be inferred only if `T` appears exactly once among the variant
+/// types. Otherwise, the inference is ambiguous and the code does not compile.
+///
+/// [`Here`]: crate::type_list::Here
+/// [`There`]: crate::type_list::There
+/// [`Variants`]: crate::coproduct::Variants
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::coproduct::*;
+/// use orx_meta::coproduct_of;
+///
+/// type X = coproduct_of!(i32, bool, char);
+///
+/// let mut x: X = Coproduct::inject('x');
+/// assert_eq!(x.get::<i32>(), None);
+/// assert_eq!(x.get::<char>(), Some(&'x'));
+///
+/// if let Some(c) = x.get_mut::<char>() {
+///     *c = 'y';
+/// }
+/// assert_eq!(x.take::<char>(), Some('y'));
+/// ```
+///
+/// Injecting a value whose type is not a variant of the coproduct does not compile.
+///
+/// ```compile_fail
+/// use orx_meta::coproduct::*;
+/// use orx_meta::coproduct_of;
+///
+/// type X = coproduct_of!(i32, bool, char);
+///
+/// let x: X = Coproduct::inject("foo");
+/// ```
+pub trait CoproductMember<T, I>: StCoproduct + Sized {
+    /// Creates the coproduct holding the `value` as its active variant.
+    fn from_variant(value: T) -> Self;
+
+    /// Returns a reference to the value if the active variant is of type `T`; None otherwise.
+    fn variant(&self) -> Option<&T>;
+
+    /// Returns a mutable reference to the value if the active variant is of type `T`; None otherwise.
+    fn variant_mut(&mut self) -> Option<&mut T>;
+
+    /// Consumes the coproduct and returns the value if the active variant is of type `T`; None otherwise.
+    fn into_variant(self) -> Option<T>;
+}
+
+// single
+
+impl<T> CoproductMember<T, Here> for CoproductSingle<T> {
+    #[inline(always)]
+    fn from_variant(value: T) -> Self {
+        Self(value)
+    }
+
+    #[inline(always)]
+    fn variant(&self) -> Option<&T> {
+        Some(&self.0)
+    }
+
+    #[inline(always)]
+    fn variant_mut(&mut self) -> Option<&mut T> {
+        Some(&mut self.0)
+    }
+
+    #[inline(always)]
+    fn into_variant(self) -> Option<T> {
+        Some(self.0)
+    }
+}
+
+// multi
+
+impl<T, R> CoproductMember<T, Here> for Coproduct<T, R>
+where
+    R: StCoproduct,
+{
+    #[inline(always)]
+    fn from_variant(value: T) -> Self {
+        Self::Inl(value)
+    }
+
+    #[inline(always)]
+    fn variant(&self) -> Option<&T> {
+        match self {
+            Self::Inl(x) => Some(x),
+            Self::Inr(_) => None,
+        }
+    }
+
+    #[inline(always)]
+    fn variant_mut(&mut self) -> Option<&mut T> {
+        match self {
+            Self::Inl(x) => Some(x),
+            Self::Inr(_) => None,
+        }
+    }
+
+    #[inline(always)]
+    fn into_variant(self) -> Option<T> {
+        match self {
+            Self::Inl(x) => Some(x),
+            Self::Inr(_) => None,
+        }
+    }
+}
+
+impl<T, H, R, I> CoproductMember<T, There<I>> for Coproduct<H, R>
+where
+    R: CoproductMember<T, I>,
+{
+    #[inline(always)]
+    fn from_variant(value: T) -> Self {
+        Self::Inr(R::from_variant(value))
+    }
+
+    #[inline(always)]
+    fn variant(&self) -> Option<&T> {
+        match self {
+            Self::Inl(_) => None,
+            Self::Inr(r) => r.variant(),
+        }
+    }
+
+    #[inline(always)]
+    fn variant_mut(&mut self) -> Option<&mut T> {
+        match self {
+            Self::Inl(_) => None,
+            Self::Inr(r) => r.variant_mut(),
+        }
+    }
+
+    #[inline(always)]
+    fn into_variant(self) -> Option<T> {
+        match self {
+            Self::Inl(_) => None,
+            Self::Inr(r) => r.into_variant(),
+        }
+    }
+}
+
+/// Extension methods accessing the variants of coproducts by their types.
+///
+/// The generic parameter `I` is the index witness of [`CoproductMember`]. Since it is a parameter of
+/// the trait rather than of its methods, it is inferred at calls and variants are accessed only by
+/// their types, such as `coproduct.get::<T>()`.
+///
+/// This trait is automatically implemented for all statically-typed coproducts, including those
+/// created by the [`define_coproduct`] macro.
+///
+/// [`define_coproduct`]: crate::define_coproduct
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::coproduct::*;
+/// use orx_meta::coproduct_of;
+///
+/// type X = coproduct_of!(i32, bool, char);
+///
+/// let mut x = X::inject::<char>('x');
+/// assert_eq!(x.get::<i32>(), None);
+/// assert_eq!(x.get::<char>(), Some(&'x'));
+///
+/// if let Some(c) = x.get_mut::<char>() {
+///     *c = 'y';
+/// }
+/// assert_eq!(x.take::<char>(), Some('y'));
+/// ```
+pub trait Variants<I>: StCoproduct + Sized {
+    /// Creates the coproduct holding the `value` as its active variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::coproduct::*;
+    /// use orx_meta::coproduct_of;
+    ///
+    /// let x: coproduct_of!(u32) = CoproductSingle::inject(42);
+    /// assert_eq!(x, CoproductSingle(42));
+    ///
+    /// let x: coproduct_of!(u32, char) = Coproduct::inject('x');
+    /// assert_eq!(x, Coproduct::Inr(CoproductSingle('x')));
+    ///
+    /// let x = <coproduct_of!(u32, char)>::inject::<u32>(42);
+    /// assert_eq!(x, Coproduct::Inl(42));
+    /// ```
+    #[inline(always)]
+    fn inject<T>(value: T) -> Self
+    where
+        Self: CoproductMember<T, I>,
+    {
+        CoproductMember::from_variant(value)
+    }
+
+    /// Returns a reference to the value if the active variant is of type `T`; None otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::coproduct::*;
+    /// use orx_meta::coproduct_of;
+    ///
+    /// let x: coproduct_of!(u32, char) = Coproduct::inject('x');
+    /// assert_eq!(x.get::<u32>(), None);
+    /// assert_eq!(x.get::<char>(), Some(&'x'));
+    /// ```
+    #[inline(always)]
+    fn get<T>(&self) -> Option<&T>
+    where
+        Self: CoproductMember<T, I>,
+    {
+        self.variant()
+    }
+
+    /// Returns a mutable reference to the value if the active variant is of type `T`; None otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::coproduct::*;
+    /// use orx_meta::coproduct_of;
+    ///
+    /// let mut x: coproduct_of!(u32, char) = Coproduct::inject(42u32);
+    /// if let Some(x) = x.get_mut::<u32>() {
+    ///     *x += 1;
+    /// }
+    /// assert_eq!(x.get::<u32>(), Some(&43));
+    /// ```
+    #[inline(always)]
+    fn get_mut<T>(&mut self) -> Option<&mut T>
+    where
+        Self: CoproductMember<T, I>,
+    {
+        self.variant_mut()
+    }
+
+    /// Consumes the coproduct and returns the value if the active variant is of type `T`; None otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::coproduct::*;
+    /// use orx_meta::coproduct_of;
+    ///
+    /// let x: coproduct_of!(u32, char) = Coproduct::inject('x');
+    /// assert_eq!(x.take::<char>(), Some('x'));
+    /// ```
+    #[inline(always)]
+    fn take<T>(self) -> Option<T>
+    where
+        Self: CoproductMember<T, I>,
+    {
+        self.into_variant()
+    }
+}
+
+impl<C: StCoproduct, I> Variants<I> for C {}
@@ -0,0 +1,14 @@
+#[cfg(test)]
+mod tests;
+
+mod coproduct_of;
+mod define_coproduct;
+mod member;
+mod multi;
+mod single;
+mod st_coproduct;
+
+pub use member::{CoproductMember, Variants};
+pub use multi::Coproduct;
+pub use single::CoproductSingle;
+pub use st_coproduct::StCoproduct;
@@ -0,0 +1,59 @@
+use crate::coproduct::StCoproduct;
+
+/// A statically-typed coproduct with multiple (>=2) variants.
+///
+/// It is either:
+/// * `Inl(Head)` holding a value of the first variant type `Head`, or
+/// * `Inr(Tail)` holding the coproduct `Tail` of the remaining variant types, which can be
+///   * either a [`CoproductSingle`] in which case this coproduct has 2 variants,
+///   * or a [`Coproduct`] in which case this coproduct has more than 2 variants.
+///
+/// Coproducts of all lengths can be represented by these two types; and it is often more convenient
+/// to write their types by the [`coproduct_of`] macro.
+///
+/// [`CoproductSingle`]: crate::coproduct::CoproductSingle
+/// [`coproduct_of`]: crate::coproduct_of
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::coproduct::*;
+/// use orx_meta::coproduct_of;
+///
+/// type X = coproduct_of!(i32, bool, char);
+///
+/// let x: X = Coproduct::inject(true);
+/// assert_eq!(x, Coproduct::Inr(Coproduct::Inl(true)));
+///
+/// let description = match x {
+///     Coproduct::Inl(i) => format!("int {i}"),
+///     Coproduct::Inr(Coproduct::Inl(b)) => format!("bool {b}"),
+///     Coproduct::Inr(Coproduct::Inr(CoproductSingle(c))) => format!("char {c}"),
+/// };
+/// assert_eq!(description, "bool true");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Coproduct<Head, Tail>
+where
+    Tail: StCoproduct,
+{
+    /// The coproduct holds a value of the first variant type `Head`.
+    Inl(Head),
+    /// The coproduct holds a value of one of the remaining variant types of the coproduct `Tail`.
+    Inr(Tail),
+}
+
+impl<H, T> StCoproduct for Coproduct<H, T>
+where
+    T: StCoproduct,
+{
+    const LEN: usize = 1 + T::LEN;
+
+    #[inline(always)]
+    fn index(&self) -> usize {
+        match self {
+            Self::Inl(_) => 0,
+            Self::Inr(t) => 1 + t.index(),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::coproduct::StCoproduct;
+
+/// A statically-typed coproduct with exactly one variant of type `T`.
+///
+/// It always holds a value of type `T`.
+///
+/// See also the other [`StCoproduct`] implementation [`Coproduct`] with multiple variants.
+///
+/// [`Coproduct`]: crate::coproduct::Coproduct
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::coproduct::*;
+///
+/// let x = CoproductSingle(42);
+/// assert_eq!(x.index(), 0);
+/// assert_eq!(x.get::<i32>(), Some(&42));
+/// assert_eq!(x.0, 42);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CoproductSingle<T>(pub T);
+
+impl<T> StCoproduct for CoproductSingle<T> {
+    const LEN: usize = 1;
+
+    #[inline(always)]
+    fn index(&self) -> usize {
+        0
+    }
+}
@@ -0,0 +1,60 @@
+/// A strongly typed non-empty coproduct, or sum type, of heterogeneous elements.
+///
+/// A coproduct holds exactly one value whose type is one of its variant types; it is the counterpart
+/// of the statically-typed queue which holds one value of each of its element types.
+/// In other words, a queue corresponds to a struct while a coproduct corresponds to an enum.
+///
+/// There exist two implementations:
+/// * [`CoproductSingle`] with exactly one variant, and
+/// * [`Coproduct`] with multiple (>=2) variants, which is either the head variant `Inl` or one of
+///   the variants of its tail coproduct `Inr`.
+///
+/// Also see [`define_coproduct`] macro to define a coproduct of heterogeneous variants all of which
+/// exhibit a common behavior, or implement a common set of traits.
+///
+/// [`CoproductSingle`]: crate::coproduct::CoproductSingle
+/// [`Coproduct`]: crate::coproduct::Coproduct
+/// [`define_coproduct`]: crate::define_coproduct
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::coproduct::*;
+/// use orx_meta::coproduct_of;
+///
+/// type Value = coproduct_of!(i32, bool, String);
+/// assert_eq!(Value::LEN, 3);
+///
+/// let values: Vec<Value> = vec![
+///     Coproduct::inject(42),
+///     Coproduct::inject("foo".to_string()),
+///     Coproduct::inject(true),
+/// ];
+///
+/// let indices: Vec<_> = values.iter().map(|x| x.index()).collect();
+/// assert_eq!(indices, [0, 2, 1]);
+///
+/// assert_eq!(values[0].get::<i32>(), Some(&42));
+/// assert_eq!(values[0].get::<bool>(), None);
+/// assert_eq!(values[1].get::<String>(), Some(&"foo".to_string()));
+/// ```
+pub trait StCoproduct {
+    /// Number of variants of the coproduct.
+    const LEN: usize;
+
+    /// Returns the position of the active variant among the variants of the coproduct.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::coproduct::*;
+    /// use orx_meta::coproduct_of;
+    ///
+    /// let x: coproduct_of!(i32, bool, char) = Coproduct::inject('x');
+    /// assert_eq!(x.index(), 2);
+    ///
+    /// let x: coproduct_of!(i32, bool, char) = Coproduct::inject(42);
+    /// assert_eq!(x.index(), 0);
+    /// ```
+    fn index(&self) -> usize;
+}
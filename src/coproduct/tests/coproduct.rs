@@ -0,0 +1,80 @@
+use crate::coproduct::*;
+use crate::coproduct_of;
+
+type X = coproduct_of!(i32, bool, char, String);
+
+#[test]
+fn len() {
+    assert_eq!(<coproduct_of!(i32)>::LEN, 1);
+    assert_eq!(<coproduct_of!(i32, bool)>::LEN, 2);
+    assert_eq!(X::LEN, 4);
+}
+
+#[test]
+fn inject() {
+    let x: X = Coproduct::inject(42);
+    assert_eq!(x, Coproduct::Inl(42));
+    assert_eq!(x.index(), 0);
+
+    let x: X = Coproduct::inject(true);
+    assert_eq!(x, Coproduct::Inr(Coproduct::Inl(true)));
+    assert_eq!(x.index(), 1);
+
+    let x: X = Coproduct::inject("foo".to_string());
+    assert_eq!(
+        x,
+        Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(CoproductSingle(
+            "foo".to_string()
+        ))))
+    );
+    assert_eq!(x.index(), 3);
+
+    let x: coproduct_of!(char) = CoproductSingle::inject('x');
+    assert_eq!(x.index(), 0);
+}
+
+#[test]
+fn get() {
+    let x: X = Coproduct::inject('x');
+    assert_eq!(x.get::<i32>(), None);
+    assert_eq!(x.get::<bool>(), None);
+    assert_eq!(x.get::<char>(), Some(&'x'));
+    assert_eq!(x.get::<String>(), None);
+
+    let x = CoproductSingle(42);
+    assert_eq!(x.get::<i32>(), Some(&42));
+}
+
+#[test]
+fn get_mut() {
+    let mut x: X = Coproduct::inject("foo".to_string());
+    assert_eq!(x.get_mut::<i32>(), None);
+
+    if let Some(s) = x.get_mut::<String>() {
+        s.push('!');
+    }
+    assert_eq!(x.get::<String>(), Some(&"foo!".to_string()));
+}
+
+#[test]
+fn take() {
+    let x: X = Coproduct::inject(true);
+    assert_eq!(x.clone().take::<i32>(), None);
+    assert_eq!(x.take::<bool>(), Some(true));
+
+    let x = CoproductSingle(42);
+    assert_eq!(x.take::<i32>(), Some(42));
+}
+
+#[test]
+fn generic_member() {
+    fn as_char<C: CoproductMember<char, I>, I>(c: &C) -> Option<char> {
+        c.variant().copied()
+    }
+
+    let x: X = Coproduct::inject('x');
+    assert_eq!(as_char(&x), Some('x'));
+
+    let x: X = Coproduct::inject(42);
+    assert_eq!(as_char(&x), None);
+}
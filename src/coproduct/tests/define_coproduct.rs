@@ -0,0 +1,225 @@
+#[allow(dead_code)]
+mod shapes {
+    use crate::coproduct::*;
+
+    pub trait Area {
+        fn area(&self) -> u32;
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Square(pub u32);
+    impl Area for Square {
+        fn area(&self) -> u32 {
+            self.0 * self.0
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Rectangle(pub u32, pub u32);
+    impl Area for Rectangle {
+        fn area(&self) -> u32 {
+            self.0 * self.1
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Nothing;
+    impl Area for Nothing {
+        fn area(&self) -> u32 {
+            0
+        }
+    }
+
+    crate::define_coproduct!(
+        elements => [ Area ];
+        coproduct => [ StShape ; ShapeSingle, Shape ];
+        coproduct_of => shape_of;
+    );
+
+    impl<F: Area> Area for ShapeSingle<F> {
+        fn area(&self) -> u32 {
+            self.0.area()
+        }
+    }
+
+    impl<F: Area, R: StShape> Area for Shape<F, R> {
+        fn area(&self) -> u32 {
+            match self {
+                Self::Inl(x) => x.area(),
+                Self::Inr(r) => r.area(),
+            }
+        }
+    }
+
+    pub type AnyShape = shape_of!(Square, Rectangle, Nothing);
+
+    #[test]
+    fn dispatch() {
+        let shapes: Vec<AnyShape> = vec![
+            Shape::inject(Square(3)),
+            Shape::inject(Nothing),
+            Shape::inject(Rectangle(2, 5)),
+        ];
+        let areas: Vec<_> = shapes.iter().map(|x| x.area()).collect();
+        assert_eq!(areas, [9, 0, 10]);
+
+        let indices: Vec<_> = shapes.iter().map(|x| x.index()).collect();
+        assert_eq!(indices, [0, 2, 1]);
+        assert_eq!(AnyShape::LEN, 3);
+    }
+
+    #[test]
+    fn access() {
+        let mut shape: AnyShape = Shape::inject(Rectangle(2, 5));
+        assert_eq!(shape.get::<Square>(), None);
+        assert_eq!(shape.get::<Rectangle>(), Some(&Rectangle(2, 5)));
+
+        if let Some(r) = shape.get_mut::<Rectangle>() {
+            r.1 = 7;
+        }
+        assert_eq!(shape.area(), 14);
+        assert_eq!(shape.take::<Rectangle>(), Some(Rectangle(2, 7)));
+
+        let single: shape_of!(Square) = ShapeSingle::inject(Square(4));
+        assert_eq!(single.area(), 16);
+        assert_eq!(single.get::<Square>(), Some(&Square(4)));
+    }
+
+    #[test]
+    fn generic_over_family() {
+        fn total_area<S: StShape>(shapes: &[S]) -> u32 {
+            shapes.iter().map(|x| x.area()).sum()
+        }
+
+        let shapes: Vec<AnyShape> = vec![Shape::inject(Square(3)), Shape::inject(Square(2))];
+        assert_eq!(total_area(&shapes), 13);
+    }
+}
+
+#[allow(dead_code)]
+mod unbounded {
+    use crate::coproduct::Variants;
+
+    crate::define_coproduct!(
+        coproduct => [ StValue ; ValueSingle, Value ];
+        coproduct_of => value_of;
+    );
+
+    #[test]
+    fn without_elements() {
+        let x: value_of!(u32, char) = Value::inject('x');
+        assert_eq!(x.get::<char>(), Some(&'x'));
+        assert_eq!(x, Value::Inr(ValueSingle('x')));
+    }
+}
+
+#[allow(dead_code)]
+mod path_bounds {
+    use crate::coproduct::Variants;
+
+    pub trait Describe {
+        fn describe(&self) -> String;
+    }
+
+    impl Describe for u32 {
+        fn describe(&self) -> String {
+            format!("u32:{self}")
+        }
+    }
+
+    impl Describe for char {
+        fn describe(&self) -> String {
+            format!("char:{self}")
+        }
+    }
+
+    crate::define_coproduct!(
+        coproduct_of => item_of;
+        coproduct => [ StItem ; ItemSingle, Item ];
+        elements => [ Describe | core::fmt::Debug ];
+    );
+
+    impl<F: Describe + core::fmt::Debug> Describe for ItemSingle<F> {
+        fn describe(&self) -> String {
+            self.0.describe()
+        }
+    }
+
+    impl<F: Describe + core::fmt::Debug, R: StItem> Describe for Item<F, R> {
+        fn describe(&self) -> String {
+            match self {
+                Self::Inl(x) => x.describe(),
+                Self::Inr(r) => r.describe(),
+            }
+        }
+    }
+
+    #[test]
+    fn elements_as_paths_in_any_order() {
+        let x: item_of!(u32, char) = Item::inject('x');
+        assert_eq!(x.describe(), "char:x");
+        assert_eq!(format!("{x:?}"), "Inr(ItemSingle('x'))");
+    }
+}
+
+#[allow(dead_code)]
+mod lifetime_and_generics {
+    use crate::coproduct::*;
+
+    pub trait Lookup<'a, K> {
+        fn lookup(&self, key: &K) -> Option<&'a str>;
+    }
+
+    pub struct Fixed(&'static str);
+    impl<'a, K> Lookup<'a, K> for Fixed {
+        fn lookup(&self, _: &K) -> Option<&'a str> {
+            Some(self.0)
+        }
+    }
+
+    pub struct Pairs<'a, K>(Vec<(K, &'a str)>);
+    impl<'a, K: PartialEq> Lookup<'a, K> for Pairs<'a, K> {
+        fn lookup(&self, key: &K) -> Option<&'a str> {
+            self.0.iter().find(|x| &x.0 == key).map(|x| x.1)
+        }
+    }
+
+    crate::define_coproduct!(
+        elements => [ Lookup<'a, K> ];
+        generics => [ K: PartialEq ];
+        coproduct => [ StSource ; SourceSingle, Source ];
+        lt => ['a];
+        coproduct_of => source_of;
+    );
+
+    impl<'a, K, F: Lookup<'a, K>> Lookup<'a, K> for SourceSingle<F> {
+        fn lookup(&self, key: &K) -> Option<&'a str> {
+            self.0.lookup(key)
+        }
+    }
+
+    impl<'a, K: PartialEq, F: Lookup<'a, K>, R: StSource<'a, K>> Lookup<'a, K> for Source<F, R> {
+        fn lookup(&self, key: &K) -> Option<&'a str> {
+            match self {
+                Self::Inl(x) => x.lookup(key),
+                Self::Inr(r) => r.lookup(key),
+            }
+        }
+    }
+
+    fn find<'a, K: PartialEq, S: StSource<'a, K>>(sources: &[S], key: &K) -> Vec<&'a str> {
+        sources.iter().filter_map(|x| x.lookup(key)).collect()
+    }
+
+    #[test]
+    fn lifetime_and_generics() {
+        let text = String::from("two");
+        let sources: Vec<source_of!(Fixed, Pairs<'_, u32>)> = vec![
+            Source::inject(Pairs(vec![(1, "one"), (2, text.as_str())])),
+            Source::inject(Fixed("any")),
+        ];
+        assert_eq!(find(&sources, &2), ["two", "any"]);
+        assert_eq!(find(&sources, &3), ["any"]);
+        assert_eq!(sources[0].index(), 1);
+    }
+}
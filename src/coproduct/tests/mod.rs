@@ -0,0 +1,2 @@
+mod coproduct;
+mod define_coproduct;
@@ -12,5 +12,17 @@
 )]
 #![cfg_attr(not(test), no_std)]
 
+/// Module defining statically typed non-empty coproducts, or sum types, of heterogeneous variants.
+pub mod coproduct;
+
 /// Module defining statically typed non-empty queues of heterogeneous elements.
 pub mod queue;
+
+/// Module defining records, which are statically typed queues of named fields.
+pub mod record;
+
+/// Module defining statically typed trees of heterogeneous elements with logarithmic nesting depth.
+pub mod tree;
+
+/// Module defining type-level lists of types which carry no values.
+pub mod type_list;
@@ -0,0 +1,133 @@
+/// Asserts at compile time that layout of a `#[repr(C)]` queue matches that of a `#[repr(C)]` struct.
+///
+/// Queues with stable layouts are [`ReprCQueue`] and [`ReprCQueueSingle`], or those created by the
+/// [`define_queue`] macro with the `repr => C` option.
+///
+/// * `assert_layout_matches!(Queue, Struct)` asserts that both types have the same size and alignment.
+/// * `assert_layout_matches!(Queue, Struct { a, b, c })` additionally asserts that the i-th element of
+///   the queue has the same offset as the i-th listed field of the struct.
+/// * Offsets are computed by the `front` and `back` fields of the queue. Queues created by [`define_queue`]
+///   name these fields as `f` and `b`, which can be provided as `assert_layout_matches!(Queue { f, b }, Struct { a, b, c })`.
+///   Notice that the fields are private; therefore, the assertion must be made in the module defining the queue.
+///
+/// Since the assertions are evaluated in a const context, a layout mismatch is a compile time error.
+///
+/// [`ReprCQueue`]: crate::queue::ReprCQueue
+/// [`ReprCQueueSingle`]: crate::queue::ReprCQueueSingle
+/// [`define_queue`]: crate::define_queue
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::queue::*;
+/// use orx_meta::assert_layout_matches;
+///
+/// #[repr(C)]
+/// struct Header {
+///     id: u64,
+///     len: u32,
+///     flags: u16,
+///     kind: u16,
+/// }
+///
+/// type HeaderQueue = ReprCQueue<u64, ReprCQueue<u32, ReprCQueue<u16, ReprCQueueSingle<u16>>>>;
+///
+/// assert_layout_matches!(HeaderQueue, Header);
+/// assert_layout_matches!(HeaderQueue, Header { id, len, flags, kind });
+/// ```
+///
+/// A queue is a nested struct; hence, its layout differs from a flat struct whenever nesting introduces
+/// additional padding. In the following example, the back queue `(u8, u32)` is aligned to 4 bytes, which
+/// places the second element at offset 4 while it is at offset 1 in the flat struct.
+///
+/// ```compile_fail
+/// use orx_meta::queue::*;
+/// use orx_meta::assert_layout_matches;
+///
+/// #[repr(C)]
+/// struct Flat {
+///     a: u8,
+///     b: u8,
+///     c: u32,
+/// }
+///
+/// type Nested = ReprCQueue<u8, ReprCQueue<u8, ReprCQueueSingle<u32>>>;
+///
+/// assert_layout_matches!(Nested, Flat { a, b, c });
+/// ```
+///
+/// Similarly, the assertion fails when the fields are not listed in the order of the queue elements.
+///
+/// ```compile_fail
+/// use orx_meta::queue::*;
+/// use orx_meta::assert_layout_matches;
+///
+/// #[repr(C)]
+/// struct Rgba {
+///     r: u16,
+///     g: u16,
+///     b: u16,
+///     a: u16,
+/// }
+///
+/// type RgbaQueue = ReprCQueue<u16, ReprCQueue<u16, ReprCQueue<u16, ReprCQueueSingle<u16>>>>;
+///
+/// assert_layout_matches!(RgbaQueue, Rgba { r, g, a, b });
+/// ```
+///
+/// Queues defined by the `define_queue` macro with `repr => C` option can be checked as well.
+///
+/// ```
+/// use orx_meta::assert_layout_matches;
+///
+/// orx_meta::define_queue!(
+///     repr => C;
+///     queue => [ StPoint ; PointSingle, Point ];
+///     queue_of => point_of;
+/// );
+///
+/// #[repr(C)]
+/// struct Point3 {
+///     x: f64,
+///     y: f64,
+///     z: f64,
+/// }
+///
+/// assert_layout_matches!(point_of!(f64, f64, f64) { f, b }, Point3 { x, y, z });
+/// ```
+#[macro_export]
+macro_rules! assert_layout_matches {
+    (@offsets $queue:ty, $strct:ident, $front:ident, $back:ident, [$($path:ident .)*] $field:ident $(, $rest:ident)*) => {
+        assert!(
+            core::mem::offset_of!($queue, $($path .)* $front) == core::mem::offset_of!($strct, $field),
+            concat!("offset of the queue element does not match that of the field `", stringify!($field), "`")
+        );
+        $crate::assert_layout_matches!(@offsets $queue, $strct, $front, $back, [$($path .)* $back .] $($rest),*);
+    };
+
+    (@offsets $queue:ty, $strct:ident, $front:ident, $back:ident, [$($path:ident .)*]) => {};
+
+    ($queue:ty, $strct:ty) => {
+        const _: () = {
+            assert!(
+                core::mem::size_of::<$queue>() == core::mem::size_of::<$strct>(),
+                "sizes of the queue and struct do not match"
+            );
+            assert!(
+                core::mem::align_of::<$queue>() == core::mem::align_of::<$strct>(),
+                "alignments of the queue and struct do not match"
+            );
+        };
+    };
+
+    ($queue:ty, $strct:ident { $($field:ident),+ $(,)? }) => {
+        $crate::assert_layout_matches!($queue { front, back }, $strct { $($field),+ });
+    };
+
+    ($queue:ty { $front:ident, $back:ident }, $strct:ident { $($field:ident),+ $(,)? }) => {
+        $crate::assert_layout_matches!($queue, $strct);
+        const _: () = {
+            $crate::assert_layout_matches!(@offsets $queue, $strct, $front, $back, [] $($field),+);
+        };
+    };
+}
@@ -0,0 +1,136 @@
+use crate::queue::{Queue, QueueSingle, StQueue};
+
+/// Conversion of a statically-typed queue into the base [`Queue`] or [`QueueSingle`] with the same elements.
+///
+/// Queues created by [`define_queue`] restrict their elements with trait bounds. Converting them into the
+/// base queue drops these bounds, which allows us to use all utilities of the base queue, such as
+/// [`QueueBuilder`], on the same elements. The conversion is reverted by [`FromBase`].
+///
+/// Base queues are their own base; hence, the conversion is the identity.
+///
+/// In addition to `into_base`, [`define_queue`] implements `From` conversions from its queue types into
+/// the base queue types.
+///
+/// [`define_queue`]: crate::define_queue
+/// [`QueueBuilder`]: crate::queue::QueueBuilder
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::queue::*;
+///
+/// orx_meta::define_queue!(
+///     elements => [ Clone ];
+///     queue => [ StClones ; ClonesSingle, Clones ];
+/// );
+///
+/// let queue = ClonesSingle::new(42).push(true).push('x');
+///
+/// let base: Queue<i32, Queue<bool, QueueSingle<char>>> = queue.clone().into_base();
+/// assert_eq!(base.into_tuple(), (42, true, 'x'));
+///
+/// let base: Queue<i32, Queue<bool, QueueSingle<char>>> = queue.into();
+/// assert_eq!(base.into_tuple(), (42, true, 'x'));
+/// ```
+pub trait IntoBase {
+    /// Type of the base queue containing the same elements.
+    type Base: StQueue;
+
+    /// Converts this queue into the base queue containing the same elements.
+    fn into_base(self) -> Self::Base;
+}
+
+/// Conversion of the base [`Queue`] or [`QueueSingle`] into a statically-typed queue with the same elements.
+///
+/// Queues created by [`define_queue`] restrict their elements with trait bounds. The conversion from the
+/// base queue is implemented only if all elements satisfy these bounds; otherwise, it does not compile.
+/// This is the inverse of [`IntoBase`].
+///
+/// In addition to `from_base`, [`define_queue`] implements `From` conversions from the base queue types into
+/// its queue types.
+///
+/// [`define_queue`]: crate::define_queue
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::queue::*;
+///
+/// orx_meta::define_queue!(
+///     elements => [ Clone ];
+///     queue => [ StClones ; ClonesSingle, Clones ];
+///     queue_of => clones_of;
+/// );
+///
+/// let base = Queue::new(42).push(true).push('x');
+///
+/// let queue = <clones_of!(i32, bool, char)>::from_base(base.clone());
+/// assert_eq!(queue, ClonesSingle::new(42).push(true).push('x'));
+///
+/// let queue: clones_of!(i32, bool, char) = base.into();
+/// assert_eq!(queue, ClonesSingle::new(42).push(true).push('x'));
+/// ```
+///
+/// Elements of the base queue must satisfy the element bounds of the target queue.
+///
+/// ```compile_fail
+/// use orx_meta::queue::*;
+///
+/// orx_meta::define_queue!(
+///     elements => [ Copy ];
+///     queue => [ StCopies ; CopiesSingle, Copies ];
+///     queue_of => copies_of;
+/// );
+///
+/// let base = Queue::new(42).push(String::from("not copy"));
+/// let queue: copies_of!(i32, String) = base.into();
+/// ```
+pub trait FromBase<Base>: Sized {
+    /// Creates the queue from the `base` queue containing the same elements.
+    fn from_base(base: Base) -> Self;
+}
+
+// single
+
+impl<F> IntoBase for QueueSingle<F> {
+    type Base = Self;
+
+    #[inline(always)]
+    fn into_base(self) -> Self {
+        self
+    }
+}
+
+impl<F> FromBase<QueueSingle<F>> for QueueSingle<F> {
+    #[inline(always)]
+    fn from_base(base: QueueSingle<F>) -> Self {
+        base
+    }
+}
+
+// multi
+
+impl<F, B> IntoBase for Queue<F, B>
+where
+    B: StQueue + IntoBase,
+{
+    type Base = Queue<F, B::Base>;
+
+    #[inline(always)]
+    fn into_base(self) -> Self::Base {
+        let (f, b) = self.pop();
+        Queue::from_fb(f, b.into_base())
+    }
+}
+
+impl<F, B, BB> FromBase<Queue<F, BB>> for Queue<F, B>
+where
+    B: StQueue + FromBase<BB>,
+    BB: StQueue,
+{
+    #[inline(always)]
+    fn from_base(base: Queue<F, BB>) -> Self {
+        let (f, b) = base.pop();
+        Queue::from_fb(f, B::from_base(b))
+    }
+}
@@ -1,6 +1,16 @@
-use crate::queue::{QueueSingle, StQueue};
+use crate::queue::{ParseQueue, ParseQueueError, Queue, QueueSingle, SlotError, StQueue};
+use core::fmt::{Debug, Formatter};
 use core::marker::PhantomData;
 
+type FirstBuilding<Target> =
+    QueueBuilding<Target, <Target as StQueue>::Back, QueueSingle<<Target as StQueue>::Front>>;
+
+type NextBuilding<Target, Remaining, Current> = QueueBuilding<
+    Target,
+    <Remaining as StQueue>::Back,
+    <Current as StQueue>::PushBack<<Remaining as StQueue>::Front>,
+>;
+
 /// A type-safe builder for queues such that:
 ///
 /// * `push` can only be called correct number of times with correct types,
@@ -8,6 +18,10 @@ use core::marker::PhantomData;
 ///
 /// Further, since queues can represent any struct, `QueueBuilder` can be used as a generic builder for any struct or tuple.
 ///
+/// See also [`QueueSlotBuilder`] which allows to set the elements in any order by their types or field names.
+///
+/// [`QueueSlotBuilder`]: crate::queue::QueueSlotBuilder
+///
 /// # Example
 ///
 /// In the following example, we want to build a queue of four elements of types `u32`, `bool`, `char` and `String` respectively.
@@ -102,6 +116,17 @@ where
     }
 }
 
+impl<Target> Debug for QueueBuilder<Target>
+where
+    Target: StQueue,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("QueueBuilder")
+            .field("target", &core::any::type_name::<Target>())
+            .finish()
+    }
+}
+
 impl<Target> QueueBuilder<Target>
 where
     Target: StQueue,
@@ -120,8 +145,195 @@ where
     ) -> QueueBuilding<Target, Target::Back, QueueSingle<Target::Front>> {
         QueueBuilding::new(QueueSingle::new(element))
     }
+
+    /// Tries to convert the `value` into the next element and push it to build the target type.
+    ///
+    /// Returns the error pointing to the position and type of the element if the conversion fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    /// use orx_meta::queue_of;
+    ///
+    /// type MyQueue = queue_of!(u8, bool);
+    ///
+    /// let instance = QueueBuilder::<MyQueue>::new()
+    ///     .try_push(42u32)
+    ///     .map(|b| b.push(true).finish());
+    /// assert_eq!(instance.map(|x| x.into_tuple()), Ok((42, true)));
+    ///
+    /// let error = QueueBuilder::<MyQueue>::new().try_push(1000u32).err().unwrap();
+    /// assert_eq!((error.position, error.type_name), (0, "u8"));
+    /// ```
+    pub fn try_push<V>(self, value: V) -> Result<FirstBuilding<Target>, SlotError<V::Error>>
+    where
+        V: TryInto<Target::Front>,
+    {
+        match value.try_into() {
+            Ok(element) => Ok(self.push(element)),
+            Err(error) => Err(SlotError::new::<Target::Front>(0, error)),
+        }
+    }
+
+    /// Pushes the default value of the next element to build the target type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    /// use orx_meta::queue_of;
+    ///
+    /// type MyQueue = queue_of!(u32, bool, char);
+    ///
+    /// let instance = QueueBuilder::<MyQueue>::new().skip().push(true).push('x').finish();
+    /// assert_eq!(instance.as_tuple(), (&0, &true, &'x'));
+    /// ```
+    pub fn skip(self) -> QueueBuilding<Target, Target::Back, QueueSingle<Target::Front>>
+    where
+        Target::Front: Default,
+    {
+        self.push(Default::default())
+    }
+
+    /// Builds the target type by parsing its elements in order from the string values of the `iter`.
+    ///
+    /// Each element is parsed by its `FromStr` implementation. The method fails with an error pointing to
+    /// the position and type of the element when:
+    /// * a value cannot be parsed into the corresponding element type,
+    /// * there exist fewer values than the elements, or
+    /// * there exist more values than the elements.
+    ///
+    /// In the first case, the parse error of the element is converted into the error type `E` of the
+    /// [`ParseQueueError`]; hence, `E` must be convertible from the parse errors of all elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    /// use orx_meta::queue_of;
+    /// use std::error::Error;
+    ///
+    /// type Args = queue_of!(String, u16, bool);
+    /// type ArgsError<'a> = ParseQueueError<'a, Box<dyn Error>>;
+    ///
+    /// let args: Result<_, ArgsError> = QueueBuilder::<Args>::try_from_iter("localhost 8080 true".split(' '));
+    /// assert_eq!(
+    ///     args.map(|x| x.into_tuple()).ok(),
+    ///     Some(("localhost".to_string(), 8080, true))
+    /// );
+    ///
+    /// let args: Result<_, ArgsError> = QueueBuilder::<Args>::try_from_iter(["localhost", "http"]);
+    /// assert_eq!(
+    ///     args.err().map(|e| e.to_string()).as_deref(),
+    ///     Some("failed to parse 'http' into the element at position 1 of type u16: invalid digit found in string")
+    /// );
+    ///
+    /// let args: Result<_, ArgsError> = QueueBuilder::<Args>::try_from_iter(["localhost", "8080"]);
+    /// assert!(matches!(
+    ///     args,
+    ///     Err(ParseQueueError::Missing { position: 2, type_name: "bool" })
+    /// ));
+    ///
+    /// let args: Result<_, ArgsError> = QueueBuilder::<Args>::try_from_iter(["localhost", "8080", "true", "x"]);
+    /// assert!(matches!(
+    ///     args,
+    ///     Err(ParseQueueError::Unexpected { position: 3, value: "x" })
+    /// ));
+    /// ```
+    ///
+    /// When all elements share the same parse error type, it can be used directly as `E`.
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    /// use orx_meta::queue_of;
+    /// use core::num::ParseIntError;
+    ///
+    /// type Point = queue_of!(i32, i32, u8);
+    ///
+    /// let point = QueueBuilder::<Point>::try_from_iter(["3", "-4", "300"]);
+    /// assert_eq!(
+    ///     point,
+    ///     Err(ParseQueueError::Invalid {
+    ///         position: 2,
+    ///         type_name: "u8",
+    ///         value: "300",
+    ///         error: "300".parse::<u8>().unwrap_err(),
+    ///     })
+    /// );
+    ///
+    /// let point: Result<_, ParseQueueError<ParseIntError>> =
+    ///     QueueBuilder::<Point>::try_from_iter(["3", "-4", "5"]);
+    /// assert_eq!(point.map(|x| x.into_tuple()), Ok((3, -4, 5)));
+    /// ```
+    pub fn try_from_iter<'a, E, I>(iter: I) -> Result<Target, ParseQueueError<'a, E>>
+    where
+        I: IntoIterator<Item = &'a str>,
+        Target: ParseQueue<E>,
+    {
+        let mut iter = iter.into_iter();
+        let target = Target::parse_from(&mut iter, 0)?;
+        match iter.next() {
+            None => Ok(target),
+            Some(value) => Err(ParseQueueError::Unexpected {
+                position: Target::LEN,
+                value,
+            }),
+        }
+    }
+
+    /// Completes the builder by filling all elements with their default values, and returns the built target type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    /// use orx_meta::queue_of;
+    ///
+    /// type MyQueue = queue_of!(u32, bool, String);
+    ///
+    /// let instance = QueueBuilder::<MyQueue>::new().finish_with_defaults();
+    /// assert_eq!(instance.as_tuple(), (&0, &false, &String::new()));
+    /// ```
+    pub fn finish_with_defaults(self) -> Target
+    where
+        Target: FillWithDefaults<()>,
+    {
+        Target::fill_with_defaults(())
+    }
 }
 
+/// A builder in progress which is obtained by pushing elements to a [`QueueBuilder`].
+///
+/// * `Target` is the queue type to be built,
+/// * `Remaining` is the queue of element types which are not pushed yet, and
+/// * `Current` is the queue of elements pushed so far.
+///
+/// Progress of the builder can be observed through the [`BuilderProgress`] trait, which allows to drive
+/// the builder step by step, such as by a CLI wizard. The same progress information is available on the
+/// [`QueueBuilder`].
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::queue::*;
+/// use orx_meta::queue_of;
+///
+/// type MyQueue = queue_of!(u32, bool, char);
+///
+/// let builder = QueueBuilder::<MyQueue>::new().push(42);
+/// assert_eq!(builder.pushed(), 1);
+/// assert_eq!(builder.remaining(), 2);
+/// assert_eq!(builder.next_type_name(), Some("bool"));
+///
+/// let builder = builder.push(true).push('x');
+/// assert_eq!(builder.pushed(), 3);
+/// assert_eq!(builder.remaining(), 0);
+/// assert_eq!(builder.next_type_name(), None);
+///
+/// let instance = builder.finish();
+/// assert_eq!(instance.as_tuple(), (&42, &true, &'x'));
+/// ```
 pub struct QueueBuilding<Target, Remaining, Current>
 where
     Target: StQueue,
@@ -133,6 +345,31 @@ where
     current: Current,
 }
 
+impl<Target, Remaining, Current> Default for QueueBuilding<Target, Remaining, Current>
+where
+    Target: StQueue,
+    Remaining: StQueue,
+    Current: StQueue + Default,
+{
+    fn default() -> Self {
+        Self::new(Current::default())
+    }
+}
+
+impl<Target, Remaining, Current> Debug for QueueBuilding<Target, Remaining, Current>
+where
+    Target: StQueue,
+    Remaining: StQueue,
+    Current: StQueue + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("QueueBuilding")
+            .field("current", &self.current)
+            .field("remaining", &Self::REMAINING)
+            .finish()
+    }
+}
+
 impl<Target, Remaining, Current> QueueBuilding<Target, Remaining, Current>
 where
     Target: StQueue,
@@ -155,6 +392,66 @@ where
         QueueBuilding::new(self.current.push(element))
     }
 
+    /// Tries to convert the `value` into the next element and push it to build the target type.
+    ///
+    /// Returns the error pointing to the position and type of the element if the conversion fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    /// use orx_meta::queue_of;
+    ///
+    /// type MyQueue = queue_of!(u32, u8, i8);
+    ///
+    /// let instance = QueueBuilder::<MyQueue>::new()
+    ///     .push(42)
+    ///     .try_push(7u64)
+    ///     .and_then(|b| b.try_push(-1i64))
+    ///     .map(|b| b.finish());
+    /// assert_eq!(instance.map(|x| x.into_tuple()), Ok((42, 7, -1)));
+    ///
+    /// let error = QueueBuilder::<MyQueue>::new()
+    ///     .push(42)
+    ///     .try_push(7u64)
+    ///     .and_then(|b| b.try_push(-1000i64))
+    ///     .err()
+    ///     .unwrap();
+    /// assert_eq!((error.position, error.type_name), (2, "i8"));
+    /// ```
+    pub fn try_push<V>(
+        self,
+        value: V,
+    ) -> Result<NextBuilding<Target, Remaining, Current>, SlotError<V::Error>>
+    where
+        V: TryInto<Remaining::Front>,
+    {
+        match value.try_into() {
+            Ok(element) => Ok(self.push(element)),
+            Err(error) => Err(SlotError::new::<Remaining::Front>(Current::LEN, error)),
+        }
+    }
+
+    /// Pushes the default value of the next element to build the target type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    /// use orx_meta::queue_of;
+    ///
+    /// type MyQueue = queue_of!(u32, bool, char);
+    ///
+    /// let instance = QueueBuilder::<MyQueue>::new().push(42).skip().push('x').finish();
+    /// assert_eq!(instance.as_tuple(), (&42, &false, &'x'));
+    /// ```
+    pub fn skip(self) -> QueueBuilding<Target, Remaining::Back, Current::PushBack<Remaining::Front>>
+    where
+        Remaining::Front: Default,
+    {
+        self.push(Default::default())
+    }
+
     /// Completes the builder and returns the built target type.
     pub fn finish(self) -> Current
     where
@@ -162,4 +459,202 @@ where
     {
         self.current
     }
+
+    /// Completes the builder by filling the remaining elements with their default values, and returns
+    /// the built target type.
+    ///
+    /// Only the types of the remaining elements are required to implement `Default`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    /// use orx_meta::queue_of;
+    ///
+    /// struct NoDefault(u32);
+    ///
+    /// type MyQueue = queue_of!(NoDefault, bool, String);
+    ///
+    /// let instance = QueueBuilder::<MyQueue>::new()
+    ///     .push(NoDefault(42))
+    ///     .finish_with_defaults();
+    /// assert_eq!(instance.front().0, 42);
+    /// assert_eq!(instance.back().as_tuple(), (&false, &String::new()));
+    /// ```
+    ///
+    /// Elements without a default value cannot be skipped.
+    ///
+    /// ```compile_fail
+    /// use orx_meta::queue::*;
+    /// use orx_meta::queue_of;
+    ///
+    /// struct NoDefault(u32);
+    ///
+    /// type MyQueue = queue_of!(bool, NoDefault);
+    ///
+    /// let instance = QueueBuilder::<MyQueue>::new()
+    ///     .push(true)
+    ///     .finish_with_defaults();
+    /// ```
+    pub fn finish_with_defaults(self) -> Target
+    where
+        Target: FillWithDefaults<Current>,
+    {
+        Target::fill_with_defaults(self.current)
+    }
+}
+
+/// Progress of a queue builder, which is common to the empty [`QueueBuilder`] and the [`QueueBuilding`]
+/// in progress, as well as to the builders created by the [`define_queue`] macro.
+///
+/// It allows to drive a builder step by step in a generic context, such as by a CLI wizard which
+/// prompts for the next element.
+///
+/// [`define_queue`]: crate::define_queue
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::queue::*;
+/// use orx_meta::queue_of;
+///
+/// fn prompt<B: BuilderProgress>(builder: &B) -> String {
+///     match builder.next_type_name() {
+///         Some(name) => format!("[{}/{}] enter {name}", B::PUSHED + 1, B::PUSHED + B::REMAINING),
+///         None => "done".to_string(),
+///     }
+/// }
+///
+/// type MyQueue = queue_of!(u32, bool);
+///
+/// let builder = QueueBuilder::<MyQueue>::new();
+/// assert_eq!(prompt(&builder), "[1/2] enter u32");
+///
+/// let builder = builder.push(42);
+/// assert_eq!(prompt(&builder), "[2/2] enter bool");
+///
+/// let builder = builder.push(true);
+/// assert_eq!(prompt(&builder), "done");
+/// ```
+pub trait BuilderProgress {
+    /// Number of elements pushed so far.
+    const PUSHED: usize;
+
+    /// Number of elements remaining to be pushed before the builder can be finished.
+    const REMAINING: usize;
+
+    /// Returns the name of the type of the next element to be pushed, obtained by `core::any::type_name`;
+    /// or None if all elements are already pushed.
+    fn next_type_name(&self) -> Option<&'static str>;
+
+    /// Returns the number of elements pushed so far; i.e., [`PUSHED`].
+    ///
+    /// [`PUSHED`]: BuilderProgress::PUSHED
+    fn pushed(&self) -> usize {
+        Self::PUSHED
+    }
+
+    /// Returns the number of elements remaining to be pushed; i.e., [`REMAINING`].
+    ///
+    /// [`REMAINING`]: BuilderProgress::REMAINING
+    fn remaining(&self) -> usize {
+        Self::REMAINING
+    }
+}
+
+impl<Target> BuilderProgress for QueueBuilder<Target>
+where
+    Target: StQueue,
+{
+    const PUSHED: usize = 0;
+
+    const REMAINING: usize = Target::LEN;
+
+    fn next_type_name(&self) -> Option<&'static str> {
+        Some(core::any::type_name::<Target::Front>())
+    }
+}
+
+impl<Target, Remaining, Current> BuilderProgress for QueueBuilding<Target, Remaining, Current>
+where
+    Target: StQueue,
+    Remaining: StQueue,
+    Current: StQueue,
+{
+    const PUSHED: usize = Current::LEN;
+
+    // saturating since the public `Default` does not require `Current` to be a prefix of `Target`
+    const REMAINING: usize = Target::LEN.saturating_sub(Current::LEN);
+
+    fn next_type_name(&self) -> Option<&'static str> {
+        match Self::REMAINING {
+            0 => None,
+            _ => Some(core::any::type_name::<Remaining::Front>()),
+        }
+    }
+}
+
+/// A queue which can be created from its `Prefix` queue by filling the remaining elements with their
+/// default values.
+///
+/// The `Prefix` is either `()` representing no elements, or a queue whose element types match the first
+/// elements of this queue.
+///
+/// This is the building block of [`finish_with_defaults`] method of the [`QueueBuilder`].
+///
+/// [`finish_with_defaults`]: crate::queue::QueueBuilder::finish_with_defaults
+/// [`QueueBuilder`]: crate::queue::QueueBuilder
+pub trait FillWithDefaults<Prefix>: StQueue {
+    /// Creates the queue from the `prefix` by filling the remaining elements with their default values.
+    fn fill_with_defaults(prefix: Prefix) -> Self;
+}
+
+impl<T> FillWithDefaults<()> for QueueSingle<T>
+where
+    T: Default,
+{
+    #[inline(always)]
+    fn fill_with_defaults(_: ()) -> Self {
+        QueueSingle::new(T::default())
+    }
+}
+
+impl<T> FillWithDefaults<QueueSingle<T>> for QueueSingle<T> {
+    #[inline(always)]
+    fn fill_with_defaults(prefix: QueueSingle<T>) -> Self {
+        prefix
+    }
+}
+
+impl<F, B> FillWithDefaults<()> for Queue<F, B>
+where
+    F: Default,
+    B: FillWithDefaults<()>,
+{
+    #[inline(always)]
+    fn fill_with_defaults(_: ()) -> Self {
+        Queue::from_fb(F::default(), B::fill_with_defaults(()))
+    }
+}
+
+impl<F, B> FillWithDefaults<QueueSingle<F>> for Queue<F, B>
+where
+    B: FillWithDefaults<()>,
+{
+    #[inline(always)]
+    fn fill_with_defaults(prefix: QueueSingle<F>) -> Self {
+        Queue::from_fb(prefix.pop(), B::fill_with_defaults(()))
+    }
+}
+
+impl<F, B, P> FillWithDefaults<Queue<F, P>> for Queue<F, B>
+where
+    B: FillWithDefaults<P>,
+    P: StQueue,
+{
+    #[inline(always)]
+    fn fill_with_defaults(prefix: Queue<F, P>) -> Self {
+        let (f, p) = prefix.pop();
+        Queue::from_fb(f, B::fill_with_defaults(p))
+    }
 }
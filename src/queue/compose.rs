@@ -0,0 +1,43 @@
+/// Creates a [`Pipeline`] from the given functions, which are applied from first to last.
+///
+/// `compose!(f1, f2, f3)` is equivalent to `QueueSingle::new(f1).then(f2).then(f3)`, which creates
+/// the queue `Queue<F1, Queue<F2, QueueSingle<F3>>>` of the functions.
+///
+/// Each function is validated to take the output of the previous function as its input as it is added.
+/// Therefore, only the input type of the first function needs to be known, while input types of the
+/// remaining closures are inferred.
+///
+/// [`Pipeline`]: crate::queue::Pipeline
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::compose;
+/// use orx_meta::queue::*;
+///
+/// fn parse(x: &str) -> Result<u32, core::num::ParseIntError> {
+///     x.trim().parse()
+/// }
+///
+/// let pipeline = compose!(parse, |x| x.map(|x| x * 10), |x| x.unwrap_or_default());
+///
+/// assert_eq!(pipeline.run(" 42 "), 420);
+/// assert_eq!(pipeline.run("foo"), 0);
+///
+/// let single = compose!(|x: i32| -x);
+/// assert_eq!(single.run(3), -3);
+/// ```
+#[macro_export]
+macro_rules! compose {
+    (@then [$($acc:tt)*]) => {
+        $($acc)*
+    };
+
+    (@then [$($acc:tt)*] $f:expr $(, $rest:expr)*) => {
+        $crate::compose!(@then [$crate::queue::Pipeline::then($($acc)*, $f)] $($rest),*)
+    };
+
+    ($f:expr $(, $rest:expr)* $(,)?) => {
+        $crate::compose!(@then [$crate::queue::QueueSingle::new($f)] $($rest),*)
+    };
+}
@@ -15,6 +15,67 @@
 ///   In other words, we can add trait bounds to elements that can be pushed to the queue. This allows us to achieve
 ///   zero-cost composition.
 ///
+/// Although each invocation defines a distinct queue trait, all generated queues implement the common [`QueueLike`]
+/// trait; therefore, utilities written once over [`QueueLike`] work with every queue family.
+/// Further, generated queues can be converted into the base [`Queue`] and [`QueueSingle`] types and back with the same
+/// elements via [`IntoBase`], [`FromBase`] or `From`; the conversion from the base queue compiles only if all elements
+/// satisfy the element bounds.
+///
+/// Similar to the base queues, generated queues implement a flat list-style `Debug`, such as `[42, true, 'x']`, when all
+/// elements are `Debug`. The `format` option selects the formatting traits to implement: `Debug` and/or `Display`, the
+/// latter of which separates the elements by `", "` or by the separator given to [`display_with`]. Traits given to this
+/// option are required by all elements, and hence, by all queues. See [`DebugElements`] and [`DisplayElements`].
+///
+/// Queues of references to the elements are obtained by [`AsRefs`] and [`AsMuts`] whenever references to the elements
+/// satisfy the element bounds; and these are converted back into queues of values by [`Cloned`] or [`Copied`].
+///
+/// # Options
+///
+/// The macro accepts the following options, each terminated by a semicolon. Only the `queue` option is required;
+/// all others are optional and options might be provided in any order.
+///
+/// * `queue => [Trait ; Single, Multi];` names the queue trait and its single and multiple element implementations.
+/// * `lt => ['a, 'b];` lifetime parameters of the queue types.
+/// * `generics => [T: Bound1 | Bound2, U];` generic type parameters of the queue types.
+/// * `consts => [const D: usize];` const generic parameters of the queue types.
+/// * `where => [T: Add<Output = T> + Copy];` additional where predicates, such as those relating the generic parameters,
+///   which are added to the queue trait, queue types and the builder.
+/// * `elements => [Bound1 + Bound2];` trait bounds that all elements of the queue must satisfy.
+/// * `repr => C;` adds `#[repr(C)]` to the queue types.
+/// * `derive => [Clone, PartialEq, Hash];` derives of the queue types, which replace the default derives
+///   `Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default`. As with any derive, each trait is implemented only
+///   when the elements implement it; and the comparisons are lexicographic from front to back.
+/// * `format => [Debug, Display];` flat formatting traits implemented by the queue types, which are also required by
+///   the elements. When omitted, only `Debug` is implemented, and only for queues of `Debug` elements.
+///   `format => [];` implements neither, which allows to derive or implement them manually.
+/// * `vis => pub(crate);` visibility of the queue trait, queue types and the builder, which is `pub` by default.
+/// * `queue_of => name;` defines the `queue_of` macro with the given name.
+/// * `builder => Name;` defines the queue builder with the given name.
+///
+/// Attributes, including doc comments, can be attached to the generated items by writing them in front of their
+/// names in the `queue` and `builder` options.
+///
+/// Note that generated code uses the type parameter names `T`, `F` and `B`; hence, these names cannot be used in `generics`.
+///
+/// Unknown or repeated options do not compile.
+///
+/// ```compile_fail
+/// orx_meta::define_queue!(
+///     queue => [ MyQueue ; MySingleQueue, MyMultiQueue ];
+///     builder => MyBuilder;
+///     builder => MyOtherBuilder;
+/// );
+/// ```
+///
+/// Similarly, formatting traits other than `Debug` and `Display` are rejected.
+///
+/// ```compile_fail
+/// orx_meta::define_queue!(
+///     format => [ Debug, LowerHex ];
+///     queue => [ MyQueue ; MySingleQueue, MyMultiQueue ];
+/// );
+/// ```
+///
 /// # Example - Recreating and Renaming
 ///
 /// The following is the simplest usage of this macro.
@@ -46,14 +107,43 @@
 /// assert_eq!(queue.as_tuple(), (&42, &true, &'x'));
 /// ```
 ///
+/// The generated builder is a peer of the [`QueueBuilder`]. It carries the element trait bounds as well as
+/// the lifetime and generic parameters of the queue; and provides `try_push`, `skip`, and `Default` and `Debug`
+/// implementations. Both the builder and its building states implement [`BuilderProgress`], which provides the
+/// progress constants `PUSHED` and `REMAINING`.
+///
+/// ```
+/// use orx_meta::queue::BuilderProgress;
+///
+/// orx_meta::define_queue!(
+///     queue => [ MyQueue ; MySingleQueue, MyMultiQueue ];
+///     queue_of => q_of;
+///     builder => MyQueueBuilder;
+/// );
+///
+/// let builder = MyQueueBuilder::<q_of!(u32, bool, u8)>::default().push(42).skip();
+/// assert_eq!(builder.remaining(), 1);
+/// assert_eq!(builder.next_type_name(), Some("u8"));
+///
+/// let queue = builder.try_push(7u64).map(|b| b.finish());
+/// assert_eq!(queue.map(|q| q.into_tuple()), Ok((42, false, 7)));
+/// ```
+///
 /// # Example - Trait Bounds
 ///
 /// The main purpose of this macro; however, is to add trait bounds to the elements that can be contained by the queue.
 ///
 /// Assume for instance, we want all our elements to implement the `Draw` trait.
 ///
-/// We can define this by providing the `elements => [Draw]` block to the macro. Note that we can define a comma-separated
-/// list of traits within the brackets.
+/// We can define this by providing the `elements => [Draw]` block to the macro. Within the brackets, we can write any bounds
+/// that could follow `T:` in a where clause, such as `core::fmt::Debug + Clone + Send + Sync`. Bounds from other crates given
+/// by their paths, associated type bindings such as `Iterator<Item = u32>` and higher-ranked bounds such as `for<'a> Visit<'a>`
+/// are all supported. Bounds might also be separated by `|` rather than `+`, such as `Draw | Clone`.
+///
+/// Elements are stored by value; therefore, pushed elements are always `Sized`. Adding `?Sized` to the bounds allows the
+/// back element to be unsized, such as a `dyn Draw` or a slice, which is obtained by unsizing coercion behind a reference
+/// or a `Box`. Queues with an unsized back element can be accessed by reference and compose the behavior of their elements;
+/// however, they do not implement the queue trait since pushing or popping requires them to be `Sized`.
 ///
 /// In addition to elements of the queue, the design requires the queue types to implement the `Draw` trait as well.
 /// This is the central idea of zero-composition.
@@ -150,6 +240,286 @@
 /// // SelectBox { width: 10, height: 6, options: ["This", "that"] }
 /// ```
 ///
+/// # Example - Element Bounds
+///
+/// ```
+/// pub trait Visit<'a> {
+///     fn visit(&self, text: &'a str) -> &'a str;
+/// }
+///
+/// orx_meta::define_queue!(
+///     elements => [ for<'a> Visit<'a> + core::fmt::Debug + Send + Sync ];
+///     format => [ Debug ];
+///     queue => [ StVisitors ; VisitorsSingle, Visitors ];
+/// );
+///
+/// #[derive(Debug)]
+/// struct Trim;
+///
+/// impl<'a> Visit<'a> for Trim {
+///     fn visit(&self, text: &'a str) -> &'a str {
+///         text.trim()
+///     }
+/// }
+///
+/// impl<'a, F: for<'b> Visit<'b> + core::fmt::Debug + Send + Sync> Visit<'a> for VisitorsSingle<F> {
+///     fn visit(&self, text: &'a str) -> &'a str {
+///         self.f.visit(text)
+///     }
+/// }
+///
+/// impl<'a, F: for<'b> Visit<'b> + core::fmt::Debug + Send + Sync, B: StVisitors> Visit<'a> for Visitors<F, B> {
+///     fn visit(&self, text: &'a str) -> &'a str {
+///         self.b.visit(self.f.visit(text))
+///     }
+/// }
+///
+/// let visitors = Visitors::new(Trim).push(Trim);
+/// assert_eq!(visitors.visit("  abc "), "abc");
+/// ```
+///
+/// With a `?Sized` bound, the back element of the queue can be a trait object or a slice.
+///
+/// ```
+/// pub trait Area {
+///     fn area(&self) -> f64;
+/// }
+///
+/// impl Area for f64 {
+///     fn area(&self) -> f64 {
+///         *self
+///     }
+/// }
+///
+/// impl Area for [f64] {
+///     fn area(&self) -> f64 {
+///         self.iter().sum()
+///     }
+/// }
+///
+/// impl<const N: usize> Area for [f64; N] {
+///     fn area(&self) -> f64 {
+///         self.iter().sum()
+///     }
+/// }
+///
+/// orx_meta::define_queue!(
+///     elements => [ Area + ?Sized ];
+///     queue => [ StAreas ; AreasSingle, Areas ];
+/// );
+///
+/// impl<F: Area + ?Sized> Area for AreasSingle<F> {
+///     fn area(&self) -> f64 {
+///         self.f.area()
+///     }
+/// }
+///
+/// impl<F: Area, B: Area + ?Sized> Area for Areas<F, B> {
+///     fn area(&self) -> f64 {
+///         self.f.area() + self.b.area()
+///     }
+/// }
+///
+/// let areas: Box<Areas<f64, AreasSingle<dyn Area>>> = Box::new(Areas::new(1.0).push(2.0));
+/// assert_eq!(areas.area(), 3.0);
+///
+/// let areas: &Areas<f64, AreasSingle<[f64]>> = &Areas::new(1.0).push([2.0, 3.0]);
+/// assert_eq!(areas.back().front(), &[2.0, 3.0]);
+/// ```
+///
+/// # Example - Const Generics
+///
+/// Const generic parameters of the queue are provided by the optional `consts => [...]` block, which follows the
+/// lifetime and generic parameters. Then, element bounds, the queue types, the builder and the `queue_of` macro all
+/// share these parameters.
+///
+/// ```
+/// pub trait Evaluate<const D: usize> {
+///     fn evaluate(&self, x: &[f64; D]) -> f64;
+/// }
+///
+/// pub struct Norm1;
+///
+/// impl<const D: usize> Evaluate<D> for Norm1 {
+///     fn evaluate(&self, x: &[f64; D]) -> f64 {
+///         x.iter().map(|x| x.abs()).sum()
+///     }
+/// }
+///
+/// orx_meta::define_queue!(
+///     consts => [const D: usize];
+///     elements => [Evaluate<D>];
+///     queue => [StKernel; KernelSingle, Kernel];
+///     queue_of => kernel_of;
+///     builder => KernelBuilder;
+/// );
+///
+/// impl<const D: usize, F: Evaluate<D>> Evaluate<D> for KernelSingle<D, F> {
+///     fn evaluate(&self, x: &[f64; D]) -> f64 {
+///         self.f.evaluate(x)
+///     }
+/// }
+///
+/// impl<const D: usize, F: Evaluate<D>, B: StKernel<D>> Evaluate<D> for Kernel<D, F, B> {
+///     fn evaluate(&self, x: &[f64; D]) -> f64 {
+///         self.f.evaluate(x) + self.b.evaluate(x)
+///     }
+/// }
+///
+/// type Kernels<const D: usize> = kernel_of!(Norm1, Norm1);
+///
+/// let kernel = KernelBuilder::<2, Kernels<2>>::new().push(Norm1).push(Norm1).finish();
+/// assert_eq!(kernel.evaluate(&[1.0, -2.0]), 6.0);
+/// ```
+///
+/// # Example - Where Clauses
+///
+/// Constraints which cannot be expressed by the `generics` block can be provided by the `where` block.
+///
+/// ```
+/// use core::ops::Add;
+///
+/// pub trait Total<N> {
+///     fn total(&self) -> N;
+/// }
+///
+/// impl Total<f32> for f32 {
+///     fn total(&self) -> f32 {
+///         *self
+///     }
+/// }
+///
+/// orx_meta::define_queue!(
+///     generics => [N];
+///     where => [N: Add<Output = N> + Copy,];
+///     elements => [Total<N>];
+///     queue => [StTotals; TotalsSingle, Totals];
+/// );
+///
+/// impl<N: Add<Output = N> + Copy, F: Total<N>> Total<N> for TotalsSingle<N, F> {
+///     fn total(&self) -> N {
+///         self.f.total()
+///     }
+/// }
+///
+/// impl<N: Add<Output = N> + Copy, F: Total<N>, B: StTotals<N>> Total<N> for Totals<N, F, B> {
+///     fn total(&self) -> N {
+///         self.f.total() + self.b.total()
+///     }
+/// }
+///
+/// let totals = Totals::new(1.5).push(2.0);
+/// assert_eq!(totals.total(), 3.5);
+/// ```
+///
+/// # Example - Derives, Visibility and Attributes
+///
+/// ```
+/// mod keys {
+///     orx_meta::define_queue!(
+///         derive => [Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default];
+///         vis => pub(crate);
+///         queue => [
+///             /// A composite key.
+///             StKey;
+///             /// A composite key with one element.
+///             KeySingle,
+///             /// A composite key with multiple elements.
+///             Key
+///         ];
+///         queue_of => key_of;
+///         builder =>
+///             /// Builder of composite keys.
+///             #[must_use]
+///             KeyBuilder;
+///     );
+///
+///     pub(crate) type MyKey = key_of!(u32, String);
+/// }
+///
+/// use keys::*;
+///
+/// let a = KeyBuilder::<MyKey>::new().push(1).push("b".to_string()).finish();
+/// let b = KeyBuilder::<MyKey>::new().push(1).push("c".to_string()).finish();
+/// assert!(a < b);
+/// assert!(MyKey::default() < a);
+/// ```
+///
+/// # Example - Zip
+///
+/// Queues created by the macro implement [`Zip`] and [`Unzip`] whenever the pairs of elements
+/// satisfy the element bounds as well.
+///
+/// ```
+/// use orx_meta::queue::{Unzip, Zip};
+///
+/// pub trait Area {
+///     fn area(&self) -> u32;
+/// }
+///
+/// impl Area for u32 {
+///     fn area(&self) -> u32 {
+///         *self
+///     }
+/// }
+///
+/// impl<X: Area, Y: Area> Area for (X, Y) {
+///     fn area(&self) -> u32 {
+///         self.0.area() * self.1.area()
+///     }
+/// }
+///
+/// orx_meta::define_queue!(
+///     elements => [ Area ];
+///     queue => [ StAreas ; AreasSingle, Areas ];
+/// );
+///
+/// impl<F: Area> Area for AreasSingle<F> {
+///     fn area(&self) -> u32 {
+///         self.f.area()
+///     }
+/// }
+///
+/// impl<F: Area, B: StAreas> Area for Areas<F, B> {
+///     fn area(&self) -> u32 {
+///         self.f.area() + self.b.area()
+///     }
+/// }
+///
+/// let widths = Areas::new(2u32).push(3u32);
+/// let heights = Areas::new(4u32).push(5u32);
+///
+/// let rectangles = widths.zip(heights);
+/// assert_eq!(rectangles.area(), 2 * 4 + 3 * 5);
+///
+/// let (widths, heights) = rectangles.unzip();
+/// assert_eq!(widths.as_tuple(), (&2, &3));
+/// assert_eq!(heights.as_tuple(), (&4, &5));
+/// ```
+///
+/// # Example - Stable Layout
+///
+/// The optional `repr => C` block adds the `#[repr(C)]` attribute to both queue structs, which guarantees
+/// that the element in the front `f` is followed by the back queue `b`. Then, layout of a queue matches
+/// that of a C struct with the same fields, which can be verified by the [`assert_layout_matches`] macro.
+///
+/// ```
+/// orx_meta::define_queue!(
+///     repr => C;
+///     queue => [ StVertex ; VertexSingle, Vertex ];
+///     queue_of => vertex_of;
+/// );
+///
+/// #[repr(C)]
+/// struct Vertex3 {
+///     x: f32,
+///     y: f32,
+///     z: f32,
+/// }
+///
+/// orx_meta::assert_layout_matches!(vertex_of!(f32, f32, f32) { f, b }, Vertex3 { x, y, z });
+/// ```
+///
 ///
 ///
 ///
@@ -159,361 +529,752 @@
 /// [`QueueSingle`]: crate::queue::QueueSingle
 /// [`Queue`]: crate::queue::Queue
 /// [`QueueBuilder`]: crate::queue::QueueBuilder
+/// [`BuilderProgress`]: crate::queue::BuilderProgress
+/// [`QueueLike`]: crate::queue::QueueLike
+/// [`IntoBase`]: crate::queue::IntoBase
+/// [`FromBase`]: crate::queue::FromBase
+/// [`DebugElements`]: crate::queue::DebugElements
+/// [`DisplayElements`]: crate::queue::DisplayElements
+/// [`display_with`]: crate::queue::DisplayElements::display_with
+/// [`AsRefs`]: crate::queue::AsRefs
+/// [`AsMuts`]: crate::queue::AsMuts
+/// [`Cloned`]: crate::queue::Cloned
+/// [`Copied`]: crate::queue::Copied
 /// [`queue_of`]: crate::queue_of
+/// [`Zip`]: crate::queue::Zip
+/// [`Unzip`]: crate::queue::Unzip
+/// [`assert_layout_matches`]: crate::assert_layout_matches
 #[macro_export]
 macro_rules! define_queue {
-    (
-        lt => [$($g_lt:tt), *];
-        generics => [ $( $g:tt $( : $( $g_bnd:ident $( < $( $g_bnd_g:tt ),* > )? )| * )? ), * ];
-        elements => [ $( $el_bnd:ident $( < $( $el_bnd_g:tt ),* > )? )| * ];
-        queue => [$q:ident ; $empty:ident, $pair:ident];
+    ($($options:tt)*) => {
+        $crate::define_queue_impl!(
+            @parse
+            lt [] generics [] consts [] where [] elements [] repr [] derive [@default] format [@default] vis [@default]
+            queue [] queue_of [] builder []
+            $($options)*
+        );
+    };
+}
 
-        queue_of => $queue_of:ident;
-        builder => $builder:ident;
+#[doc(hidden)]
+#[macro_export]
+macro_rules! define_queue_impl {
+    // options are parsed independently of each other and their order
+
+    (
+        @parse
+        lt []
+        generics [$($generics:tt)*]
+        consts [$($consts:tt)*]
+        where [$($where_clause:tt)*]
+        elements [$($elements:tt)*]
+        repr [$($repr:tt)*]
+        derive [$($derive:tt)*]
+        format [$($format:tt)*]
+        vis [$($vis:tt)*]
+        queue [$($queue:tt)*]
+        queue_of [$($queue_of:tt)*]
+        builder [$($builder:tt)*]
+        lt => [$($value:tt)*]; $($rest:tt)*
     ) => {
-        $crate::define_queue_core!(
-            lt => [$($g_lt), *];
-            generics => [ $( $g $( : $( $g_bnd $( < $( $g_bnd_g ),* > )? )| * )? ), * ];
-            elements => [ $( $el_bnd $( < $( $el_bnd_g ),* > )? )| * ];
-            queue => [$q ; $empty, $pair];
-        );
-        $crate::define_queue_of!(
-            lt => [$($g_lt), *];
-            generics => [ $( $g $( : $( $g_bnd $( < $( $g_bnd_g ),* > )? )| * )? ), * ];
-            queue => [$q ; $empty, $pair];
-            queue_of => $queue_of;
+        $crate::define_queue_impl!(
+            @parse
+            lt [$($value)*]
+            generics [$($generics)*]
+            consts [$($consts)*]
+            where [$($where_clause)*]
+            elements [$($elements)*]
+            repr [$($repr)*]
+            derive [$($derive)*]
+            format [$($format)*]
+            vis [$($vis)*]
+            queue [$($queue)*]
+            queue_of [$($queue_of)*]
+            builder [$($builder)*]
+            $($rest)*
         );
+    };
 
-        $crate::define_queue_builder!(
-            lt => [$($g_lt), *];
-            generics => [ $( $g $( : $( $g_bnd $( < $( $g_bnd_g ),* > )? )| * )? ), * ];
-            queue => [$q ; $empty, $pair];
-            builder => $builder;
+    (
+        @parse
+        lt [$($lt:tt)*]
+        generics []
+        consts [$($consts:tt)*]
+        where [$($where_clause:tt)*]
+        elements [$($elements:tt)*]
+        repr [$($repr:tt)*]
+        derive [$($derive:tt)*]
+        format [$($format:tt)*]
+        vis [$($vis:tt)*]
+        queue [$($queue:tt)*]
+        queue_of [$($queue_of:tt)*]
+        builder [$($builder:tt)*]
+        generics => [$($value:tt)*]; $($rest:tt)*
+    ) => {
+        $crate::define_queue_impl!(
+            @parse
+            lt [$($lt)*]
+            generics [$($value)*]
+            consts [$($consts)*]
+            where [$($where_clause)*]
+            elements [$($elements)*]
+            repr [$($repr)*]
+            derive [$($derive)*]
+            format [$($format)*]
+            vis [$($vis)*]
+            queue [$($queue)*]
+            queue_of [$($queue_of)*]
+            builder [$($builder)*]
+            $($rest)*
         );
+    };
 
-        $crate::define_nonempty_queue_tuple_transformation!(
-            lt => [$($g_lt), *];
-            generics => [ $( $g $( : $( $g_bnd $( < $( $g_bnd_g ),* > )? )| * )? ), * ];
-            elements => [ $( $el_bnd $( < $( $el_bnd_g ),* > )? )| * ];
-            queue => [$q ; $empty, $pair];
+    (
+        @parse
+        lt [$($lt:tt)*]
+        generics [$($generics:tt)*]
+        consts []
+        where [$($where_clause:tt)*]
+        elements [$($elements:tt)*]
+        repr [$($repr:tt)*]
+        derive [$($derive:tt)*]
+        format [$($format:tt)*]
+        vis [$($vis:tt)*]
+        queue [$($queue:tt)*]
+        queue_of [$($queue_of:tt)*]
+        builder [$($builder:tt)*]
+        consts => [$($value:tt)*]; $($rest:tt)*
+    ) => {
+        $crate::define_queue_impl!(
+            @parse
+            lt [$($lt)*]
+            generics [$($generics)*]
+            consts [$($value)*]
+            where [$($where_clause)*]
+            elements [$($elements)*]
+            repr [$($repr)*]
+            derive [$($derive)*]
+            format [$($format)*]
+            vis [$($vis)*]
+            queue [$($queue)*]
+            queue_of [$($queue_of)*]
+            builder [$($builder)*]
+            $($rest)*
         );
     };
 
-    // core
     (
-        queue => [$q:ident ; $empty:ident, $pair:ident];
+        @parse
+        lt [$($lt:tt)*]
+        generics [$($generics:tt)*]
+        consts [$($consts:tt)*]
+        where []
+        elements [$($elements:tt)*]
+        repr [$($repr:tt)*]
+        derive [$($derive:tt)*]
+        format [$($format:tt)*]
+        vis [$($vis:tt)*]
+        queue [$($queue:tt)*]
+        queue_of [$($queue_of:tt)*]
+        builder [$($builder:tt)*]
+        where => [$($value:tt)*]; $($rest:tt)*
     ) => {
-        $crate::define_queue_core!(
-            lt => [];
-            generics => [];
-            elements => [];
-            queue => [$q ; $empty, $pair];
-        );
-        $crate::define_nonempty_queue_tuple_transformation!(
-            lt => [];
-            generics => [];
-            elements => [];
-            queue => [$q ; $empty, $pair];
+        $crate::define_queue_impl!(
+            @parse
+            lt [$($lt)*]
+            generics [$($generics)*]
+            consts [$($consts)*]
+            where [$($value)*]
+            elements [$($elements)*]
+            repr [$($repr)*]
+            derive [$($derive)*]
+            format [$($format)*]
+            vis [$($vis)*]
+            queue [$($queue)*]
+            queue_of [$($queue_of)*]
+            builder [$($builder)*]
+            $($rest)*
         );
     };
 
-    // core - elements
     (
-        elements => [ $( $el_bnd:ident $( < $( $el_bnd_g:tt ),* > )? )| * ];
-        queue => [$q:ident ; $empty:ident, $pair:ident];
+        @parse
+        lt [$($lt:tt)*]
+        generics [$($generics:tt)*]
+        consts [$($consts:tt)*]
+        where [$($where_clause:tt)*]
+        elements []
+        repr [$($repr:tt)*]
+        derive [$($derive:tt)*]
+        format [$($format:tt)*]
+        vis [$($vis:tt)*]
+        queue [$($queue:tt)*]
+        queue_of [$($queue_of:tt)*]
+        builder [$($builder:tt)*]
+        elements => [$($value:tt)*]; $($rest:tt)*
     ) => {
-        $crate::define_queue_core!(
-            lt => [];
-            generics => [];
-            elements => [ $( $el_bnd $( < $( $el_bnd_g ),* > )? )| * ];
-            queue => [$q ; $empty, $pair];
-        );
-        $crate::define_nonempty_queue_tuple_transformation!(
-            lt => [];
-            generics => [];
-            elements => [ $( $el_bnd $( < $( $el_bnd_g ),* > )? )| * ];
-            queue => [$q ; $empty, $pair];
+        $crate::define_queue_impl!(
+            @parse
+            lt [$($lt)*]
+            generics [$($generics)*]
+            consts [$($consts)*]
+            where [$($where_clause)*]
+            elements [$($value)*]
+            repr [$($repr)*]
+            derive [$($derive)*]
+            format [$($format)*]
+            vis [$($vis)*]
+            queue [$($queue)*]
+            queue_of [$($queue_of)*]
+            builder [$($builder)*]
+            $($rest)*
         );
     };
 
-    // core - lifetime elements
     (
-        lt => [$($g_lt:tt), *];
-        elements => [ $( $el_bnd:ident $( < $( $el_bnd_g:tt ),* > )? )| * ];
-        queue => [$q:ident ; $empty:ident, $pair:ident];
+        @parse
+        lt [$($lt:tt)*]
+        generics [$($generics:tt)*]
+        consts [$($consts:tt)*]
+        where [$($where_clause:tt)*]
+        elements [$($elements:tt)*]
+        repr []
+        derive [$($derive:tt)*]
+        format [$($format:tt)*]
+        vis [$($vis:tt)*]
+        queue [$($queue:tt)*]
+        queue_of [$($queue_of:tt)*]
+        builder [$($builder:tt)*]
+        repr => $value:ident; $($rest:tt)*
     ) => {
-        $crate::define_queue_core!(
-            lt => [$($g_lt), *];
-            generics => [];
-            elements => [ $( $el_bnd $( < $( $el_bnd_g ),* > )? )| * ];
-            queue => [$q ; $empty, $pair];
-        );
-        $crate::define_nonempty_queue_tuple_transformation!(
-            lt => [$($g_lt), *];
-            generics => [];
-            elements => [ $( $el_bnd $( < $( $el_bnd_g ),* > )? )| * ];
-            queue => [$q ; $empty, $pair];
+        $crate::define_queue_impl!(
+            @parse
+            lt [$($lt)*]
+            generics [$($generics)*]
+            consts [$($consts)*]
+            where [$($where_clause)*]
+            elements [$($elements)*]
+            repr [$value]
+            derive [$($derive)*]
+            format [$($format)*]
+            vis [$($vis)*]
+            queue [$($queue)*]
+            queue_of [$($queue_of)*]
+            builder [$($builder)*]
+            $($rest)*
         );
     };
 
-    // # queue_of
-
-    // core
     (
-        queue => [$q:ident ; $empty:ident, $pair:ident];
-        queue_of => $queue_of:ident;
+        @parse
+        lt [$($lt:tt)*]
+        generics [$($generics:tt)*]
+        consts [$($consts:tt)*]
+        where [$($where_clause:tt)*]
+        elements [$($elements:tt)*]
+        repr [$($repr:tt)*]
+        derive [@default]
+        format [$($format:tt)*]
+        vis [$($vis:tt)*]
+        queue [$($queue:tt)*]
+        queue_of [$($queue_of:tt)*]
+        builder [$($builder:tt)*]
+        derive => [$($value:tt)*]; $($rest:tt)*
     ) => {
-        $crate::define_queue_core!(
-            lt => [];
-            generics => [];
-            elements => [];
-            queue => [$q ; $empty, $pair];
-        );
-        $crate::define_nonempty_queue_tuple_transformation!(
-            lt => [];
-            generics => [];
-            elements => [];
-            queue => [$q ; $empty, $pair];
-        );
-        $crate::define_queue_of!(
-            lt => [];
-            generics => [];
-            queue => [$q ; $empty, $pair];
-            queue_of => $queue_of;
+        $crate::define_queue_impl!(
+            @parse
+            lt [$($lt)*]
+            generics [$($generics)*]
+            consts [$($consts)*]
+            where [$($where_clause)*]
+            elements [$($elements)*]
+            repr [$($repr)*]
+            derive [$($value)*]
+            format [$($format)*]
+            vis [$($vis)*]
+            queue [$($queue)*]
+            queue_of [$($queue_of)*]
+            builder [$($builder)*]
+            $($rest)*
         );
     };
 
-    // core - elements
     (
-        elements => [ $( $el_bnd:ident $( < $( $el_bnd_g:tt ),* > )? )| * ];
-        queue => [$q:ident ; $empty:ident, $pair:ident];
-        queue_of => $queue_of:ident;
+        @parse
+        lt [$($lt:tt)*]
+        generics [$($generics:tt)*]
+        consts [$($consts:tt)*]
+        where [$($where_clause:tt)*]
+        elements [$($elements:tt)*]
+        repr [$($repr:tt)*]
+        derive [$($derive:tt)*]
+        format [@default]
+        vis [$($vis:tt)*]
+        queue [$($queue:tt)*]
+        queue_of [$($queue_of:tt)*]
+        builder [$($builder:tt)*]
+        format => [$($value:tt)*]; $($rest:tt)*
     ) => {
-        $crate::define_queue_core!(
-            lt => [];
-            generics => [];
-            elements => [ $( $el_bnd $( < $( $el_bnd_g ),* > )? )| * ];
-            queue => [$q ; $empty, $pair];
-        );
-        $crate::define_nonempty_queue_tuple_transformation!(
-            lt => [];
-            generics => [ ];
-            elements => [ $( $el_bnd $( < $( $el_bnd_g ),* > )? )| * ];
-            queue => [$q ; $empty, $pair];
-        );
-        $crate::define_queue_of!(
-            lt => [];
-            generics => [];
-            queue => [$q ; $empty, $pair];
-            queue_of => $queue_of;
+        $crate::define_queue_impl!(
+            @parse
+            lt [$($lt)*]
+            generics [$($generics)*]
+            consts [$($consts)*]
+            where [$($where_clause)*]
+            elements [$($elements)*]
+            repr [$($repr)*]
+            derive [$($derive)*]
+            format [$($value)*]
+            vis [$($vis)*]
+            queue [$($queue)*]
+            queue_of [$($queue_of)*]
+            builder [$($builder)*]
+            $($rest)*
         );
     };
 
-    // core - lifetime elements
     (
-        lt => [$($g_lt:tt), *];
-        elements => [ $( $el_bnd:ident $( < $( $el_bnd_g:tt ),* > )? )| * ];
-        queue => [$q:ident ; $empty:ident, $pair:ident];
-        queue_of => $queue_of:ident;
+        @parse
+        lt [$($lt:tt)*]
+        generics [$($generics:tt)*]
+        consts [$($consts:tt)*]
+        where [$($where_clause:tt)*]
+        elements [$($elements:tt)*]
+        repr [$($repr:tt)*]
+        derive [$($derive:tt)*]
+        format [$($format:tt)*]
+        vis [@default]
+        queue [$($queue:tt)*]
+        queue_of [$($queue_of:tt)*]
+        builder [$($builder:tt)*]
+        vis => pub $(($($restriction:tt)*))?; $($rest:tt)*
     ) => {
-        $crate::define_queue_core!(
-            lt => [$($g_lt), *];
-            generics => [];
-            elements => [ $( $el_bnd $( < $( $el_bnd_g ),* > )? )| * ];
-            queue => [$q ; $empty, $pair];
-        );
-        $crate::define_nonempty_queue_tuple_transformation!(
-            lt => [$($g_lt), *];
-            generics => [];
-            elements => [ $( $el_bnd $( < $( $el_bnd_g ),* > )? )| * ];
-            queue => [$q ; $empty, $pair];
-        );
-        $crate::define_queue_of!(
-            lt => [$($g_lt), *];
-            generics => [];
-            queue => [$q ; $empty, $pair];
-            queue_of => $queue_of;
+        $crate::define_queue_impl!(
+            @parse
+            lt [$($lt)*]
+            generics [$($generics)*]
+            consts [$($consts)*]
+            where [$($where_clause)*]
+            elements [$($elements)*]
+            repr [$($repr)*]
+            derive [$($derive)*]
+            format [$($format)*]
+            vis [pub $(($($restriction)*))?]
+            queue [$($queue)*]
+            queue_of [$($queue_of)*]
+            builder [$($builder)*]
+            $($rest)*
         );
     };
 
-    // # builder
-
-    // core
     (
-        queue => [$q:ident ; $empty:ident, $pair:ident];
-        builder => $builder:ident;
+        @parse
+        lt [$($lt:tt)*]
+        generics [$($generics:tt)*]
+        consts [$($consts:tt)*]
+        where [$($where_clause:tt)*]
+        elements [$($elements:tt)*]
+        repr [$($repr:tt)*]
+        derive [$($derive:tt)*]
+        format [$($format:tt)*]
+        vis [$($vis:tt)*]
+        queue []
+        queue_of [$($queue_of:tt)*]
+        builder [$($builder:tt)*]
+        queue => [$($value:tt)*]; $($rest:tt)*
     ) => {
-        $crate::define_queue_core!(
-            lt => [];
-            generics => [];
-            elements => [];
-            queue => [$q ; $empty, $pair];
-        );
-        $crate::define_nonempty_queue_tuple_transformation!(
-            lt => [];
-            generics => [];
-            elements => [];
-            queue => [$q ; $empty, $pair];
-        );
-        $crate::define_queue_builder!(
-            lt => [];
-            generics => [];
-            queue => [$q ; $empty, $pair];
-            builder => $builder;
+        $crate::define_queue_impl!(
+            @parse
+            lt [$($lt)*]
+            generics [$($generics)*]
+            consts [$($consts)*]
+            where [$($where_clause)*]
+            elements [$($elements)*]
+            repr [$($repr)*]
+            derive [$($derive)*]
+            format [$($format)*]
+            vis [$($vis)*]
+            queue [$($value)*]
+            queue_of [$($queue_of)*]
+            builder [$($builder)*]
+            $($rest)*
         );
     };
 
-    // core - elements
     (
-        elements => [ $( $el_bnd:ident $( < $( $el_bnd_g:tt ),* > )? )| * ];
-        queue => [$q:ident ; $empty:ident, $pair:ident];
-        builder => $builder:ident;
+        @parse
+        lt [$($lt:tt)*]
+        generics [$($generics:tt)*]
+        consts [$($consts:tt)*]
+        where [$($where_clause:tt)*]
+        elements [$($elements:tt)*]
+        repr [$($repr:tt)*]
+        derive [$($derive:tt)*]
+        format [$($format:tt)*]
+        vis [$($vis:tt)*]
+        queue [$($queue:tt)*]
+        queue_of []
+        builder [$($builder:tt)*]
+        queue_of => $value:ident; $($rest:tt)*
     ) => {
-        $crate::define_queue_core!(
-            lt => [];
-            generics => [];
-            elements => [ $( $el_bnd $( < $( $el_bnd_g ),* > )? )| * ];
-            queue => [$q ; $empty, $pair];
-        );
-        $crate::define_nonempty_queue_tuple_transformation!(
-            lt => [];
-            generics => [];
-            elements => [ $( $el_bnd $( < $( $el_bnd_g ),* > )? )| * ];
-            queue => [$q ; $empty, $pair];
-        );
-        $crate::define_queue_builder!(
-            lt => [];
-            generics => [];
-            queue => [$q ; $empty, $pair];
-            builder => $builder;
+        $crate::define_queue_impl!(
+            @parse
+            lt [$($lt)*]
+            generics [$($generics)*]
+            consts [$($consts)*]
+            where [$($where_clause)*]
+            elements [$($elements)*]
+            repr [$($repr)*]
+            derive [$($derive)*]
+            format [$($format)*]
+            vis [$($vis)*]
+            queue [$($queue)*]
+            queue_of [$value]
+            builder [$($builder)*]
+            $($rest)*
         );
     };
 
-    // core - lifetime elements
     (
-        lt => [$($g_lt:tt), *];
-        elements => [ $( $el_bnd:ident $( < $( $el_bnd_g:tt ),* > )? )| * ];
-        queue => [$q:ident ; $empty:ident, $pair:ident];
-        builder => $builder:ident;
+        @parse
+        lt [$($lt:tt)*]
+        generics [$($generics:tt)*]
+        consts [$($consts:tt)*]
+        where [$($where_clause:tt)*]
+        elements [$($elements:tt)*]
+        repr [$($repr:tt)*]
+        derive [$($derive:tt)*]
+        format [$($format:tt)*]
+        vis [$($vis:tt)*]
+        queue [$($queue:tt)*]
+        queue_of [$($queue_of:tt)*]
+        builder []
+        builder => $(#[$attr:meta])* $value:ident; $($rest:tt)*
     ) => {
-        $crate::define_queue_core!(
-            lt => [$($g_lt), *];
-            generics => [];
-            elements => [ $( $el_bnd $( < $( $el_bnd_g ),* > )? )| * ];
-            queue => [$q ; $empty, $pair];
-        );
-        $crate::define_nonempty_queue_tuple_transformation!(
-            lt => [$($g_lt), *];
-            generics => [];
-            elements => [ $( $el_bnd $( < $( $el_bnd_g ),* > )? )| * ];
-            queue => [$q ; $empty, $pair];
-        );
-        $crate::define_queue_builder!(
-            lt => [$($g_lt), *];
-            generics => [];
-            queue => [$q ; $empty, $pair];
-            builder => $builder;
+        $crate::define_queue_impl!(
+            @parse
+            lt [$($lt)*]
+            generics [$($generics)*]
+            consts [$($consts)*]
+            where [$($where_clause)*]
+            elements [$($elements)*]
+            repr [$($repr)*]
+            derive [$($derive)*]
+            format [$($format)*]
+            vis [$($vis)*]
+            queue [$($queue)*]
+            queue_of [$($queue_of)*]
+            builder [$(#[$attr])* $value]
+            $($rest)*
         );
     };
 
-    // # queue_of + builder
+    (
+        @parse
+        lt [$($lt:tt)*]
+        generics [$($generics:tt)*]
+        consts [$($consts:tt)*]
+        where [$($where_clause:tt)*]
+        elements [$($elements:tt)*]
+        repr [$($repr:tt)*]
+        derive [$($derive:tt)*]
+        format [$($format:tt)*]
+        vis [$($vis:tt)*]
+        queue [$($queue:tt)*]
+        queue_of [$($queue_of:tt)*]
+        builder [$($builder:tt)*]
+        $option:ident => $($rest:tt)*
+    ) => {
+        core::compile_error!(core::concat!(
+            "`define_queue!` option `",
+            core::stringify!($option),
+            "` is either unknown or provided more than once"
+        ));
+    };
 
-    // core
     (
-        queue => [$q:ident ; $empty:ident, $pair:ident];
-        queue_of => $queue_of:ident;
-        builder => $builder:ident;
+        @parse
+        lt [$($lt:tt)*]
+        generics [$($generics:tt)*]
+        consts [$($consts:tt)*]
+        where [$($where_clause:tt)*]
+        elements [$($elements:tt)*]
+        repr [$($repr:tt)*]
+        derive [$($derive:tt)*]
+        format [$($format:tt)*]
+        vis [$($vis:tt)*]
+        queue []
+        queue_of [$($queue_of:tt)*]
+        builder [$($builder:tt)*]
     ) => {
-        $crate::define_queue_core!(
-            lt => [];
-            generics => [];
-            elements => [];
-            queue => [$q ; $empty, $pair];
-        );
-        $crate::define_nonempty_queue_tuple_transformation!(
-            lt => [];
-            generics => [];
-            elements => [];
-            queue => [$q ; $empty, $pair];
-        );
-        $crate::define_queue_of!(
-            lt => [];
-            generics => [];
-            queue => [$q ; $empty, $pair];
-            queue_of => $queue_of;
-        );
-        $crate::define_queue_builder!(
-            lt => [];
-            generics => [];
-            queue => [$q ; $empty, $pair];
-            builder => $builder;
+        core::compile_error!("`define_queue!` requires the `queue => [Trait ; Single, Multi];` option");
+    };
+
+    (
+        @parse
+        lt [$($lt:tt)*]
+        generics [$($generics:tt)*]
+        consts [$($consts:tt)*]
+        where [$($where_clause:tt)*]
+        elements [$($elements:tt)*]
+        repr [$($repr:tt)*]
+        derive [@default]
+        format [$($format:tt)*]
+        vis [$($vis:tt)*]
+        queue [$($queue:tt)*]
+        queue_of [$($queue_of:tt)*]
+        builder [$($builder:tt)*]
+    ) => {
+        $crate::define_queue_impl!(
+            @parse
+            lt [$($lt)*]
+            generics [$($generics)*]
+            consts [$($consts)*]
+            where [$($where_clause)*]
+            elements [$($elements)*]
+            repr [$($repr)*]
+            derive [Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default]
+            format [$($format)*]
+            vis [$($vis)*]
+            queue [$($queue)*]
+            queue_of [$($queue_of)*]
+            builder [$($builder)*]
         );
     };
 
-    // core - elements
     (
-        elements => [ $( $el_bnd:ident $( < $( $el_bnd_g:tt ),* > )? )| * ];
-        queue => [$q:ident ; $empty:ident, $pair:ident];
-        queue_of => $queue_of:ident;
-        builder => $builder:ident;
+        @parse
+        lt [$($lt:tt)*]
+        generics [$($generics:tt)*]
+        consts [$($consts:tt)*]
+        where [$($where_clause:tt)*]
+        elements [$($elements:tt)*]
+        repr [$($repr:tt)*]
+        derive [$($derive:tt)*]
+        format [$($format:tt)*]
+        vis [@default]
+        queue [$($queue:tt)*]
+        queue_of [$($queue_of:tt)*]
+        builder [$($builder:tt)*]
     ) => {
-        $crate::define_queue_core!(
-            lt => [];
-            generics => [];
-            elements => [ $( $el_bnd $( < $( $el_bnd_g ),* > )? )| * ];
-            queue => [$q ; $empty, $pair];
+        $crate::define_queue_impl!(
+            @parse
+            lt [$($lt)*]
+            generics [$($generics)*]
+            consts [$($consts)*]
+            where [$($where_clause)*]
+            elements [$($elements)*]
+            repr [$($repr)*]
+            derive [$($derive)*]
+            format [$($format)*]
+            vis [pub]
+            queue [$($queue)*]
+            queue_of [$($queue_of)*]
+            builder [$($builder)*]
         );
-        $crate::define_nonempty_queue_tuple_transformation!(
-            lt => [];
-            generics => [];
-            elements => [ $( $el_bnd $( < $( $el_bnd_g ),* > )? )| * ];
-            queue => [$q ; $empty, $pair];
+    };
+
+    (
+        @parse
+        lt [$($lt:tt)*]
+        generics [$($generics:tt)*]
+        consts [$($consts:tt)*]
+        where [$($where_clause:tt)*]
+        elements [$($elements:tt)*]
+        repr [$($repr:tt)*]
+        derive [$($derive:tt)*]
+        format [$($format:tt)*]
+        vis [$($vis:tt)*]
+        queue [$($queue:tt)*]
+        queue_of [$($queue_of:tt)*]
+        builder [$($builder:tt)*]
+    ) => {
+        $crate::define_queue_impl!(
+            @format [$($format)*] [$($elements)*] [] []
+            lt => [$($lt)*];
+            generics => [$($generics)*];
+            consts => [$($consts)*];
+            where => [$($where_clause)*];
+            repr => [$($repr)*];
+            derive => [$($derive)*];
+            vis => [$($vis)*];
+            queue => [$($queue)*];
+            queue_of => [$($queue_of)*];
+            builder => [$($builder)*];
         );
-        $crate::define_queue_of!(
-            lt => [];
-            generics => [];
-            queue => [$q ; $empty, $pair];
-            queue_of => $queue_of;
+    };
+
+    // format: each given formatting trait is required by the elements, and hence by the queues;
+    // by default, `Debug` is implemented whenever the elements are `Debug` without being required.
+
+    (@format [@default] [$($elements:tt)*] [] [] $($rest:tt)*) => {
+        $crate::define_queue_impl!(@elements [] [$($elements)*] [] queue_bounds => []; format => [DefaultDebug]; $($rest)*);
+    };
+
+    (@format [Debug $(, $($fmt:tt)*)?] [$($elements:tt)*] [$($q_bnd:tt)*] [$($impls:ident)*] $($rest:tt)*) => {
+        $crate::define_queue_impl!(
+            @format [$($($fmt)*)?] [$($elements)* | core::fmt::Debug] [$($q_bnd)* + $crate::queue::DebugElements] [$($impls)* Debug]
+            $($rest)*
         );
-        $crate::define_queue_builder!(
-            lt => [];
-            generics => [];
-            queue => [$q ; $empty, $pair];
-            builder => $builder;
+    };
+
+    (@format [Display $(, $($fmt:tt)*)?] [$($elements:tt)*] [$($q_bnd:tt)*] [$($impls:ident)*] $($rest:tt)*) => {
+        $crate::define_queue_impl!(
+            @format [$($($fmt)*)?] [$($elements)* | core::fmt::Display] [$($q_bnd)* + $crate::queue::DisplayElements] [$($impls)* Display]
+            $($rest)*
         );
     };
 
-    // core - lifetime elements
+    (@format [] [$($elements:tt)*] [$($q_bnd:tt)*] [$($impls:ident)*] $($rest:tt)*) => {
+        $crate::define_queue_impl!(@elements [] [$($elements)*] [] queue_bounds => [$($q_bnd)*]; format => [$($impls)*]; $($rest)*);
+    };
+
+    (@format [$fmt:tt $($other:tt)*] $($rest:tt)*) => {
+        core::compile_error!(core::concat!(
+            "`define_queue!` format `",
+            core::stringify!($fmt),
+            "` is unknown; expected `Debug` and/or `Display`"
+        ));
+    };
+
+    // elements: `|` separators are normalized into `+`; and `?Sized` is removed from the bounds and
+    // kept as a flag, since it only applies to the type parameter of the back element
+
+    (@elements [$($acc:tt)*] [? Sized + $($el_bnd:tt)*] [$($uns:ident)?] $($rest:tt)*) => {
+        $crate::define_queue_impl!(@elements [$($acc)*] [$($el_bnd)*] [Sized] $($rest)*);
+    };
+
+    (@elements [$($acc:tt)*] [? Sized | $($el_bnd:tt)*] [$($uns:ident)?] $($rest:tt)*) => {
+        $crate::define_queue_impl!(@elements [$($acc)*] [$($el_bnd)*] [Sized] $($rest)*);
+    };
+
+    (@elements [$($acc:tt)*] [? Sized $($el_bnd:tt)*] [$($uns:ident)?] $($rest:tt)*) => {
+        $crate::define_queue_impl!(@elements [$($acc)*] [$($el_bnd)*] [Sized] $($rest)*);
+    };
+
+    (@elements [$($acc:tt)*] [+ ? Sized $($el_bnd:tt)*] [$($uns:ident)?] $($rest:tt)*) => {
+        $crate::define_queue_impl!(@elements [$($acc)*] [$($el_bnd)*] [Sized] $($rest)*);
+    };
+
+    (@elements [$($acc:tt)*] [| ? Sized $($el_bnd:tt)*] [$($uns:ident)?] $($rest:tt)*) => {
+        $crate::define_queue_impl!(@elements [$($acc)*] [$($el_bnd)*] [Sized] $($rest)*);
+    };
+
+    (@elements [] [| $($el_bnd:tt)*] $($rest:tt)*) => {
+        $crate::define_queue_impl!(@elements [] [$($el_bnd)*] $($rest)*);
+    };
+
+    (@elements [$($acc:tt)*] [| $($el_bnd:tt)*] $($rest:tt)*) => {
+        $crate::define_queue_impl!(@elements [$($acc)* +] [$($el_bnd)*] $($rest)*);
+    };
+
+    (@elements [$($acc:tt)*] [$next:tt $($el_bnd:tt)*] $($rest:tt)*) => {
+        $crate::define_queue_impl!(@elements [$($acc)* $next] [$($el_bnd)*] $($rest)*);
+    };
+
+    (@elements [$($acc:tt)*] [] [$($uns:ident)?] $($rest:tt)*) => {
+        $crate::define_queue_impl!(elements => [$($acc)*]; unsized => [$($uns)?]; $($rest)*);
+    };
+
+    // back: the pair queue requires its back to be a queue; or, when the back element can be unsized,
+    // only the element bounds since an unsized queue cannot implement the by-value queue methods.
+    // Further, the unsized element must be the last field, and hence, the phantom field is moved to the front.
+
+    (@back [] [$($q:tt)*] [$($el_bnd:tt)*] $($args:tt)*) => {
+        $crate::define_queue_core!(back => [$($q)*]; phantom_first => []; phantom_last => [phantom]; $($args)*);
+    };
+
+    (@back [Sized] [$($q:tt)*] [$($el_bnd:tt)*] $($args:tt)*) => {
+        $crate::define_queue_core!(back => [?Sized + $($el_bnd)*]; phantom_first => [phantom]; phantom_last => []; $($args)*);
+    };
+
+    // optional macros
+
+    (@queue_of [] $($args:tt)*) => {};
+
+    (@queue_of [$queue_of:ident] $($args:tt)*) => {
+        $crate::define_queue_of!($($args)* queue_of => $queue_of;);
+    };
+
+    (@builder [] $($args:tt)*) => {};
+
+    (@builder [$(#[$attr:meta])* $builder:ident] $($args:tt)*) => {
+        $crate::define_queue_builder!($($args)* builder => $(#[$attr])* $builder;);
+    };
+
     (
+        elements => [ $($el_bnd:tt)* ];
+        unsized => [$($uns:ident)?];
+        queue_bounds => [ $($q_bnd:tt)* ];
+        format => [ $($fmt:ident)* ];
         lt => [$($g_lt:tt), *];
-        elements => [ $( $el_bnd:ident $( < $( $el_bnd_g:tt ),* > )? )| * ];
-        queue => [$q:ident ; $empty:ident, $pair:ident];
-        queue_of => $queue_of:ident;
-        builder => $builder:ident;
+        generics => [ $( $g:tt $( : $( $g_bnd:ident $( < $( $g_bnd_g:tt ),* > )? )| * )? ), * ];
+        consts => [ $( const $c:ident : $c_ty:ty ),* ];
+        where => [ $($wh:tt)* ];
+        repr => [$($repr:ident)?];
+        derive => [$($derive:path),* $(,)?];
+        vis => [$vis:vis];
+        queue => [
+            $(#[$q_attr:meta])* $q:ident ;
+            $(#[$empty_attr:meta])* $empty:ident,
+            $(#[$pair_attr:meta])* $pair:ident $(,)?
+        ];
+        queue_of => [$($queue_of:ident)?];
+        builder => [$( $(#[$builder_attr:meta])* $builder:ident )?];
     ) => {
-        $crate::define_queue_core!(
+        $crate::define_queue_impl!(
+            @back [$($uns)?] [$q<$($g_lt ,)* $($g ,)* $($c ,)*>] [$($el_bnd)*]
+            phantom => [core::marker::PhantomData<$(&$g_lt)* ($($g ,)*)>];
+            unsized => [$($uns)?];
             lt => [$($g_lt), *];
-            generics => [];
-            elements => [ $( $el_bnd $( < $( $el_bnd_g ),* > )? )| * ];
-            queue => [$q ; $empty, $pair];
+            generics => [ $( $g $( : $( $g_bnd $( < $( $g_bnd_g ),* > )? )| * )? ), * ];
+            consts => [ $( const $c: $c_ty ),* ];
+            where => [ $($wh)* ];
+            elements => [ $($el_bnd)* ];
+            queue_bounds => [ $($q_bnd)* ];
+            repr => [$($repr)?];
+            derive => [$($derive),*];
+            vis => [$vis];
+            queue => [$(#[$q_attr])* $q ; $(#[$empty_attr])* $empty, $(#[$pair_attr])* $pair];
         );
+
         $crate::define_nonempty_queue_tuple_transformation!(
             lt => [$($g_lt), *];
-            generics => [];
-            elements => [ $( $el_bnd $( < $( $el_bnd_g ),* > )? )| * ];
+            generics => [ $( $g $( : $( $g_bnd $( < $( $g_bnd_g ),* > )? )| * )? ), * ];
+            consts => [ $( const $c: $c_ty ),* ];
+            where => [ $($wh)* ];
+            elements => [ $($el_bnd)* ];
+            queue => [$q ; $empty, $pair];
+        );
+
+        $crate::define_queue_format!(
+            format => [$($fmt)*];
+            lt => [$($g_lt), *];
+            generics => [ $( $g $( : $( $g_bnd $( < $( $g_bnd_g ),* > )? )| * )? ), * ];
+            consts => [ $( const $c: $c_ty ),* ];
+            where => [ $($wh)* ];
+            elements => [ $($el_bnd)* ];
             queue => [$q ; $empty, $pair];
         );
-        $crate::define_queue_of!(
+
+        $crate::define_queue_impl!(
+            @queue_of [$($queue_of)?]
             lt => [$($g_lt), *];
-            generics => [];
+            generics => [ $( $g $( : $( $g_bnd $( < $( $g_bnd_g ),* > )? )| * )? ), * ];
+            consts => [ $( const $c: $c_ty ),* ];
+            where => [ $($wh)* ];
             queue => [$q ; $empty, $pair];
-            queue_of => $queue_of;
         );
-        $crate::define_queue_builder!(
+
+        $crate::define_queue_impl!(
+            @builder [$( $(#[$builder_attr])* $builder )?]
             lt => [$($g_lt), *];
-            generics => [];
+            generics => [ $( $g $( : $( $g_bnd $( < $( $g_bnd_g ),* > )? )| * )? ), * ];
+            consts => [ $( const $c: $c_ty ),* ];
+            where => [ $($wh)* ];
+            elements => [ $($el_bnd)* ];
+            vis => [$vis];
             queue => [$q ; $empty, $pair];
-            builder => $builder;
         );
     };
 }
@@ -524,11 +1285,27 @@ macro_rules! define_queue {
 #[macro_export]
 macro_rules! define_queue_core {
     (
+        back => [ $($back:tt)* ];
+        phantom_first => [$($ph_first:ident)?];
+        phantom_last => [$($ph_last:ident)?];
+        phantom => [$phantom:ty];
+        unsized => [$($uns:ident)?];
         lt => [$($g_lt:tt), *];
         generics => [ $( $g:tt $( : $( $g_bnd:ident $( < $( $g_bnd_g:tt ),* > )? )| * )? ), * ];
-        elements => [ $( $el_bnd:ident $( < $( $el_bnd_g:tt ),* > )? )| * ];
-        queue => [$q:ident ; $empty:ident, $pair:ident];
+        consts => [ $( const $c:ident : $c_ty:ty ),* ];
+        where => [ $($wh:tt)* ];
+        elements => [ $($el_bnd:tt)* ];
+        queue_bounds => [ $($q_bnd:tt)* ];
+        repr => [$($repr:ident)?];
+        derive => [$($derive:path),*];
+        vis => [$vis:vis];
+        queue => [
+            $(#[$q_attr:meta])* $q:ident ;
+            $(#[$empty_attr:meta])* $empty:ident,
+            $(#[$pair_attr:meta])* $pair:ident
+        ];
     ) => {
+        $(#[$q_attr])*
         /// A strongly typed non-empty queue of heterogeneous elements.
         ///
         /// There exist two implementations:
@@ -547,21 +1324,21 @@ macro_rules! define_queue_core {
         /// [`Queue`]: crate::queue::Queue
         #[allow(dead_code)]
         #[allow(clippy::len_without_is_empty)]
-        pub trait $q<$($g_lt ,)* $($g ,)*>
+        $vis trait $q<$($g_lt ,)* $($g ,)* $(const $c: $c_ty ,)*>
         where
-            Self: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            Self: $($el_bnd)* $($q_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
             /// Type of the queue obtained by adding an element of type `Elem` to this queue.
-            type PushBack<T>: $q<$($g_lt ,)* $($g ,)*>
+            type PushBack<T>: $q<$($g_lt ,)* $($g ,)* $($c ,)*>
             where
-                T: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *;
+                T: $($el_bnd)*;
 
             /// Type of the element at the front of the queue.
-            type Front: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *;
+            type Front: $($el_bnd)*;
 
             /// Type of the queue that would be obtained by popping the `Front` element of the queue.
-            type Back: $q<$($g_lt ,)* $($g ,)*>;
+            type Back: $q<$($g_lt ,)* $($g ,)* $($c ,)*>;
 
             /// Number of elements in the queue.
             const LEN: usize;
@@ -586,7 +1363,7 @@ macro_rules! define_queue_core {
             /// ```
             fn push<T>(self, x: T) -> Self::PushBack<T>
             where
-                T: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *;
+                T: $($el_bnd)*;
 
             /// Pushes the `element` and returns the resulting queue.
             ///
@@ -683,30 +1460,37 @@ macro_rules! define_queue_core {
             ///
             /// assert_eq!(queue.into_front(), 'x');
             /// ```
+            ///
+            /// Trait methods cannot be `const fn`, and neither could this method be, as explained in `pop` of the
+            /// multiple element queue. In const items, the front can be accessed by the inherent `const fn front`
+            /// of the queue types.
             fn into_front(self) -> Self::Front;
         }
 
         // # single
 
 
+        $(#[$empty_attr])*
         /// A statically-typed queue containing exactly one element of type `Front`.
         ///
         /// See also the other [`StQueue`] implementation [`Queue`] which can be
         /// created by pushing a second element to this queue.
-        #[derive(Clone, Copy, PartialEq, Eq)]
-        pub struct $empty<$($g_lt ,)* $($g ,)* Front>
+        #[derive($($derive),*)]
+        $( #[repr($repr)] )?
+        $vis struct $empty<$($g_lt ,)* $($g ,)* $(const $c: $c_ty ,)* Front>
         where
-            Front: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            Front: $(?$uns +)? $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
-            phantom: core::marker::PhantomData<$(&$g_lt)* ($($g ,)*)>,
+            $( $ph_first: $phantom, )?
             f: Front,
+            $( $ph_last: $phantom, )?
         }
 
-        impl<$($g_lt ,)* F, $($g ,)*> $empty<$($g_lt ,)* $($g ,)* F>
+        impl<$($g_lt ,)* F, $($g ,)* $(const $c: $c_ty ,)*> $empty<$($g_lt ,)* $($g ,)* $($c ,)* F>
         where
-            F: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            F: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
             /// Creates a new statically-typed queue [`StQueue`] containing exactly one `element`.
             ///
@@ -730,9 +1514,9 @@ macro_rules! define_queue_core {
             /// assert_eq!(queue.front(), &42);
             /// ```
             #[inline(always)]
-            pub fn new(element: F) -> Self {
+            pub const fn new(element: F) -> Self {
                 Self {
-                    phantom: Default::default(),
+                    phantom: core::marker::PhantomData,
                     f: element,
                 }
             }
@@ -742,6 +1526,9 @@ macro_rules! define_queue_core {
             /// Since this element contains only one element, there is no remaining queue once the
             /// front is popped. Therefore, the return type is only the element rather than a tuple.
             ///
+            /// This method is not a `const fn`, see `pop` of the multiple element queue for the reason and the
+            /// alternatives in const items.
+            ///
             /// # Examples
             ///
             /// ```ignore
@@ -758,25 +1545,48 @@ macro_rules! define_queue_core {
             }
         }
 
-        impl<$($g_lt ,)* F, $($g ,)*> core::fmt::Debug for $empty<$($g_lt ,)* $($g ,)* F>
+        impl<$($g_lt ,)* F, $($g ,)* $(const $c: $c_ty ,)*> $empty<$($g_lt ,)* $($g ,)* $($c ,)* F>
         where
-            F: core::fmt::Debug,
-            F: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            F: $(?$uns +)? $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
-            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                write!(f, "{}({:?})", stringify!($empty), self.f)
+            /// Returns a reference to the element in the front of the queue.
+            ///
+            /// This is the `const fn` counterpart of `front` method of the queue trait.
+            ///
+            /// # Examples
+            ///
+            /// ```ignore
+            /// use orx_meta::queue::*;
+            ///
+            /// const QUEUE: QueueSingle<u32> = QueueSingle::new(42);
+            /// const FRONT: &u32 = QUEUE.front();
+            ///
+            /// assert_eq!(FRONT, &42);
+            /// ```
+            #[inline(always)]
+            pub const fn front(&self) -> &F {
+                &self.f
+            }
+
+            /// Returns a mutable reference to the element in the front of the queue.
+            ///
+            /// This is the `const fn` counterpart of `front_mut` method of the queue trait, which is also
+            /// available when the element is unsized.
+            #[inline(always)]
+            pub const fn front_mut(&mut self) -> &mut F {
+                &mut self.f
             }
         }
 
-        impl<$($g_lt ,)* F, $($g ,)*> $q<$($g_lt ,)* $($g ,)*> for $empty<$($g_lt ,)* $($g ,)* F>
+        impl<$($g_lt ,)* F, $($g ,)* $(const $c: $c_ty ,)*> $q<$($g_lt ,)* $($g ,)* $($c ,)*> for $empty<$($g_lt ,)* $($g ,)* $($c ,)* F>
         where
-            F: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            F: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
-            type PushBack<Elem> = $pair<$($g_lt ,)* $($g ,)* F, $empty<$($g_lt ,)* $($g ,)* Elem>>
+            type PushBack<Elem> = $pair<$($g_lt ,)* $($g ,)* $($c ,)* F, $empty<$($g_lt ,)* $($g ,)* $($c ,)* Elem>>
             where
-                Elem: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *;
+                Elem: $($el_bnd)*;
 
             type Front = F;
 
@@ -787,7 +1597,7 @@ macro_rules! define_queue_core {
             #[inline(always)]
             fn push<Elem>(self, x: Elem) -> Self::PushBack<Elem>
             where
-                Elem: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *
+                Elem: $($el_bnd)*
             {
                 $pair::from_fb(self.f, $empty::new(x))
             }
@@ -810,6 +1620,7 @@ macro_rules! define_queue_core {
 
         // # pair
 
+        $(#[$pair_attr])*
         /// A queue containing multiple (>= 2) elements.
         ///
         /// It is composed of two parts:
@@ -824,22 +1635,24 @@ macro_rules! define_queue_core {
         /// Note that `Queue::new(element)` gives a `QueueSingle` with one element. In order to create
         /// a queue of multiple elements, we need to push at least one more element, such as
         /// `Queue::new(elem1).push(elem2)`.
-        #[derive(Clone, Copy, PartialEq, Eq)]
-        pub struct $pair<$($g_lt ,)* $($g ,)* Front, Back>
+        #[derive($($derive),*)]
+        $( #[repr($repr)] )?
+        $vis struct $pair<$($g_lt ,)* $($g ,)* $(const $c: $c_ty ,)* Front, Back>
         where
-            Front: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            Back: $q<$($g_lt ,)* $($g ,)*>,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            Front: $($el_bnd)*,
+            Back: $($back)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
-            phantom: core::marker::PhantomData<$(&$g_lt)* ($($g ,)*)>,
+            $( $ph_first: $phantom, )?
             f: Front,
             b: Back,
+            $( $ph_last: $phantom, )?
         }
 
-        impl<$($g_lt ,)* F, $($g ,)*> $pair<$($g_lt ,)* $($g ,)* F, $empty<$($g_lt ,)* $($g ,)* F>>
+        impl<$($g_lt ,)* F, $($g ,)* $(const $c: $c_ty ,)*> $pair<$($g_lt ,)* $($g ,)* $($c ,)* F, $empty<$($g_lt ,)* $($g ,)* $($c ,)* F>>
         where
-            F: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            F: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
             /// Creates a [`QueueSingle`] with exactly one `element`.
             ///
@@ -872,28 +1685,71 @@ macro_rules! define_queue_core {
             /// ```
             #[allow(clippy::new_ret_no_self)]
             #[inline(always)]
-            pub fn new(element: F) -> $empty<$($g_lt ,)* $($g ,)* F> {
+            pub const fn new(element: F) -> $empty<$($g_lt ,)* $($g ,)* $($c ,)* F> {
                 $empty::new(element)
             }
         }
 
-        impl<$($g_lt ,)* F, B, $($g ,)*> $pair<$($g_lt ,)* $($g ,)* F, B>
+        impl<$($g_lt ,)* F, B, $($g ,)* $(const $c: $c_ty ,)*> $pair<$($g_lt ,)* $($g ,)* $($c ,)* F, B>
         where
-            F: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            B: $q<$($g_lt ,)* $($g ,)*>,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            F: $($el_bnd)*,
+            B: $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
+            /// Creates a queue with the given `front` element and the `back` queue of remaining elements.
+            ///
+            /// Unlike `push`, this is a `const fn`; and hence, it can be used to create queues of
+            /// any length in const contexts such as `const` and `static` items.
+            ///
+            /// # Examples
+            ///
+            /// ```ignore
+            /// use orx_meta::queue::*;
+            /// use orx_meta::queue_of;
+            ///
+            /// const QUEUE: queue_of!(u32, bool, char) =
+            ///     Queue::from_fb(42, Queue::from_fb(true, QueueSingle::new('x')));
+            ///
+            /// assert_eq!(QUEUE, Queue::new(42).push(true).push('x'));
+            /// ```
             #[inline(always)]
-            fn from_fb(f: F, b: B) -> Self {
+            pub const fn from_fb(f: F, b: B) -> Self {
                 Self {
-                    phantom: Default::default(),
+                    phantom: core::marker::PhantomData,
                     f,
                     b,
                 }
             }
+        }
 
+        impl<$($g_lt ,)* F, B, $($g ,)* $(const $c: $c_ty ,)*> $pair<$($g_lt ,)* $($g ,)* $($c ,)* F, B>
+        where
+            F: $($el_bnd)*,
+            B: $($back)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
             // ref
 
+            /// Returns a reference to the element in the front of the queue.
+            ///
+            /// This is the `const fn` counterpart of `front` method of the queue trait.
+            ///
+            /// # Examples
+            ///
+            /// ```ignore
+            /// use orx_meta::queue::*;
+            /// use orx_meta::queue_of;
+            ///
+            /// const QUEUE: queue_of!(u32, bool) = Queue::from_fb(42, QueueSingle::new(true));
+            /// const FRONT: &u32 = QUEUE.front();
+            ///
+            /// assert_eq!(FRONT, &42);
+            /// ```
+            #[inline(always)]
+            pub const fn front(&self) -> &F {
+                &self.f
+            }
+
             /// Returns a reference to the queue including elements of this queue
             /// excluding the element in the front.
             ///
@@ -934,7 +1790,7 @@ macro_rules! define_queue_core {
             /// let s = queue.pop();
             /// assert_eq!(s, "foo");
             /// ```
-            pub fn back(&self) -> &B {
+            pub const fn back(&self) -> &B {
                 &self.b
             }
 
@@ -959,7 +1815,7 @@ macro_rules! define_queue_core {
             ///
             /// assert_eq!(queue.as_tuple(), (&84, &false, &'y'));
             /// ```
-            pub fn back_mut(&mut self) -> &mut B {
+            pub const fn back_mut(&mut self) -> &mut B {
                 &mut self.b
             }
 
@@ -1012,107 +1868,470 @@ macro_rules! define_queue_core {
             ///
             /// assert_eq!(q.as_tuple(), (&6, &true, &'y'));
             /// ```
-            pub fn front_back_mut(&mut self) -> (&mut F, &mut B) {
+            pub const fn front_back_mut(&mut self) -> (&mut F, &mut B) {
                 (&mut self.f, &mut self.b)
             }
+        }
 
+        impl<$($g_lt ,)* F, B, $($g ,)* $(const $c: $c_ty ,)*> $pair<$($g_lt ,)* $($g ,)* $($c ,)* F, B>
+        where
+            F: $($el_bnd)*,
+            B: $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
             // into
 
-            /// Consumes the queue and returns the queue including elements of this queue
-            /// except for the element in the front.
-            ///
-            /// Equivalent to `queue.pop().1`.
-            pub fn into_back(self) -> B {
-                self.b
+            /// Consumes the queue and returns the queue including elements of this queue
+            /// except for the element in the front.
+            ///
+            /// Equivalent to `queue.pop().1`.
+            ///
+            /// This method is not a `const fn`, see `pop` for the reason and the alternatives in const items.
+            pub fn into_back(self) -> B {
+                self.b
+            }
+
+            /// Consumes the queue and returns the tuple of its front and back:
+            ///
+            /// * **front** is the element in the front of this queue.
+            /// * **back** is the queue including all elements of this queue except
+            ///   for the front element. In other words, it is the queue obtained by
+            ///   popping the front element.
+            ///
+            /// This method is not a `const fn` for the reason explained in `orx_meta::queue::Queue::pop`. In const
+            /// items, queues can be created by `new` and `from_fb`, and their elements can be accessed by `front`
+            /// and `back`.
+            ///
+            /// # Examples
+            ///
+            /// ```ignore
+            /// use orx_meta::queue::*;
+            ///
+            /// let queue = Queue::new(42);
+            /// let num = queue.pop(); // QueueSingle::pop just returns the front
+            /// assert_eq!(num, 42);
+            ///
+            /// let queue = Queue::new(42).push(true).push('x').push("foo");
+            ///
+            /// let (num, queue) = queue.pop(); // Queue::pop returns (front, back)
+            /// assert_eq!(num, 42);
+            /// assert_eq!(queue, Queue::new(true).push('x').push("foo"));
+            ///
+            /// let (flag, queue) = queue.pop();
+            /// assert_eq!(flag, true);
+            /// assert_eq!(queue, Queue::new('x').push("foo"));
+            ///
+            /// let (c, queue) = queue.pop();
+            /// assert_eq!(c, 'x');
+            /// assert_eq!(queue, Queue::new("foo"));
+            ///
+            /// let s = queue.pop();
+            /// assert_eq!(s, "foo");
+            /// ```
+            pub fn pop(self) -> (F, B) {
+                (self.f, self.b)
+            }
+        }
+
+        impl<$($g_lt ,)* F, B, $($g ,)* $(const $c: $c_ty ,)*> $q<$($g_lt ,)* $($g ,)* $($c ,)*> for $pair<$($g_lt ,)* $($g ,)* $($c ,)* F, B>
+        where
+            F: $($el_bnd)*,
+            B: $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            type PushBack<Elem> = $pair<$($g_lt ,)* $($g ,)* $($c ,)* F, B::PushBack<Elem>>
+            where
+                Elem: $($el_bnd)*;
+
+            type Front = F;
+
+            type Back = B;
+
+            const LEN: usize = 1 + B::LEN;
+
+            fn push<Elem>(self, x: Elem) -> Self::PushBack<Elem>
+            where
+                Elem: $($el_bnd)*
+            {
+                $pair::from_fb(self.f, self.b.push(x))
+            }
+
+            #[inline(always)]
+            fn front(&self) -> &Self::Front {
+                &self.f
+            }
+
+            #[inline(always)]
+            fn front_mut(&mut self) -> &mut Self::Front {
+                &mut self.f
+            }
+
+            #[inline(always)]
+            fn into_front(self) -> Self::Front {
+                self.f
+            }
+        }
+
+        // # queue-like
+
+        impl<$($g_lt ,)* F, $($g ,)* $(const $c: $c_ty ,)*> $crate::queue::QueueLike for $empty<$($g_lt ,)* $($g ,)* $($c ,)* F>
+        where
+            F: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            type Front = F;
+
+            type Back = Self;
+
+            const LEN: usize = 1;
+
+            #[inline(always)]
+            fn front(&self) -> &F {
+                &self.f
+            }
+
+            #[inline(always)]
+            fn back(&self) -> Option<&Self> {
+                None
+            }
+
+            #[inline(always)]
+            fn pop(self) -> (F, Option<Self>) {
+                (self.f, None)
+            }
+        }
+
+        impl<$($g_lt ,)* F, B, $($g ,)* $(const $c: $c_ty ,)*> $crate::queue::QueueLike for $pair<$($g_lt ,)* $($g ,)* $($c ,)* F, B>
+        where
+            F: $($el_bnd)*,
+            B: $q<$($g_lt ,)* $($g ,)* $($c ,)*> + $crate::queue::QueueLike,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            type Front = F;
+
+            type Back = B;
+
+            const LEN: usize = 1 + <B as $crate::queue::QueueLike>::LEN;
+
+            #[inline(always)]
+            fn front(&self) -> &F {
+                &self.f
+            }
+
+            #[inline(always)]
+            fn back(&self) -> Option<&B> {
+                Some(&self.b)
+            }
+
+            #[inline(always)]
+            fn pop(self) -> (F, Option<B>) {
+                (self.f, Some(self.b))
+            }
+        }
+
+        // # base
+
+        impl<$($g_lt ,)* F, $($g ,)* $(const $c: $c_ty ,)*> $crate::queue::IntoBase for $empty<$($g_lt ,)* $($g ,)* $($c ,)* F>
+        where
+            F: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            type Base = $crate::queue::QueueSingle<F>;
+
+            #[inline(always)]
+            fn into_base(self) -> Self::Base {
+                $crate::queue::QueueSingle::new(self.f)
+            }
+        }
+
+        impl<$($g_lt ,)* F, B, $($g ,)* $(const $c: $c_ty ,)*> $crate::queue::IntoBase for $pair<$($g_lt ,)* $($g ,)* $($c ,)* F, B>
+        where
+            F: $($el_bnd)*,
+            B: $q<$($g_lt ,)* $($g ,)* $($c ,)*> + $crate::queue::IntoBase,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            type Base = $crate::queue::Queue<F, B::Base>;
+
+            #[inline(always)]
+            fn into_base(self) -> Self::Base {
+                $crate::queue::Queue::from_fb(self.f, $crate::queue::IntoBase::into_base(self.b))
+            }
+        }
+
+        impl<$($g_lt ,)* F, $($g ,)* $(const $c: $c_ty ,)*> $crate::queue::FromBase<$crate::queue::QueueSingle<F>> for $empty<$($g_lt ,)* $($g ,)* $($c ,)* F>
+        where
+            F: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            #[inline(always)]
+            fn from_base(base: $crate::queue::QueueSingle<F>) -> Self {
+                $empty::new(base.pop())
+            }
+        }
+
+        impl<$($g_lt ,)* F, B, BB, $($g ,)* $(const $c: $c_ty ,)*> $crate::queue::FromBase<$crate::queue::Queue<F, BB>> for $pair<$($g_lt ,)* $($g ,)* $($c ,)* F, B>
+        where
+            F: $($el_bnd)*,
+            B: $q<$($g_lt ,)* $($g ,)* $($c ,)*> + $crate::queue::FromBase<BB>,
+            BB: $crate::queue::StQueue,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            #[inline(always)]
+            fn from_base(base: $crate::queue::Queue<F, BB>) -> Self {
+                let (f, b) = base.pop();
+                $pair::from_fb(f, $crate::queue::FromBase::from_base(b))
+            }
+        }
+
+        impl<$($g_lt ,)* F, $($g ,)* $(const $c: $c_ty ,)*> From<$empty<$($g_lt ,)* $($g ,)* $($c ,)* F>> for $crate::queue::QueueSingle<F>
+        where
+            F: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            #[inline(always)]
+            fn from(queue: $empty<$($g_lt ,)* $($g ,)* $($c ,)* F>) -> Self {
+                $crate::queue::IntoBase::into_base(queue)
+            }
+        }
+
+        impl<$($g_lt ,)* F, B, $($g ,)* $(const $c: $c_ty ,)*> From<$pair<$($g_lt ,)* $($g ,)* $($c ,)* F, B>> for $crate::queue::Queue<F, B::Base>
+        where
+            F: $($el_bnd)*,
+            B: $q<$($g_lt ,)* $($g ,)* $($c ,)*> + $crate::queue::IntoBase,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            #[inline(always)]
+            fn from(queue: $pair<$($g_lt ,)* $($g ,)* $($c ,)* F, B>) -> Self {
+                $crate::queue::IntoBase::into_base(queue)
+            }
+        }
+
+        impl<$($g_lt ,)* F, $($g ,)* $(const $c: $c_ty ,)*> From<$crate::queue::QueueSingle<F>> for $empty<$($g_lt ,)* $($g ,)* $($c ,)* F>
+        where
+            F: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            #[inline(always)]
+            fn from(base: $crate::queue::QueueSingle<F>) -> Self {
+                $crate::queue::FromBase::from_base(base)
+            }
+        }
+
+        impl<$($g_lt ,)* F, B, BB, $($g ,)* $(const $c: $c_ty ,)*> From<$crate::queue::Queue<F, BB>> for $pair<$($g_lt ,)* $($g ,)* $($c ,)* F, B>
+        where
+            F: $($el_bnd)*,
+            B: $q<$($g_lt ,)* $($g ,)* $($c ,)*> + $crate::queue::FromBase<BB>,
+            BB: $crate::queue::StQueue,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            #[inline(always)]
+            fn from(base: $crate::queue::Queue<F, BB>) -> Self {
+                $crate::queue::FromBase::from_base(base)
+            }
+        }
+
+        // # refs
+
+        impl<'__orx_q, $($g_lt ,)* F, $($g ,)* $(const $c: $c_ty ,)*> $crate::queue::AsRefs<'__orx_q> for $empty<$($g_lt ,)* $($g ,)* $($c ,)* F>
+        where
+            F: $($el_bnd)*,
+            F: '__orx_q,
+            &'__orx_q F: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            type Refs = $empty<$($g_lt ,)* $($g ,)* $($c ,)* &'__orx_q F>;
+
+            #[inline(always)]
+            fn as_refs(&'__orx_q self) -> Self::Refs {
+                $empty::new(&self.f)
+            }
+        }
+
+        impl<'__orx_q, $($g_lt ,)* F, B, $($g ,)* $(const $c: $c_ty ,)*> $crate::queue::AsRefs<'__orx_q> for $pair<$($g_lt ,)* $($g ,)* $($c ,)* F, B>
+        where
+            F: $($el_bnd)*,
+            F: '__orx_q,
+            &'__orx_q F: $($el_bnd)*,
+            B: $q<$($g_lt ,)* $($g ,)* $($c ,)*> + $crate::queue::AsRefs<'__orx_q>,
+            B::Refs: $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            type Refs = $pair<$($g_lt ,)* $($g ,)* $($c ,)* &'__orx_q F, B::Refs>;
+
+            #[inline(always)]
+            fn as_refs(&'__orx_q self) -> Self::Refs {
+                $pair::from_fb(&self.f, $crate::queue::AsRefs::as_refs(&self.b))
+            }
+        }
+
+        impl<'__orx_q, $($g_lt ,)* F, $($g ,)* $(const $c: $c_ty ,)*> $crate::queue::AsMuts<'__orx_q> for $empty<$($g_lt ,)* $($g ,)* $($c ,)* F>
+        where
+            F: $($el_bnd)*,
+            F: '__orx_q,
+            &'__orx_q mut F: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            type Muts = $empty<$($g_lt ,)* $($g ,)* $($c ,)* &'__orx_q mut F>;
+
+            #[inline(always)]
+            fn as_muts(&'__orx_q mut self) -> Self::Muts {
+                $empty::new(&mut self.f)
+            }
+        }
+
+        impl<'__orx_q, $($g_lt ,)* F, B, $($g ,)* $(const $c: $c_ty ,)*> $crate::queue::AsMuts<'__orx_q> for $pair<$($g_lt ,)* $($g ,)* $($c ,)* F, B>
+        where
+            F: $($el_bnd)*,
+            F: '__orx_q,
+            &'__orx_q mut F: $($el_bnd)*,
+            B: $q<$($g_lt ,)* $($g ,)* $($c ,)*> + $crate::queue::AsMuts<'__orx_q>,
+            B::Muts: $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            type Muts = $pair<$($g_lt ,)* $($g ,)* $($c ,)* &'__orx_q mut F, B::Muts>;
+
+            #[inline(always)]
+            fn as_muts(&'__orx_q mut self) -> Self::Muts {
+                $pair::from_fb(&mut self.f, $crate::queue::AsMuts::as_muts(&mut self.b))
+            }
+        }
+
+        impl<'__orx_q, $($g_lt ,)* F, $($g ,)* $(const $c: $c_ty ,)*> $crate::queue::Cloned for $empty<$($g_lt ,)* $($g ,)* $($c ,)* &'__orx_q F>
+        where
+            F: Clone,
+            F: $($el_bnd)*,
+            &'__orx_q F: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            type Output = $empty<$($g_lt ,)* $($g ,)* $($c ,)* F>;
+
+            #[inline(always)]
+            fn cloned(self) -> Self::Output {
+                $empty::new(self.f.clone())
             }
+        }
 
-            /// Consumes the queue and returns the tuple of its front and back:
-            ///
-            /// * **front** is the element in the front of this queue.
-            /// * **back** is the queue including all elements of this queue except
-            ///   for the front element. In other words, it is the queue obtained by
-            ///   popping the front element.
-            ///
-            /// # Examples
-            ///
-            /// ```ignore
-            /// use orx_meta::queue::*;
-            ///
-            /// let queue = Queue::new(42);
-            /// let num = queue.pop(); // QueueSingle::pop just returns the front
-            /// assert_eq!(num, 42);
-            ///
-            /// let queue = Queue::new(42).push(true).push('x').push("foo");
-            ///
-            /// let (num, queue) = queue.pop(); // Queue::pop returns (front, back)
-            /// assert_eq!(num, 42);
-            /// assert_eq!(queue, Queue::new(true).push('x').push("foo"));
-            ///
-            /// let (flag, queue) = queue.pop();
-            /// assert_eq!(flag, true);
-            /// assert_eq!(queue, Queue::new('x').push("foo"));
-            ///
-            /// let (c, queue) = queue.pop();
-            /// assert_eq!(c, 'x');
-            /// assert_eq!(queue, Queue::new("foo"));
-            ///
-            /// let s = queue.pop();
-            /// assert_eq!(s, "foo");
-            /// ```
-            pub fn pop(self) -> (F, B) {
-                (self.f, self.b)
+        impl<'__orx_q, $($g_lt ,)* F, B, $($g ,)* $(const $c: $c_ty ,)*> $crate::queue::Cloned for $pair<$($g_lt ,)* $($g ,)* $($c ,)* &'__orx_q F, B>
+        where
+            F: Clone,
+            F: $($el_bnd)*,
+            &'__orx_q F: $($el_bnd)*,
+            B: $q<$($g_lt ,)* $($g ,)* $($c ,)*> + $crate::queue::Cloned,
+            B::Output: $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            type Output = $pair<$($g_lt ,)* $($g ,)* $($c ,)* F, B::Output>;
+
+            #[inline(always)]
+            fn cloned(self) -> Self::Output {
+                $pair::from_fb(self.f.clone(), $crate::queue::Cloned::cloned(self.b))
             }
         }
 
-        impl<$($g_lt ,)* F, B, $($g ,)*> core::fmt::Debug for $pair<$($g_lt ,)* $($g ,)* F, B>
+        impl<'__orx_q, $($g_lt ,)* F, $($g ,)* $(const $c: $c_ty ,)*> $crate::queue::Copied for $empty<$($g_lt ,)* $($g ,)* $($c ,)* &'__orx_q F>
         where
-            F: core::fmt::Debug,
-            B: core::fmt::Debug,
-            F: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            B: $q<$($g_lt ,)* $($g ,)*>,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            F: Copy,
+            F: $($el_bnd)*,
+            &'__orx_q F: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
-            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                write!(f, "{}({:?}, {:?})", stringify!($pair), self.f, self.b)
+            type Output = $empty<$($g_lt ,)* $($g ,)* $($c ,)* F>;
+
+            #[inline(always)]
+            fn copied(self) -> Self::Output {
+                $empty::new(*self.f)
             }
         }
 
-        impl<$($g_lt ,)* F, B, $($g ,)*> $q<$($g_lt ,)* $($g ,)*> for $pair<$($g_lt ,)* $($g ,)* F, B>
+        impl<'__orx_q, $($g_lt ,)* F, B, $($g ,)* $(const $c: $c_ty ,)*> $crate::queue::Copied for $pair<$($g_lt ,)* $($g ,)* $($c ,)* &'__orx_q F, B>
         where
-            F: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            B: $q<$($g_lt ,)* $($g ,)*>,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            F: Copy,
+            F: $($el_bnd)*,
+            &'__orx_q F: $($el_bnd)*,
+            B: $q<$($g_lt ,)* $($g ,)* $($c ,)*> + $crate::queue::Copied,
+            B::Output: $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
-            type PushBack<Elem> = $pair<$($g_lt ,)* $($g ,)* F, B::PushBack<Elem>>
-            where
-                Elem: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *;
+            type Output = $pair<$($g_lt ,)* $($g ,)* $($c ,)* F, B::Output>;
 
-            type Front = F;
+            #[inline(always)]
+            fn copied(self) -> Self::Output {
+                $pair::from_fb(*self.f, $crate::queue::Copied::copied(self.b))
+            }
+        }
 
-            type Back = B;
+        // # zip
 
-            const LEN: usize = 1 + B::LEN;
+        impl<$($g_lt ,)* F, F2, $($g ,)* $(const $c: $c_ty ,)*> $crate::queue::Zip<$empty<$($g_lt ,)* $($g ,)* $($c ,)* F2>> for $empty<$($g_lt ,)* $($g ,)* $($c ,)* F>
+        where
+            F: $($el_bnd)*,
+            F2: $($el_bnd)*,
+            (F, F2): $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            type Zipped = $empty<$($g_lt ,)* $($g ,)* $($c ,)* (F, F2)>;
 
-            fn push<Elem>(self, x: Elem) -> Self::PushBack<Elem>
-            where
-                Elem: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *
-            {
-                $pair::from_fb(self.f, self.b.push(x))
+            #[inline(always)]
+            fn zip(self, other: $empty<$($g_lt ,)* $($g ,)* $($c ,)* F2>) -> Self::Zipped {
+                $empty::new((self.f, other.f))
             }
+        }
+
+        impl<$($g_lt ,)* F, B, F2, B2, $($g ,)* $(const $c: $c_ty ,)*> $crate::queue::Zip<$pair<$($g_lt ,)* $($g ,)* $($c ,)* F2, B2>> for $pair<$($g_lt ,)* $($g ,)* $($c ,)* F, B>
+        where
+            F: $($el_bnd)*,
+            F2: $($el_bnd)*,
+            (F, F2): $($el_bnd)*,
+            B: $q<$($g_lt ,)* $($g ,)* $($c ,)*> + $crate::queue::Zip<B2>,
+            B2: $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            B::Zipped: $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            type Zipped = $pair<$($g_lt ,)* $($g ,)* $($c ,)* (F, F2), B::Zipped>;
 
             #[inline(always)]
-            fn front(&self) -> &Self::Front {
-                &self.f
+            fn zip(self, other: $pair<$($g_lt ,)* $($g ,)* $($c ,)* F2, B2>) -> Self::Zipped {
+                $pair::from_fb((self.f, other.f), $crate::queue::Zip::zip(self.b, other.b))
             }
+        }
+
+        impl<$($g_lt ,)* X, Y, $($g ,)* $(const $c: $c_ty ,)*> $crate::queue::Unzip for $empty<$($g_lt ,)* $($g ,)* $($c ,)* (X, Y)>
+        where
+            X: $($el_bnd)*,
+            Y: $($el_bnd)*,
+            (X, Y): $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            type Left = $empty<$($g_lt ,)* $($g ,)* $($c ,)* X>;
+
+            type Right = $empty<$($g_lt ,)* $($g ,)* $($c ,)* Y>;
 
             #[inline(always)]
-            fn front_mut(&mut self) -> &mut Self::Front {
-                &mut self.f
+            fn unzip(self) -> (Self::Left, Self::Right) {
+                let (x, y) = self.f;
+                ($empty::new(x), $empty::new(y))
             }
+        }
+
+        impl<$($g_lt ,)* X, Y, B, $($g ,)* $(const $c: $c_ty ,)*> $crate::queue::Unzip for $pair<$($g_lt ,)* $($g ,)* $($c ,)* (X, Y), B>
+        where
+            X: $($el_bnd)*,
+            Y: $($el_bnd)*,
+            (X, Y): $($el_bnd)*,
+            B: $q<$($g_lt ,)* $($g ,)* $($c ,)*> + $crate::queue::Unzip,
+            B::Left: $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            B::Right: $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            type Left = $pair<$($g_lt ,)* $($g ,)* $($c ,)* X, B::Left>;
+
+            type Right = $pair<$($g_lt ,)* $($g ,)* $($c ,)* Y, B::Right>;
 
             #[inline(always)]
-            fn into_front(self) -> Self::Front {
-                self.f
+            fn unzip(self) -> (Self::Left, Self::Right) {
+                let (x, y) = self.f;
+                let (left, right) = $crate::queue::Unzip::unzip(self.b);
+                ($pair::from_fb(x, left), $pair::from_fb(y, right))
             }
         }
     };
@@ -1126,12 +2345,17 @@ macro_rules! define_queue_builder {
     (
         lt => [$($g_lt:tt), *];
         generics => [ $( $g:tt $( : $( $g_bnd:ident $( < $( $g_bnd_g:tt ),* > )? )| * )? ), * ];
+        consts => [ $( const $c:ident : $c_ty:ty ),* ];
+        where => [ $($wh:tt)* ];
+        elements => [ $($el_bnd:tt)* ];
+        vis => [$vis:vis];
         queue => [$q:ident ; $empty:ident, $pair:ident];
-        builder => $builder:ident;
+        builder => $(#[$builder_attr:meta])* $builder:ident;
     ) => {
 
         // builder
 
+        $(#[$builder_attr])*
         /// A type-safe builder for queues such that:
         ///
         /// * `push` can only be called correct number of times with correct types,
@@ -1217,33 +2441,47 @@ macro_rules! define_queue_builder {
         ///     .finish(); // forgot to push the String
         /// assert_eq!(instance.as_tuple(), (&42, &true, &'x', &"foo".to_string()));
         /// ```
-        pub struct $builder<$($g_lt ,)* $($g ,)* Target>
+        $vis struct $builder<$($g_lt ,)* $($g ,)* $(const $c: $c_ty ,)* Target>
         where
-            Target:  $q<$($g_lt ,)* $($g ,)*>,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            Target:  $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
             target: core::marker::PhantomData<Target>,
+            phantom: core::marker::PhantomData<$(&$g_lt)* ($($g ,)*)>,
         }
 
-        impl<$($g_lt ,)* $($g ,)* Target> Default for $builder<$($g_lt ,)* $($g ,)* Target>
+        impl<$($g_lt ,)* $($g ,)* $(const $c: $c_ty ,)* Target> Default for $builder<$($g_lt ,)* $($g ,)* $($c ,)* Target>
         where
-            Target:  $q<$($g_lt ,)* $($g ,)*>,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            Target:  $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
             fn default() -> Self {
                 Self::new()
             }
         }
 
-        impl<$($g_lt ,)* $($g ,)* Target> $builder<$($g_lt ,)* $($g ,)* Target>
+        impl<$($g_lt ,)* $($g ,)* $(const $c: $c_ty ,)* Target> core::fmt::Debug for $builder<$($g_lt ,)* $($g ,)* $($c ,)* Target>
+        where
+            Target:  $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_struct(stringify!($builder))
+                    .field("target", &core::any::type_name::<Target>())
+                    .finish()
+            }
+        }
+
+        impl<$($g_lt ,)* $($g ,)* $(const $c: $c_ty ,)* Target> $builder<$($g_lt ,)* $($g ,)* $($c ,)* Target>
         where
-            Target:  $q<$($g_lt ,)* $($g ,)*>,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            Target:  $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
             /// Creates a new empty builder for the `Target` type defined as the generic argument.
             pub fn new() -> Self {
                 Self {
                     target: Default::default(),
+                    phantom: Default::default(),
                 }
             }
 
@@ -1252,19 +2490,79 @@ macro_rules! define_queue_builder {
             pub fn push(
                 self,
                 element: Target::Front,
-            ) -> QueueBuilding<Target, Target::Back, $empty<$($g_lt ,)* $($g ,)* Target::Front>> {
+            ) -> QueueBuilding<$($g_lt ,)* $($g ,)* $($c ,)* Target, Target::Back, $empty<$($g_lt ,)* $($g ,)* $($c ,)* Target::Front>>
+            where
+                Target::Front: $($el_bnd)*,
+            {
                 QueueBuilding::new($empty::new(element))
             }
+
+            /// Tries to convert the `value` into the next element and push it to build the target type.
+            ///
+            /// Returns the error pointing to the position and type of the element if the conversion fails.
+            #[inline(always)]
+            #[allow(clippy::type_complexity)]
+            pub fn try_push<V>(
+                self,
+                value: V,
+            ) -> Result<
+                QueueBuilding<$($g_lt ,)* $($g ,)* $($c ,)* Target, Target::Back, $empty<$($g_lt ,)* $($g ,)* $($c ,)* Target::Front>>,
+                $crate::queue::SlotError<V::Error>,
+            >
+            where
+                Target::Front: $($el_bnd)*,
+                V: TryInto<Target::Front>,
+            {
+                match value.try_into() {
+                    Ok(element) => Ok(self.push(element)),
+                    Err(error) => Err($crate::queue::SlotError {
+                        position: 0,
+                        type_name: core::any::type_name::<Target::Front>(),
+                        error,
+                    }),
+                }
+            }
+
+            /// Pushes the default value of the next element to build the target type.
+            #[inline(always)]
+            pub fn skip(
+                self,
+            ) -> QueueBuilding<$($g_lt ,)* $($g ,)* $($c ,)* Target, Target::Back, $empty<$($g_lt ,)* $($g ,)* $($c ,)* Target::Front>>
+            where
+                Target::Front: Default,
+                Target::Front: $($el_bnd)*,
+            {
+                self.push(Default::default())
+            }
+        }
+
+        impl<$($g_lt ,)* $($g ,)* $(const $c: $c_ty ,)* Target> $crate::queue::BuilderProgress for $builder<$($g_lt ,)* $($g ,)* $($c ,)* Target>
+        where
+            Target:  $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            const PUSHED: usize = 0;
+
+            const REMAINING: usize = Target::LEN;
+
+            fn next_type_name(&self) -> Option<&'static str> {
+                Some(core::any::type_name::<Target::Front>())
+            }
         }
 
         // building
 
-        pub struct QueueBuilding<$($g_lt ,)* $($g ,)* Target, Remaining, Current>
+        /// A builder in progress which is obtained by pushing elements to the queue builder.
+        ///
+        /// * `Target` is the queue type to be built,
+        /// * `Remaining` is the queue of element types which are not pushed yet, and
+        /// * `Current` is the queue of elements pushed so far.
+        $vis struct QueueBuilding<$($g_lt ,)* $($g ,)* $(const $c: $c_ty ,)* Target, Remaining, Current>
         where
-            Target:  $q<$($g_lt ,)* $($g ,)*>,
-            Remaining:  $q<$($g_lt ,)* $($g ,)*>,
-            Current:  $q<$($g_lt ,)* $($g ,)*>,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            Target:  $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            Remaining:  $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            Current:  $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
             target: core::marker::PhantomData<Target>,
             remaining: core::marker::PhantomData<Remaining>,
@@ -1272,19 +2570,46 @@ macro_rules! define_queue_builder {
             phantom: core::marker::PhantomData<$(&$g_lt)* ($($g ,)*)>,
         }
 
-        impl<$($g_lt ,)* $($g ,)* Target, Remaining, Current> QueueBuilding<$($g_lt ,)* $($g ,)* Target, Remaining, Current>
+        impl<$($g_lt ,)* $($g ,)* $(const $c: $c_ty ,)* Target, Remaining, Current> Default for QueueBuilding<$($g_lt ,)* $($g ,)* $($c ,)* Target, Remaining, Current>
+        where
+            Target:  $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            Remaining:  $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            Current:  $q<$($g_lt ,)* $($g ,)* $($c ,)*> + Default,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            fn default() -> Self {
+                Self::new(Current::default())
+            }
+        }
+
+        impl<$($g_lt ,)* $($g ,)* $(const $c: $c_ty ,)* Target, Remaining, Current> core::fmt::Debug for QueueBuilding<$($g_lt ,)* $($g ,)* $($c ,)* Target, Remaining, Current>
+        where
+            Target:  $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            Remaining:  $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            Current:  $q<$($g_lt ,)* $($g ,)* $($c ,)*> + core::fmt::Debug,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_struct("QueueBuilding")
+                    .field("current", &self.current)
+                    .field("remaining", &<Self as $crate::queue::BuilderProgress>::REMAINING)
+                    .finish()
+            }
+        }
+
+        impl<$($g_lt ,)* $($g ,)* $(const $c: $c_ty ,)* Target, Remaining, Current> QueueBuilding<$($g_lt ,)* $($g ,)* $($c ,)* Target, Remaining, Current>
         where
-            Target:  $q<$($g_lt ,)* $($g ,)*>,
-            Remaining:  $q<$($g_lt ,)* $($g ,)*>,
-            Current:  $q<$($g_lt ,)* $($g ,)*>,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            Target:  $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            Remaining:  $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            Current:  $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
             #[inline(always)]
             fn new(current: Current) -> Self {
                 Self {
                     target: Default::default(),
                     remaining: Default::default(),
-                    current: current,
+                    current,
                     phantom: Default::default(),
                 }
             }
@@ -1294,19 +2619,81 @@ macro_rules! define_queue_builder {
             pub fn push(
                 self,
                 element: Remaining::Front,
-            ) -> QueueBuilding<Target, Remaining::Back, Current::PushBack<Remaining::Front>> {
+            ) -> QueueBuilding<$($g_lt ,)* $($g ,)* $($c ,)* Target, Remaining::Back, Current::PushBack<Remaining::Front>>
+            where
+                Remaining::Front: $($el_bnd)*,
+            {
                 QueueBuilding::new(self.current.push(element))
             }
 
+            /// Tries to convert the `value` into the next element and push it to build the target type.
+            ///
+            /// Returns the error pointing to the position and type of the element if the conversion fails.
+            #[inline(always)]
+            #[allow(clippy::type_complexity)]
+            pub fn try_push<V>(
+                self,
+                value: V,
+            ) -> Result<
+                QueueBuilding<$($g_lt ,)* $($g ,)* $($c ,)* Target, Remaining::Back, Current::PushBack<Remaining::Front>>,
+                $crate::queue::SlotError<V::Error>,
+            >
+            where
+                Remaining::Front: $($el_bnd)*,
+                V: TryInto<Remaining::Front>,
+            {
+                match value.try_into() {
+                    Ok(element) => Ok(self.push(element)),
+                    Err(error) => Err($crate::queue::SlotError {
+                        position: Current::LEN,
+                        type_name: core::any::type_name::<Remaining::Front>(),
+                        error,
+                    }),
+                }
+            }
+
+            /// Pushes the default value of the next element to build the target type.
+            #[inline(always)]
+            pub fn skip(
+                self,
+            ) -> QueueBuilding<$($g_lt ,)* $($g ,)* $($c ,)* Target, Remaining::Back, Current::PushBack<Remaining::Front>>
+            where
+                Remaining::Front: Default,
+                Remaining::Front: $($el_bnd)*,
+            {
+                self.push(Default::default())
+            }
+
             /// Completes the builder and returns the built target type.
             #[inline(always)]
             pub fn finish(self) -> Current
             where
-                Target: $q<$($g_lt ,)* $($g ,)* Front = Current::Front, Back = Current::Back>,
+                Target: $q<$($g_lt ,)* $($g ,)* $($c ,)* Front = Current::Front, Back = Current::Back>,
+                Current: $($el_bnd)*,
             {
                 self.current
             }
         }
+
+        impl<$($g_lt ,)* $($g ,)* $(const $c: $c_ty ,)* Target, Remaining, Current> $crate::queue::BuilderProgress for QueueBuilding<$($g_lt ,)* $($g ,)* $($c ,)* Target, Remaining, Current>
+        where
+            Target:  $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            Remaining:  $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            Current:  $q<$($g_lt ,)* $($g ,)* $($c ,)*>,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            const PUSHED: usize = Current::LEN;
+
+            // saturating since the public `Default` does not require `Current` to be a prefix of `Target`
+            const REMAINING: usize = Target::LEN.saturating_sub(Current::LEN);
+
+            fn next_type_name(&self) -> Option<&'static str> {
+                match <Self as $crate::queue::BuilderProgress>::REMAINING {
+                    0 => None,
+                    _ => Some(core::any::type_name::<Remaining::Front>()),
+                }
+            }
+        }
     };
 }
 
@@ -1318,16 +2705,18 @@ macro_rules! define_nonempty_queue_tuple_transformation {
     (
         lt => [$($g_lt:tt), *];
         generics => [ $( $g:tt $( : $( $g_bnd:ident $( < $( $g_bnd_g:tt ),* > )? )| * )? ), * ];
-        elements => [ $( $el_bnd:ident $( < $( $el_bnd_g:tt ),* > )? )| * ];
+        consts => [ $( const $c:ident : $c_ty:ty ),* ];
+        where => [ $($wh:tt)* ];
+        elements => [ $($el_bnd:tt)* ];
         queue => [$q:ident ; $empty:ident, $pair:ident];
     ) => {
         // tuple - 1
 
         #[allow(dead_code)]
-        impl<$($g_lt ,)* X1, $($g ,)*> $empty<$($g_lt ,)* $($g ,)* X1>
+        impl<$($g_lt ,)* X1, $($g ,)* $(const $c: $c_ty ,)*> $empty<$($g_lt ,)* $($g ,)* $($c ,)* X1>
         where
-            X1: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            X1: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
             /// Converts the queue into its flat tuple representation.
             ///
@@ -1403,10 +2792,10 @@ macro_rules! define_nonempty_queue_tuple_transformation {
             }
         }
 
-        impl<$($g_lt ,)* $($g ,)* X1> From<X1> for $empty<$($g_lt ,)* $($g ,)* X1>
+        impl<$($g_lt ,)* $($g ,)* $(const $c: $c_ty ,)* X1> From<X1> for $empty<$($g_lt ,)* $($g ,)* $($c ,)* X1>
         where
-            X1: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            X1: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
             #[inline(always)]
             fn from(x: X1) -> Self {
@@ -1417,11 +2806,11 @@ macro_rules! define_nonempty_queue_tuple_transformation {
         // tuple - 2
 
         #[allow(dead_code)]
-        impl<$($g_lt ,)* $($g ,)* X1, X2> $pair<$($g_lt ,)* $($g ,)* X1, $empty<$($g_lt ,)* $($g ,)* X2>>
+        impl<$($g_lt ,)* $($g ,)* $(const $c: $c_ty ,)* X1, X2> $pair<$($g_lt ,)* $($g ,)* $($c ,)* X1, $empty<$($g_lt ,)* $($g ,)* $($c ,)* X2>>
         where
-            X1: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X2: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            X1: $($el_bnd)*,
+            X2: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
             /// Converts the queue into its flat tuple representation.
             ///
@@ -1497,11 +2886,11 @@ macro_rules! define_nonempty_queue_tuple_transformation {
             }
         }
 
-        impl<$($g_lt ,)* $($g ,)* X1, X2> From<(X1, X2)> for $pair<$($g_lt ,)* $($g ,)* X1, $empty<$($g_lt ,)* $($g ,)* X2>>
+        impl<$($g_lt ,)* $($g ,)* $(const $c: $c_ty ,)* X1, X2> From<(X1, X2)> for $pair<$($g_lt ,)* $($g ,)* $($c ,)* X1, $empty<$($g_lt ,)* $($g ,)* $($c ,)* X2>>
         where
-            X1: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X2: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            X1: $($el_bnd)*,
+            X2: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
             #[inline(always)]
             fn from(x: (X1, X2)) -> Self {
@@ -1512,15 +2901,15 @@ macro_rules! define_nonempty_queue_tuple_transformation {
         // tuple - 3
 
         #[allow(dead_code)]
-        impl<$($g_lt ,)* $($g ,)* X1, X2, X3>
-            $pair<$($g_lt ,)* $($g ,)* X1,
-                $pair<$($g_lt ,)* $($g ,)* X2, $empty<$($g_lt ,)* $($g ,)* X3>>
+        impl<$($g_lt ,)* $($g ,)* $(const $c: $c_ty ,)* X1, X2, X3>
+            $pair<$($g_lt ,)* $($g ,)* $($c ,)* X1,
+                $pair<$($g_lt ,)* $($g ,)* $($c ,)* X2, $empty<$($g_lt ,)* $($g ,)* $($c ,)* X3>>
             >
         where
-            X1: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X2: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X3: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            X1: $($el_bnd)*,
+            X2: $($el_bnd)*,
+            X3: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
             /// Converts the queue into its flat tuple representation.
             ///
@@ -1596,15 +2985,15 @@ macro_rules! define_nonempty_queue_tuple_transformation {
             }
         }
 
-        impl<$($g_lt ,)* $($g ,)* X1, X2, X3> From<(X1, X2, X3)> for
-            $pair<$($g_lt ,)* $($g ,)* X1,
-                $pair<$($g_lt ,)* $($g ,)* X2, $empty<$($g_lt ,)* $($g ,)* X3>>
+        impl<$($g_lt ,)* $($g ,)* $(const $c: $c_ty ,)* X1, X2, X3> From<(X1, X2, X3)> for
+            $pair<$($g_lt ,)* $($g ,)* $($c ,)* X1,
+                $pair<$($g_lt ,)* $($g ,)* $($c ,)* X2, $empty<$($g_lt ,)* $($g ,)* $($c ,)* X3>>
             >
         where
-            X1: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X2: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X3: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            X1: $($el_bnd)*,
+            X2: $($el_bnd)*,
+            X3: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
             #[inline(always)]
             fn from(x: (X1, X2, X3)) -> Self {
@@ -1615,18 +3004,18 @@ macro_rules! define_nonempty_queue_tuple_transformation {
         // tuple - 4
 
         #[allow(dead_code)]
-        impl<$($g_lt ,)* $($g ,)* X1, X2, X3, X4>
-            $pair<$($g_lt ,)* $($g ,)* X1,
-                $pair<$($g_lt ,)* $($g ,)* X2,
-                    $pair<$($g_lt ,)* $($g ,)* X3, $empty<$($g_lt ,)* $($g ,)* X4>>
+        impl<$($g_lt ,)* $($g ,)* $(const $c: $c_ty ,)* X1, X2, X3, X4>
+            $pair<$($g_lt ,)* $($g ,)* $($c ,)* X1,
+                $pair<$($g_lt ,)* $($g ,)* $($c ,)* X2,
+                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* X3, $empty<$($g_lt ,)* $($g ,)* $($c ,)* X4>>
                 >
             >
         where
-            X1: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X2: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X3: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X4: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            X1: $($el_bnd)*,
+            X2: $($el_bnd)*,
+            X3: $($el_bnd)*,
+            X4: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
             /// Converts the queue into its flat tuple representation.
             ///
@@ -1702,18 +3091,18 @@ macro_rules! define_nonempty_queue_tuple_transformation {
             }
         }
 
-        impl<$($g_lt ,)* $($g ,)* X1, X2, X3, X4> From<(X1, X2, X3, X4)> for
-            $pair<$($g_lt ,)* $($g ,)* X1,
-                $pair<$($g_lt ,)* $($g ,)* X2,
-                    $pair<$($g_lt ,)* $($g ,)* X3, $empty<$($g_lt ,)* $($g ,)* X4>>
+        impl<$($g_lt ,)* $($g ,)* $(const $c: $c_ty ,)* X1, X2, X3, X4> From<(X1, X2, X3, X4)> for
+            $pair<$($g_lt ,)* $($g ,)* $($c ,)* X1,
+                $pair<$($g_lt ,)* $($g ,)* $($c ,)* X2,
+                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* X3, $empty<$($g_lt ,)* $($g ,)* $($c ,)* X4>>
                 >
             >
         where
-            X1: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X2: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X3: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X4: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            X1: $($el_bnd)*,
+            X2: $($el_bnd)*,
+            X3: $($el_bnd)*,
+            X4: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
             #[inline(always)]
             fn from(x: (X1, X2, X3, X4)) -> Self {
@@ -1724,21 +3113,21 @@ macro_rules! define_nonempty_queue_tuple_transformation {
         // tuple - 5
 
         #[allow(dead_code)]
-        impl<$($g_lt ,)* $($g ,)* X1, X2, X3, X4, X5>
-            $pair<$($g_lt ,)* $($g ,)* X1,
-                $pair<$($g_lt ,)* $($g ,)* X2,
-                    $pair<$($g_lt ,)* $($g ,)* X3,
-                        $pair<$($g_lt ,)* $($g ,)* X4, $empty<$($g_lt ,)* $($g ,)* X5>>
+        impl<$($g_lt ,)* $($g ,)* $(const $c: $c_ty ,)* X1, X2, X3, X4, X5>
+            $pair<$($g_lt ,)* $($g ,)* $($c ,)* X1,
+                $pair<$($g_lt ,)* $($g ,)* $($c ,)* X2,
+                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* X3,
+                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* X4, $empty<$($g_lt ,)* $($g ,)* $($c ,)* X5>>
                     >
                 >
             >
         where
-            X1: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X2: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X3: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X4: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X5: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            X1: $($el_bnd)*,
+            X2: $($el_bnd)*,
+            X3: $($el_bnd)*,
+            X4: $($el_bnd)*,
+            X5: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
             /// Converts the queue into its flat tuple representation.
             ///
@@ -1814,21 +3203,21 @@ macro_rules! define_nonempty_queue_tuple_transformation {
             }
         }
 
-        impl<$($g_lt ,)* $($g ,)* X1, X2, X3, X4, X5> From<(X1, X2, X3, X4, X5)> for
-            $pair<$($g_lt ,)* $($g ,)* X1,
-                $pair<$($g_lt ,)* $($g ,)* X2,
-                    $pair<$($g_lt ,)* $($g ,)* X3,
-                        $pair<$($g_lt ,)* $($g ,)* X4, $empty<$($g_lt ,)* $($g ,)* X5>>
+        impl<$($g_lt ,)* $($g ,)* $(const $c: $c_ty ,)* X1, X2, X3, X4, X5> From<(X1, X2, X3, X4, X5)> for
+            $pair<$($g_lt ,)* $($g ,)* $($c ,)* X1,
+                $pair<$($g_lt ,)* $($g ,)* $($c ,)* X2,
+                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* X3,
+                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* X4, $empty<$($g_lt ,)* $($g ,)* $($c ,)* X5>>
                     >
                 >
             >
         where
-            X1: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X2: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X3: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X4: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X5: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            X1: $($el_bnd)*,
+            X2: $($el_bnd)*,
+            X3: $($el_bnd)*,
+            X4: $($el_bnd)*,
+            X5: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
             #[inline(always)]
             fn from(x: (X1, X2, X3, X4, X5)) -> Self {
@@ -1839,24 +3228,24 @@ macro_rules! define_nonempty_queue_tuple_transformation {
         // tuple - 6
 
         #[allow(dead_code)]
-        impl<$($g_lt ,)* $($g ,)* X1, X2, X3, X4, X5, X6>
-            $pair<$($g_lt ,)* $($g ,)* X1,
-                $pair<$($g_lt ,)* $($g ,)* X2,
-                    $pair<$($g_lt ,)* $($g ,)* X3,
-                        $pair<$($g_lt ,)* $($g ,)* X4,
-                            $pair<$($g_lt ,)* $($g ,)* X5, $empty<$($g_lt ,)* $($g ,)* X6>>
+        impl<$($g_lt ,)* $($g ,)* $(const $c: $c_ty ,)* X1, X2, X3, X4, X5, X6>
+            $pair<$($g_lt ,)* $($g ,)* $($c ,)* X1,
+                $pair<$($g_lt ,)* $($g ,)* $($c ,)* X2,
+                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* X3,
+                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* X4,
+                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* X5, $empty<$($g_lt ,)* $($g ,)* $($c ,)* X6>>
                         >
                     >
                 >
             >
         where
-            X1: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X2: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X3: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X4: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X5: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X6: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            X1: $($el_bnd)*,
+            X2: $($el_bnd)*,
+            X3: $($el_bnd)*,
+            X4: $($el_bnd)*,
+            X5: $($el_bnd)*,
+            X6: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
             /// Converts the queue into its flat tuple representation.
             ///
@@ -1932,24 +3321,24 @@ macro_rules! define_nonempty_queue_tuple_transformation {
             }
         }
 
-        impl<$($g_lt ,)* $($g ,)* X1, X2, X3, X4, X5, X6> From<(X1, X2, X3, X4, X5, X6)> for
-            $pair<$($g_lt ,)* $($g ,)* X1,
-                $pair<$($g_lt ,)* $($g ,)* X2,
-                    $pair<$($g_lt ,)* $($g ,)* X3,
-                        $pair<$($g_lt ,)* $($g ,)* X4,
-                            $pair<$($g_lt ,)* $($g ,)* X5, $empty<$($g_lt ,)* $($g ,)* X6>>
+        impl<$($g_lt ,)* $($g ,)* $(const $c: $c_ty ,)* X1, X2, X3, X4, X5, X6> From<(X1, X2, X3, X4, X5, X6)> for
+            $pair<$($g_lt ,)* $($g ,)* $($c ,)* X1,
+                $pair<$($g_lt ,)* $($g ,)* $($c ,)* X2,
+                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* X3,
+                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* X4,
+                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* X5, $empty<$($g_lt ,)* $($g ,)* $($c ,)* X6>>
                         >
                     >
                 >
             >
         where
-            X1: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X2: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X3: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X4: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X5: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X6: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            X1: $($el_bnd)*,
+            X2: $($el_bnd)*,
+            X3: $($el_bnd)*,
+            X4: $($el_bnd)*,
+            X5: $($el_bnd)*,
+            X6: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
             #[inline(always)]
             fn from(x: (X1, X2, X3, X4, X5, X6)) -> Self {
@@ -1960,27 +3349,27 @@ macro_rules! define_nonempty_queue_tuple_transformation {
         // tuple - 7
 
         #[allow(dead_code)]
-        impl<$($g_lt ,)* $($g ,)* X1, X2, X3, X4, X5, X6, X7>
-            $pair<$($g_lt ,)* $($g ,)* X1,
-                $pair<$($g_lt ,)* $($g ,)* X2,
-                    $pair<$($g_lt ,)* $($g ,)* X3,
-                        $pair<$($g_lt ,)* $($g ,)* X4,
-                            $pair<$($g_lt ,)* $($g ,)* X5,
-                                $pair<$($g_lt ,)* $($g ,)* X6, $empty<$($g_lt ,)* $($g ,)* X7>>
+        impl<$($g_lt ,)* $($g ,)* $(const $c: $c_ty ,)* X1, X2, X3, X4, X5, X6, X7>
+            $pair<$($g_lt ,)* $($g ,)* $($c ,)* X1,
+                $pair<$($g_lt ,)* $($g ,)* $($c ,)* X2,
+                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* X3,
+                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* X4,
+                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* X5,
+                                $pair<$($g_lt ,)* $($g ,)* $($c ,)* X6, $empty<$($g_lt ,)* $($g ,)* $($c ,)* X7>>
                             >
                         >
                     >
                 >
             >
         where
-            X1: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X2: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X3: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X4: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X5: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X6: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X7: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            X1: $($el_bnd)*,
+            X2: $($el_bnd)*,
+            X3: $($el_bnd)*,
+            X4: $($el_bnd)*,
+            X5: $($el_bnd)*,
+            X6: $($el_bnd)*,
+            X7: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
             /// Converts the queue into its flat tuple representation.
             ///
@@ -2056,27 +3445,27 @@ macro_rules! define_nonempty_queue_tuple_transformation {
             }
         }
 
-        impl<$($g_lt ,)* $($g ,)* X1, X2, X3, X4, X5, X6, X7> From<(X1, X2, X3, X4, X5, X6, X7)> for
-            $pair<$($g_lt ,)* $($g ,)* X1,
-                $pair<$($g_lt ,)* $($g ,)* X2,
-                    $pair<$($g_lt ,)* $($g ,)* X3,
-                        $pair<$($g_lt ,)* $($g ,)* X4,
-                            $pair<$($g_lt ,)* $($g ,)* X5,
-                                $pair<$($g_lt ,)* $($g ,)* X6, $empty<$($g_lt ,)* $($g ,)* X7>>
+        impl<$($g_lt ,)* $($g ,)* $(const $c: $c_ty ,)* X1, X2, X3, X4, X5, X6, X7> From<(X1, X2, X3, X4, X5, X6, X7)> for
+            $pair<$($g_lt ,)* $($g ,)* $($c ,)* X1,
+                $pair<$($g_lt ,)* $($g ,)* $($c ,)* X2,
+                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* X3,
+                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* X4,
+                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* X5,
+                                $pair<$($g_lt ,)* $($g ,)* $($c ,)* X6, $empty<$($g_lt ,)* $($g ,)* $($c ,)* X7>>
                             >
                         >
                     >
                 >
             >
         where
-            X1: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X2: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X3: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X4: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X5: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X6: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X7: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            X1: $($el_bnd)*,
+            X2: $($el_bnd)*,
+            X3: $($el_bnd)*,
+            X4: $($el_bnd)*,
+            X5: $($el_bnd)*,
+            X6: $($el_bnd)*,
+            X7: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
             #[inline(always)]
             fn from(x: (X1, X2, X3, X4, X5, X6, X7)) -> Self {
@@ -2087,14 +3476,14 @@ macro_rules! define_nonempty_queue_tuple_transformation {
         // tuple - 8
 
         #[allow(dead_code)]
-        impl<$($g_lt ,)* $($g ,)* X1, X2, X3, X4, X5, X6, X7, X8>
-            $pair<$($g_lt ,)* $($g ,)* X1,
-                $pair<$($g_lt ,)* $($g ,)* X2,
-                    $pair<$($g_lt ,)* $($g ,)* X3,
-                        $pair<$($g_lt ,)* $($g ,)* X4,
-                            $pair<$($g_lt ,)* $($g ,)* X5,
-                                $pair<$($g_lt ,)* $($g ,)* X6,
-                                    $pair<$($g_lt ,)* $($g ,)* X7, $empty<$($g_lt ,)* $($g ,)* X8>>
+        impl<$($g_lt ,)* $($g ,)* $(const $c: $c_ty ,)* X1, X2, X3, X4, X5, X6, X7, X8>
+            $pair<$($g_lt ,)* $($g ,)* $($c ,)* X1,
+                $pair<$($g_lt ,)* $($g ,)* $($c ,)* X2,
+                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* X3,
+                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* X4,
+                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* X5,
+                                $pair<$($g_lt ,)* $($g ,)* $($c ,)* X6,
+                                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* X7, $empty<$($g_lt ,)* $($g ,)* $($c ,)* X8>>
                                 >
                             >
                         >
@@ -2102,15 +3491,15 @@ macro_rules! define_nonempty_queue_tuple_transformation {
                 >
             >
         where
-            X1: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X2: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X3: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X4: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X5: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X6: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X7: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X8: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            X1: $($el_bnd)*,
+            X2: $($el_bnd)*,
+            X3: $($el_bnd)*,
+            X4: $($el_bnd)*,
+            X5: $($el_bnd)*,
+            X6: $($el_bnd)*,
+            X7: $($el_bnd)*,
+            X8: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
             /// Converts the queue into its flat tuple representation.
             ///
@@ -2186,14 +3575,14 @@ macro_rules! define_nonempty_queue_tuple_transformation {
             }
         }
 
-        impl<$($g_lt ,)* $($g ,)* X1, X2, X3, X4, X5, X6, X7, X8> From<(X1, X2, X3, X4, X5, X6, X7, X8)> for
-            $pair<$($g_lt ,)* $($g ,)* X1,
-                $pair<$($g_lt ,)* $($g ,)* X2,
-                    $pair<$($g_lt ,)* $($g ,)* X3,
-                        $pair<$($g_lt ,)* $($g ,)* X4,
-                            $pair<$($g_lt ,)* $($g ,)* X5,
-                                $pair<$($g_lt ,)* $($g ,)* X6,
-                                    $pair<$($g_lt ,)* $($g ,)* X7, $empty<$($g_lt ,)* $($g ,)* X8>>
+        impl<$($g_lt ,)* $($g ,)* $(const $c: $c_ty ,)* X1, X2, X3, X4, X5, X6, X7, X8> From<(X1, X2, X3, X4, X5, X6, X7, X8)> for
+            $pair<$($g_lt ,)* $($g ,)* $($c ,)* X1,
+                $pair<$($g_lt ,)* $($g ,)* $($c ,)* X2,
+                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* X3,
+                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* X4,
+                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* X5,
+                                $pair<$($g_lt ,)* $($g ,)* $($c ,)* X6,
+                                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* X7, $empty<$($g_lt ,)* $($g ,)* $($c ,)* X8>>
                                 >
                             >
                         >
@@ -2201,15 +3590,15 @@ macro_rules! define_nonempty_queue_tuple_transformation {
                 >
             >
         where
-            X1: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X2: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X3: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X4: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X5: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X6: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X7: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            X8: $( $el_bnd $( < $( $el_bnd_g ),* > )? + ) *,
-            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? ), *
+            X1: $($el_bnd)*,
+            X2: $($el_bnd)*,
+            X3: $($el_bnd)*,
+            X4: $($el_bnd)*,
+            X5: $($el_bnd)*,
+            X6: $($el_bnd)*,
+            X7: $($el_bnd)*,
+            X8: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
         {
             #[inline(always)]
             fn from(x: (X1, X2, X3, X4, X5, X6, X7, X8)) -> Self {
@@ -2227,6 +3616,8 @@ macro_rules! define_queue_of {
     (
         lt => [$($g_lt:tt), *];
         generics => [ $( $g:tt $( : $( $g_bnd:ident $( < $( $g_bnd_g:tt ),* > )? )| * )? ), * ];
+        consts => [ $( const $c:ident : $c_ty:ty ),* ];
+        where => [ $($wh:tt)* ];
         queue => [$q:ident ; $empty:ident, $pair:ident];
         queue_of => $queue_of:ident;
     ) => {
@@ -2268,43 +3659,43 @@ macro_rules! define_queue_of {
         /// ```
         macro_rules! $queue_of {
             ($t1:ty) => {
-                $empty<$($g_lt ,)* $($g ,)* $t1>
+                $empty<$($g_lt ,)* $($g ,)* $($c ,)* $t1>
             };
 
             ($t1:ty, $t2:ty) => {
-                $pair<$($g_lt ,)* $($g ,)* $t1, $empty<$($g_lt ,)* $($g ,)* $t2>>
+                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t1, $empty<$($g_lt ,)* $($g ,)* $($c ,)* $t2>>
             };
 
             ($t1:ty, $t2:ty, $t3:ty) => {
-                $pair<$($g_lt ,)* $($g ,)* $t1,
-                    $pair<$($g_lt ,)* $($g ,)* $t2, $empty<$($g_lt ,)* $($g ,)* $t3>>
+                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t1,
+                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t2, $empty<$($g_lt ,)* $($g ,)* $($c ,)* $t3>>
                 >
             };
 
             ($t1:ty, $t2:ty, $t3:ty, $t4:ty) => {
-                $pair<$($g_lt ,)* $($g ,)* $t1,
-                    $pair<$($g_lt ,)* $($g ,)* $t2,
-                        $pair<$($g_lt ,)* $($g ,)* $t3, $empty<$($g_lt ,)* $($g ,)* $t4>>
+                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t1,
+                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t2,
+                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t3, $empty<$($g_lt ,)* $($g ,)* $($c ,)* $t4>>
                     >
                 >
             };
 
             ($t1:ty, $t2:ty, $t3:ty, $t4:ty, $t5:ty) => {
-                $pair<$($g_lt ,)* $($g ,)* $t1,
-                    $pair<$($g_lt ,)* $($g ,)* $t2,
-                        $pair<$($g_lt ,)* $($g ,)* $t3,
-                            $pair<$($g_lt ,)* $($g ,)* $t4, $empty<$($g_lt ,)* $($g ,)* $t5>>
+                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t1,
+                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t2,
+                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t3,
+                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t4, $empty<$($g_lt ,)* $($g ,)* $($c ,)* $t5>>
                         >
                     >
                 >
             };
 
             ($t1:ty, $t2:ty, $t3:ty, $t4:ty, $t5:ty, $t6:ty) => {
-                $pair<$($g_lt ,)* $($g ,)* $t1,
-                    $pair<$($g_lt ,)* $($g ,)* $t2,
-                        $pair<$($g_lt ,)* $($g ,)* $t3,
-                            $pair<$($g_lt ,)* $($g ,)* $t4,
-                                $pair<$($g_lt ,)* $($g ,)* $t5, $empty<$($g_lt ,)* $($g ,)* $t6>>
+                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t1,
+                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t2,
+                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t3,
+                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t4,
+                                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t5, $empty<$($g_lt ,)* $($g ,)* $($c ,)* $t6>>
                             >
                         >
                     >
@@ -2312,12 +3703,12 @@ macro_rules! define_queue_of {
             };
 
             ($t1:ty, $t2:ty, $t3:ty, $t4:ty, $t5:ty, $t6:ty, $t7:ty) => {
-                $pair<$($g_lt ,)* $($g ,)* $t1,
-                    $pair<$($g_lt ,)* $($g ,)* $t2,
-                        $pair<$($g_lt ,)* $($g ,)* $t3,
-                            $pair<$($g_lt ,)* $($g ,)* $t4,
-                                $pair<$($g_lt ,)* $($g ,)* $t5,
-                                    $pair<$($g_lt ,)* $($g ,)* $t6, $empty<$($g_lt ,)* $($g ,)* $t7>>
+                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t1,
+                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t2,
+                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t3,
+                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t4,
+                                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t5,
+                                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t6, $empty<$($g_lt ,)* $($g ,)* $($c ,)* $t7>>
                                 >
                             >
                         >
@@ -2326,13 +3717,13 @@ macro_rules! define_queue_of {
             };
 
             ($t1:ty, $t2:ty, $t3:ty, $t4:ty, $t5:ty, $t6:ty, $t7:ty, $t8:ty) => {
-                $pair<$($g_lt ,)* $($g ,)* $t1,
-                    $pair<$($g_lt ,)* $($g ,)* $t2,
-                        $pair<$($g_lt ,)* $($g ,)* $t3,
-                            $pair<$($g_lt ,)* $($g ,)* $t4,
-                                $pair<$($g_lt ,)* $($g ,)* $t5,
-                                    $pair<$($g_lt ,)* $($g ,)* $t6,
-                                        $pair<$($g_lt ,)* $($g ,)* $t7, $empty<$($g_lt ,)* $($g ,)* $t8>>
+                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t1,
+                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t2,
+                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t3,
+                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t4,
+                                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t5,
+                                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t6,
+                                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t7, $empty<$($g_lt ,)* $($g ,)* $($c ,)* $t8>>
                                     >
                                 >
                             >
@@ -2342,14 +3733,14 @@ macro_rules! define_queue_of {
             };
 
             ($t1:ty, $t2:ty, $t3:ty, $t4:ty, $t5:ty, $t6:ty, $t7:ty, $t8:ty, $t9:ty) => {
-                $pair<$($g_lt ,)* $($g ,)* $t1,
-                    $pair<$($g_lt ,)* $($g ,)* $t2,
-                        $pair<$($g_lt ,)* $($g ,)* $t3,
-                            $pair<$($g_lt ,)* $($g ,)* $t4,
-                                $pair<$($g_lt ,)* $($g ,)* $t5,
-                                    $pair<$($g_lt ,)* $($g ,)* $t6,
-                                        $pair<$($g_lt ,)* $($g ,)* $t7,
-                                            $pair<$($g_lt ,)* $($g ,)* $t8, $empty<$($g_lt ,)* $($g ,)* $t9>>
+                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t1,
+                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t2,
+                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t3,
+                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t4,
+                                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t5,
+                                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t6,
+                                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t7,
+                                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t8, $empty<$($g_lt ,)* $($g ,)* $($c ,)* $t9>>
                                         >
                                     >
                                 >
@@ -2360,15 +3751,15 @@ macro_rules! define_queue_of {
             };
 
             ($t1:ty, $t2:ty, $t3:ty, $t4:ty, $t5:ty, $t6:ty, $t7:ty, $t8:ty, $t9:ty, $t10:ty) => {
-                $pair<$($g_lt ,)* $($g ,)* $t1,
-                    $pair<$($g_lt ,)* $($g ,)* $t2,
-                        $pair<$($g_lt ,)* $($g ,)* $t3,
-                            $pair<$($g_lt ,)* $($g ,)* $t4,
-                                $pair<$($g_lt ,)* $($g ,)* $t5,
-                                    $pair<$($g_lt ,)* $($g ,)* $t6,
-                                        $pair<$($g_lt ,)* $($g ,)* $t7,
-                                            $pair<$($g_lt ,)* $($g ,)* $t8,
-                                                $pair<$($g_lt ,)* $($g ,)* $t9, $empty<$($g_lt ,)* $($g ,)* $t10>>
+                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t1,
+                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t2,
+                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t3,
+                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t4,
+                                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t5,
+                                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t6,
+                                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t7,
+                                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t8,
+                                                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t9, $empty<$($g_lt ,)* $($g ,)* $($c ,)* $t10>>
                                             >
                                         >
                                     >
@@ -2380,16 +3771,16 @@ macro_rules! define_queue_of {
             };
 
             ($t1:ty, $t2:ty, $t3:ty, $t4:ty, $t5:ty, $t6:ty, $t7:ty, $t8:ty, $t9:ty, $t10:ty, $t11:ty) => {
-                $pair<$($g_lt ,)* $($g ,)* $t1,
-                    $pair<$($g_lt ,)* $($g ,)* $t2,
-                        $pair<$($g_lt ,)* $($g ,)* $t3,
-                            $pair<$($g_lt ,)* $($g ,)* $t4,
-                                $pair<$($g_lt ,)* $($g ,)* $t5,
-                                    $pair<$($g_lt ,)* $($g ,)* $t6,
-                                        $pair<$($g_lt ,)* $($g ,)* $t7,
-                                            $pair<$($g_lt ,)* $($g ,)* $t8,
-                                                $pair<$($g_lt ,)* $($g ,)* $t9,
-                                                    $pair<$($g_lt ,)* $($g ,)* $t10, $empty<$($g_lt ,)* $($g ,)* $t11>>
+                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t1,
+                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t2,
+                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t3,
+                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t4,
+                                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t5,
+                                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t6,
+                                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t7,
+                                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t8,
+                                                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t9,
+                                                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t10, $empty<$($g_lt ,)* $($g ,)* $($c ,)* $t11>>
                                                 >
                                             >
                                         >
@@ -2402,17 +3793,17 @@ macro_rules! define_queue_of {
             };
 
             ($t1:ty, $t2:ty, $t3:ty, $t4:ty, $t5:ty, $t6:ty, $t7:ty, $t8:ty, $t9:ty, $t10:ty, $t11:ty, $t12:ty) => {
-                $pair<$($g_lt ,)* $($g ,)* $t1,
-                    $pair<$($g_lt ,)* $($g ,)* $t2,
-                        $pair<$($g_lt ,)* $($g ,)* $t3,
-                            $pair<$($g_lt ,)* $($g ,)* $t4,
-                                $pair<$($g_lt ,)* $($g ,)* $t5,
-                                    $pair<$($g_lt ,)* $($g ,)* $t6,
-                                        $pair<$($g_lt ,)* $($g ,)* $t7,
-                                            $pair<$($g_lt ,)* $($g ,)* $t8,
-                                                $pair<$($g_lt ,)* $($g ,)* $t9,
-                                                    $pair<$($g_lt ,)* $($g ,)* $t10,
-                                                        $pair<$($g_lt ,)* $($g ,)* $t11, $empty<$($g_lt ,)* $($g ,)* $t12>>
+                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t1,
+                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t2,
+                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t3,
+                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t4,
+                                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t5,
+                                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t6,
+                                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t7,
+                                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t8,
+                                                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t9,
+                                                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t10,
+                                                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t11, $empty<$($g_lt ,)* $($g ,)* $($c ,)* $t12>>
                                                     >
                                                 >
                                             >
@@ -2426,18 +3817,18 @@ macro_rules! define_queue_of {
             };
 
             ($t1:ty, $t2:ty, $t3:ty, $t4:ty, $t5:ty, $t6:ty, $t7:ty, $t8:ty, $t9:ty, $t10:ty, $t11:ty, $t12:ty, $t13:ty) => {
-                $pair<$($g_lt ,)* $($g ,)* $t1,
-                    $pair<$($g_lt ,)* $($g ,)* $t2,
-                        $pair<$($g_lt ,)* $($g ,)* $t3,
-                            $pair<$($g_lt ,)* $($g ,)* $t4,
-                                $pair<$($g_lt ,)* $($g ,)* $t5,
-                                    $pair<$($g_lt ,)* $($g ,)* $t6,
-                                        $pair<$($g_lt ,)* $($g ,)* $t7,
-                                            $pair<$($g_lt ,)* $($g ,)* $t8,
-                                                $pair<$($g_lt ,)* $($g ,)* $t9,
-                                                    $pair<$($g_lt ,)* $($g ,)* $t10,
-                                                        $pair<$($g_lt ,)* $($g ,)* $t11,
-                                                            $pair<$($g_lt ,)* $($g ,)* $t12, $empty<$($g_lt ,)* $($g ,)* $t13>>
+                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t1,
+                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t2,
+                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t3,
+                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t4,
+                                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t5,
+                                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t6,
+                                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t7,
+                                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t8,
+                                                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t9,
+                                                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t10,
+                                                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t11,
+                                                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t12, $empty<$($g_lt ,)* $($g ,)* $($c ,)* $t13>>
                                                         >
                                                     >
                                                 >
@@ -2452,19 +3843,19 @@ macro_rules! define_queue_of {
             };
 
             ($t1:ty, $t2:ty, $t3:ty, $t4:ty, $t5:ty, $t6:ty, $t7:ty, $t8:ty, $t9:ty, $t10:ty, $t11:ty, $t12:ty, $t13:ty, $t14:ty) => {
-                $pair<$($g_lt ,)* $($g ,)* $t1,
-                    $pair<$($g_lt ,)* $($g ,)* $t2,
-                        $pair<$($g_lt ,)* $($g ,)* $t3,
-                            $pair<$($g_lt ,)* $($g ,)* $t4,
-                                $pair<$($g_lt ,)* $($g ,)* $t5,
-                                    $pair<$($g_lt ,)* $($g ,)* $t6,
-                                        $pair<$($g_lt ,)* $($g ,)* $t7,
-                                            $pair<$($g_lt ,)* $($g ,)* $t8,
-                                                $pair<$($g_lt ,)* $($g ,)* $t9,
-                                                    $pair<$($g_lt ,)* $($g ,)* $t10,
-                                                        $pair<$($g_lt ,)* $($g ,)* $t11,
-                                                            $pair<$($g_lt ,)* $($g ,)* $t12,
-                                                                $pair<$($g_lt ,)* $($g ,)* $t13, $empty<$($g_lt ,)* $($g ,)* $t14>>
+                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t1,
+                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t2,
+                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t3,
+                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t4,
+                                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t5,
+                                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t6,
+                                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t7,
+                                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t8,
+                                                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t9,
+                                                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t10,
+                                                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t11,
+                                                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t12,
+                                                                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t13, $empty<$($g_lt ,)* $($g ,)* $($c ,)* $t14>>
                                                             >
                                                         >
                                                     >
@@ -2480,20 +3871,20 @@ macro_rules! define_queue_of {
             };
 
             ($t1:ty, $t2:ty, $t3:ty, $t4:ty, $t5:ty, $t6:ty, $t7:ty, $t8:ty, $t9:ty, $t10:ty, $t11:ty, $t12:ty, $t13:ty, $t14:ty, $t15:ty) => {
-                $pair<$($g_lt ,)* $($g ,)* $t1,
-                    $pair<$($g_lt ,)* $($g ,)* $t2,
-                        $pair<$($g_lt ,)* $($g ,)* $t3,
-                            $pair<$($g_lt ,)* $($g ,)* $t4,
-                                $pair<$($g_lt ,)* $($g ,)* $t5,
-                                    $pair<$($g_lt ,)* $($g ,)* $t6,
-                                        $pair<$($g_lt ,)* $($g ,)* $t7,
-                                            $pair<$($g_lt ,)* $($g ,)* $t8,
-                                                $pair<$($g_lt ,)* $($g ,)* $t9,
-                                                    $pair<$($g_lt ,)* $($g ,)* $t10,
-                                                        $pair<$($g_lt ,)* $($g ,)* $t11,
-                                                            $pair<$($g_lt ,)* $($g ,)* $t12,
-                                                                $pair<$($g_lt ,)* $($g ,)* $t13,
-                                                                    $pair<$($g_lt ,)* $($g ,)* $t14, $empty<$($g_lt ,)* $($g ,)* $t15>>
+                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t1,
+                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t2,
+                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t3,
+                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t4,
+                                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t5,
+                                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t6,
+                                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t7,
+                                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t8,
+                                                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t9,
+                                                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t10,
+                                                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t11,
+                                                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t12,
+                                                                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t13,
+                                                                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t14, $empty<$($g_lt ,)* $($g ,)* $($c ,)* $t15>>
                                                                 >
                                                             >
                                                         >
@@ -2510,21 +3901,21 @@ macro_rules! define_queue_of {
             };
 
             ($t1:ty, $t2:ty, $t3:ty, $t4:ty, $t5:ty, $t6:ty, $t7:ty, $t8:ty, $t9:ty, $t10:ty, $t11:ty, $t12:ty, $t13:ty, $t14:ty, $t15:ty, $t16:ty) => {
-                $pair<$($g_lt ,)* $($g ,)* $t1,
-                    $pair<$($g_lt ,)* $($g ,)* $t2,
-                        $pair<$($g_lt ,)* $($g ,)* $t3,
-                            $pair<$($g_lt ,)* $($g ,)* $t4,
-                                $pair<$($g_lt ,)* $($g ,)* $t5,
-                                    $pair<$($g_lt ,)* $($g ,)* $t6,
-                                        $pair<$($g_lt ,)* $($g ,)* $t7,
-                                            $pair<$($g_lt ,)* $($g ,)* $t8,
-                                                $pair<$($g_lt ,)* $($g ,)* $t9,
-                                                    $pair<$($g_lt ,)* $($g ,)* $t10,
-                                                        $pair<$($g_lt ,)* $($g ,)* $t11,
-                                                            $pair<$($g_lt ,)* $($g ,)* $t12,
-                                                                $pair<$($g_lt ,)* $($g ,)* $t13,
-                                                                    $pair<$($g_lt ,)* $($g ,)* $t14,
-                                                                        $pair<$($g_lt ,)* $($g ,)* $t15, $empty<$($g_lt ,)* $($g ,)* $t16>>
+                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t1,
+                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t2,
+                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t3,
+                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t4,
+                                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t5,
+                                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t6,
+                                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t7,
+                                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t8,
+                                                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t9,
+                                                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t10,
+                                                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t11,
+                                                            $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t12,
+                                                                $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t13,
+                                                                    $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t14,
+                                                                        $pair<$($g_lt ,)* $($g ,)* $($c ,)* $t15, $empty<$($g_lt ,)* $($g ,)* $($c ,)* $t16>>
                                                                     >
                                                                 >
                                                             >
@@ -2543,3 +3934,197 @@ macro_rules! define_queue_of {
         }
     };
 }
+
+// # 5. format
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! define_queue_format {
+    (format => []; $($args:tt)*) => {};
+
+    (format => [$fmt:ident $($more:ident)*]; $($args:tt)*) => {
+        $crate::define_queue_format!(@$fmt $($args)*);
+        $crate::define_queue_format!(format => [$($more)*]; $($args)*);
+    };
+
+    (
+        @DebugElements
+        lt => [$($g_lt:tt), *];
+        generics => [ $( $g:tt $( : $( $g_bnd:ident $( < $( $g_bnd_g:tt ),* > )? )| * )? ), * ];
+        consts => [ $( const $c:ident : $c_ty:ty ),* ];
+        where => [ $($wh:tt)* ];
+        elements => [ $($el_bnd:tt)* ];
+        queue => [$q:ident ; $empty:ident, $pair:ident];
+    ) => {
+        impl<$($g_lt ,)* F, $($g ,)* $(const $c: $c_ty ,)*> $crate::queue::DebugElements for $empty<$($g_lt ,)* $($g ,)* $($c ,)* F>
+        where
+            F: core::fmt::Debug,
+            F: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            #[inline(always)]
+            fn debug_elements(&self, list: &mut core::fmt::DebugList<'_, '_>) {
+                list.entry(&self.f);
+            }
+        }
+
+        impl<$($g_lt ,)* F, $($g ,)* $(const $c: $c_ty ,)*> core::fmt::Debug for $empty<$($g_lt ,)* $($g ,)* $($c ,)* F>
+        where
+            F: core::fmt::Debug,
+            F: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut list = f.debug_list();
+                $crate::queue::DebugElements::debug_elements(self, &mut list);
+                list.finish()
+            }
+        }
+
+        impl<$($g_lt ,)* F, B, $($g ,)* $(const $c: $c_ty ,)*> $crate::queue::DebugElements for $pair<$($g_lt ,)* $($g ,)* $($c ,)* F, B>
+        where
+            F: core::fmt::Debug,
+            F: $($el_bnd)*,
+            B: $q<$($g_lt ,)* $($g ,)* $($c ,)*> + $crate::queue::DebugElements,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            #[inline(always)]
+            fn debug_elements(&self, list: &mut core::fmt::DebugList<'_, '_>) {
+                list.entry(&self.f);
+                $crate::queue::DebugElements::debug_elements(&self.b, list);
+            }
+        }
+    };
+
+    (
+        @Debug
+        lt => [$($g_lt:tt), *];
+        generics => [ $( $g:tt $( : $( $g_bnd:ident $( < $( $g_bnd_g:tt ),* > )? )| * )? ), * ];
+        consts => [ $( const $c:ident : $c_ty:ty ),* ];
+        where => [ $($wh:tt)* ];
+        elements => [ $($el_bnd:tt)* ];
+        queue => [$q:ident ; $empty:ident, $pair:ident];
+    ) => {
+        $crate::define_queue_format!(
+            @DebugElements
+            lt => [$($g_lt), *];
+            generics => [ $( $g $( : $( $g_bnd $( < $( $g_bnd_g ),* > )? )| * )? ), * ];
+            consts => [ $( const $c: $c_ty ),* ];
+            where => [ $($wh)* ];
+            elements => [ $($el_bnd)* ];
+            queue => [$q ; $empty, $pair];
+        );
+
+        impl<$($g_lt ,)* F, B, $($g ,)* $(const $c: $c_ty ,)*> core::fmt::Debug for $pair<$($g_lt ,)* $($g ,)* $($c ,)* F, B>
+        where
+            F: core::fmt::Debug,
+            F: $($el_bnd)*,
+            B: $q<$($g_lt ,)* $($g ,)* $($c ,)*> + $crate::queue::DebugElements,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let mut list = f.debug_list();
+                $crate::queue::DebugElements::debug_elements(self, &mut list);
+                list.finish()
+            }
+        }
+    };
+
+    // by default, the back queue is only known to be `Debug`, which is implied by the queue trait whenever
+    // the element bounds require `Debug`; hence, its flat debug is written without brackets as the remaining entries
+
+    (
+        @DefaultDebug
+        lt => [$($g_lt:tt), *];
+        generics => [ $( $g:tt $( : $( $g_bnd:ident $( < $( $g_bnd_g:tt ),* > )? )| * )? ), * ];
+        consts => [ $( const $c:ident : $c_ty:ty ),* ];
+        where => [ $($wh:tt)* ];
+        elements => [ $($el_bnd:tt)* ];
+        queue => [$q:ident ; $empty:ident, $pair:ident];
+    ) => {
+        $crate::define_queue_format!(
+            @DebugElements
+            lt => [$($g_lt), *];
+            generics => [ $( $g $( : $( $g_bnd $( < $( $g_bnd_g ),* > )? )| * )? ), * ];
+            consts => [ $( const $c: $c_ty ),* ];
+            where => [ $($wh)* ];
+            elements => [ $($el_bnd)* ];
+            queue => [$q ; $empty, $pair];
+        );
+
+        impl<$($g_lt ,)* F, B, $($g ,)* $(const $c: $c_ty ,)*> core::fmt::Debug for $pair<$($g_lt ,)* $($g ,)* $($c ,)* F, B>
+        where
+            F: core::fmt::Debug,
+            F: $($el_bnd)*,
+            B: $q<$($g_lt ,)* $($g ,)* $($c ,)*> + core::fmt::Debug,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_list()
+                    .entry(&self.f)
+                    .entry(&$crate::queue::DebugUnbracketed(&self.b))
+                    .finish()
+            }
+        }
+    };
+
+    (
+        @Display
+        lt => [$($g_lt:tt), *];
+        generics => [ $( $g:tt $( : $( $g_bnd:ident $( < $( $g_bnd_g:tt ),* > )? )| * )? ), * ];
+        consts => [ $( const $c:ident : $c_ty:ty ),* ];
+        where => [ $($wh:tt)* ];
+        elements => [ $($el_bnd:tt)* ];
+        queue => [$q:ident ; $empty:ident, $pair:ident];
+    ) => {
+        impl<$($g_lt ,)* F, $($g ,)* $(const $c: $c_ty ,)*> $crate::queue::DisplayElements for $empty<$($g_lt ,)* $($g ,)* $($c ,)* F>
+        where
+            F: core::fmt::Display,
+            F: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            #[inline(always)]
+            fn display_elements(&self, f: &mut core::fmt::Formatter<'_>, _: &str) -> core::fmt::Result {
+                core::fmt::Display::fmt(&self.f, f)
+            }
+        }
+
+        impl<$($g_lt ,)* F, $($g ,)* $(const $c: $c_ty ,)*> core::fmt::Display for $empty<$($g_lt ,)* $($g ,)* $($c ,)* F>
+        where
+            F: core::fmt::Display,
+            F: $($el_bnd)*,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                $crate::queue::DisplayElements::display_elements(self, f, ", ")
+            }
+        }
+
+        impl<$($g_lt ,)* F, B, $($g ,)* $(const $c: $c_ty ,)*> $crate::queue::DisplayElements for $pair<$($g_lt ,)* $($g ,)* $($c ,)* F, B>
+        where
+            F: core::fmt::Display,
+            F: $($el_bnd)*,
+            B: $q<$($g_lt ,)* $($g ,)* $($c ,)*> + $crate::queue::DisplayElements,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            #[inline(always)]
+            fn display_elements(&self, f: &mut core::fmt::Formatter<'_>, separator: &str) -> core::fmt::Result {
+                core::fmt::Display::fmt(&self.f, f)?;
+                f.write_str(separator)?;
+                $crate::queue::DisplayElements::display_elements(&self.b, f, separator)
+            }
+        }
+
+        impl<$($g_lt ,)* F, B, $($g ,)* $(const $c: $c_ty ,)*> core::fmt::Display for $pair<$($g_lt ,)* $($g ,)* $($c ,)* F, B>
+        where
+            F: core::fmt::Display,
+            F: $($el_bnd)*,
+            B: $q<$($g_lt ,)* $($g ,)* $($c ,)*> + $crate::queue::DisplayElements,
+            $( $g: $( $( $g_bnd $( < $( $g_bnd_g ),* > )? + )* )? , )* $($wh)*
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                $crate::queue::DisplayElements::display_elements(self, f, ", ")
+            }
+        }
+    };
+}
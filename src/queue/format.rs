@@ -0,0 +1,348 @@
+use crate::queue::{Queue, QueueSingle, ReprCQueue, ReprCQueueSingle, StQueue};
+use core::fmt::{Debug, DebugList, Display, Formatter, Write};
+
+/// Separator of the elements used by the `Display` implementations of the queues.
+const DEFAULT_SEPARATOR: &str = ", ";
+
+/// A queue whose elements all implement `Debug`.
+///
+/// It is used to implement the flat list-style `Debug` of the queues, such as `[42, true, 'x']`,
+/// rather than the nested representation of the recursive queue types. Pretty printing with `{:#?}`
+/// prints each element on its own line.
+///
+/// It is implemented by [`QueueSingle`], [`Queue`], [`ReprCQueueSingle`], [`ReprCQueue`] and the queues
+/// created by the [`define_queue`] macro unless its `format` option omits `Debug`.
+///
+/// [`define_queue`]: crate::define_queue
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::queue::*;
+///
+/// let queue = Queue::new(42).push(true).push('x');
+/// assert_eq!(format!("{queue:?}"), "[42, true, 'x']");
+/// assert_eq!(format!("{queue:#?}"), "[\n    42,\n    true,\n    'x',\n]");
+///
+/// let queue = QueueSingle::new("foo");
+/// assert_eq!(format!("{queue:?}"), r#"["foo"]"#);
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not implement the flat `Debug` of its elements",
+    note = "all elements of the queue must implement `Debug`; a `define_queue!` queue trait requires it by the `format => [Debug];` option"
+)]
+pub trait DebugElements {
+    /// Adds all elements of the queue, from front to back, as entries of the debug `list`.
+    fn debug_elements(&self, list: &mut DebugList<'_, '_>);
+}
+
+/// A queue whose elements all implement `Display`.
+///
+/// Queues implementing this trait implement `Display` which writes the elements from front to back
+/// separated by `", "`; and the separator can be configured by [`display_with`].
+///
+/// It is implemented by [`QueueSingle`], [`Queue`], [`ReprCQueueSingle`], [`ReprCQueue`] and the queues
+/// created by the [`define_queue`] macro with the `format => [Display];` option.
+///
+/// [`define_queue`]: crate::define_queue
+/// [`display_with`]: DisplayElements::display_with
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::queue::*;
+///
+/// let queue = Queue::new(42).push(true).push('x');
+/// assert_eq!(queue.to_string(), "42, true, x");
+/// assert_eq!(queue.display_with(" | ").to_string(), "42 | true | x");
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not implement the flat `Display` of its elements",
+    note = "when the elements of a `define_queue!` queue are required to be `Display`, add the `format => [Display];` option"
+)]
+pub trait DisplayElements {
+    /// Writes all elements of the queue, from front to back, separated by the `separator`.
+    fn display_elements(&self, f: &mut Formatter<'_>, separator: &str) -> core::fmt::Result;
+
+    /// Returns a value implementing `Display` which writes all elements of the queue separated by the `separator`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    ///
+    /// let queue = Queue::new(1).push(2.5).push("three");
+    /// assert_eq!(format!("{}", queue.display_with(" -> ")), "1 -> 2.5 -> three");
+    /// assert_eq!(format!("{}", queue.display_with("")), "12.5three");
+    /// ```
+    fn display_with<'a>(&'a self, separator: &'a str) -> DisplayWith<'a, Self> {
+        DisplayWith {
+            queue: self,
+            separator,
+        }
+    }
+}
+
+/// Displays elements of a queue separated by a custom separator.
+///
+/// It is created by [`DisplayElements::display_with`].
+#[derive(Clone, Copy)]
+pub struct DisplayWith<'a, Q: ?Sized> {
+    queue: &'a Q,
+    separator: &'a str,
+}
+
+impl<Q: DisplayElements + ?Sized> Display for DisplayWith<'_, Q> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.queue.display_elements(f, self.separator)
+    }
+}
+
+/// Writes the flat `Debug` of a queue without its enclosing brackets, so that its elements are
+/// written as entries of the list of an enclosing queue.
+///
+/// It is used by the `Debug` implementations of the [`define_queue`] queues when the `format` option
+/// is omitted, where the back queue is known to implement `Debug` but not [`DebugElements`].
+///
+/// [`define_queue`]: crate::define_queue
+#[doc(hidden)]
+pub struct DebugUnbracketed<'a, Q: ?Sized>(pub &'a Q);
+
+impl<Q: Debug + ?Sized> Debug for DebugUnbracketed<'_, Q> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let pretty = f.alternate();
+        let mut writer = Unbracket::new(f, pretty);
+        match pretty {
+            true => write!(writer, "{:#?}", self.0),
+            false => write!(writer, "{:?}", self.0),
+        }
+    }
+}
+
+/// Forwards a flat list, such as `[1, 2]`, without its opening and closing brackets; and in pretty
+/// mode, additionally without the one level of indentation of its entries.
+struct Unbracket<'a, 'b> {
+    f: &'a mut Formatter<'b>,
+    prefix: usize,
+    indent: usize,
+    skipped_indent: Option<usize>,
+    held: [char; 3],
+    num_held: usize,
+    suffix: usize,
+}
+
+impl<'a, 'b> Unbracket<'a, 'b> {
+    fn new(f: &'a mut Formatter<'b>, pretty: bool) -> Self {
+        match pretty {
+            // "[\n" + entries indented by four spaces, each followed by ",\n" + "]"
+            true => Self {
+                f,
+                prefix: 2,
+                indent: 4,
+                skipped_indent: Some(0),
+                held: ['\0'; 3],
+                num_held: 0,
+                suffix: 3,
+            },
+            // "[" + entries separated by ", " + "]"
+            false => Self {
+                f,
+                prefix: 1,
+                indent: 0,
+                skipped_indent: None,
+                held: ['\0'; 3],
+                num_held: 0,
+                suffix: 1,
+            },
+        }
+    }
+}
+
+impl Write for Unbracket<'_, '_> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        for c in s.chars() {
+            if self.prefix > 0 {
+                self.prefix -= 1;
+                continue;
+            }
+
+            match (self.skipped_indent, c) {
+                (Some(n), ' ') if n < self.indent => {
+                    self.skipped_indent = Some(n + 1);
+                    continue;
+                }
+                (Some(_), _) => self.skipped_indent = None,
+                _ => {}
+            }
+            if c == '\n' && self.indent > 0 {
+                self.skipped_indent = Some(0);
+            }
+
+            // the last characters are held back since they might be the closing suffix
+            if self.num_held == self.suffix {
+                self.f.write_char(self.held[0])?;
+                self.held.copy_within(1..self.suffix, 0);
+                self.num_held -= 1;
+            }
+            self.held[self.num_held] = c;
+            self.num_held += 1;
+        }
+        Ok(())
+    }
+}
+
+// single
+
+impl<F: Debug> DebugElements for QueueSingle<F> {
+    #[inline(always)]
+    fn debug_elements(&self, list: &mut DebugList<'_, '_>) {
+        list.entry(&self.front);
+    }
+}
+
+impl<F: Display> DisplayElements for QueueSingle<F> {
+    #[inline(always)]
+    fn display_elements(&self, f: &mut Formatter<'_>, _: &str) -> core::fmt::Result {
+        self.front.fmt(f)
+    }
+}
+
+impl<F: Debug> Debug for QueueSingle<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let mut list = f.debug_list();
+        self.debug_elements(&mut list);
+        list.finish()
+    }
+}
+
+impl<F: Display> Display for QueueSingle<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.display_elements(f, DEFAULT_SEPARATOR)
+    }
+}
+
+// multi
+
+impl<F, B> DebugElements for Queue<F, B>
+where
+    F: Debug,
+    B: StQueue + DebugElements,
+{
+    #[inline(always)]
+    fn debug_elements(&self, list: &mut DebugList<'_, '_>) {
+        list.entry(self.front());
+        self.back().debug_elements(list);
+    }
+}
+
+impl<F, B> DisplayElements for Queue<F, B>
+where
+    F: Display,
+    B: StQueue + DisplayElements,
+{
+    #[inline(always)]
+    fn display_elements(&self, f: &mut Formatter<'_>, separator: &str) -> core::fmt::Result {
+        self.front().fmt(f)?;
+        f.write_str(separator)?;
+        self.back().display_elements(f, separator)
+    }
+}
+
+impl<F, B> Debug for Queue<F, B>
+where
+    F: Debug,
+    B: StQueue + DebugElements,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let mut list = f.debug_list();
+        self.debug_elements(&mut list);
+        list.finish()
+    }
+}
+
+impl<F, B> Display for Queue<F, B>
+where
+    F: Display,
+    B: StQueue + DisplayElements,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.display_elements(f, DEFAULT_SEPARATOR)
+    }
+}
+
+// repr-c
+
+impl<F: Debug> DebugElements for ReprCQueueSingle<F> {
+    #[inline(always)]
+    fn debug_elements(&self, list: &mut DebugList<'_, '_>) {
+        list.entry(&self.front);
+    }
+}
+
+impl<F: Display> DisplayElements for ReprCQueueSingle<F> {
+    #[inline(always)]
+    fn display_elements(&self, f: &mut Formatter<'_>, _: &str) -> core::fmt::Result {
+        self.front.fmt(f)
+    }
+}
+
+impl<F: Debug> Debug for ReprCQueueSingle<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let mut list = f.debug_list();
+        self.debug_elements(&mut list);
+        list.finish()
+    }
+}
+
+impl<F: Display> Display for ReprCQueueSingle<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.display_elements(f, DEFAULT_SEPARATOR)
+    }
+}
+
+impl<F, B> DebugElements for ReprCQueue<F, B>
+where
+    F: Debug,
+    B: StQueue + DebugElements,
+{
+    #[inline(always)]
+    fn debug_elements(&self, list: &mut DebugList<'_, '_>) {
+        list.entry(&self.front);
+        self.back.debug_elements(list);
+    }
+}
+
+impl<F, B> DisplayElements for ReprCQueue<F, B>
+where
+    F: Display,
+    B: StQueue + DisplayElements,
+{
+    #[inline(always)]
+    fn display_elements(&self, f: &mut Formatter<'_>, separator: &str) -> core::fmt::Result {
+        self.front.fmt(f)?;
+        f.write_str(separator)?;
+        self.back.display_elements(f, separator)
+    }
+}
+
+impl<F, B> Debug for ReprCQueue<F, B>
+where
+    F: Debug,
+    B: StQueue + DebugElements,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let mut list = f.debug_list();
+        self.debug_elements(&mut list);
+        list.finish()
+    }
+}
+
+impl<F, B> Display for ReprCQueue<F, B>
+where
+    F: Display,
+    B: StQueue + DisplayElements,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.display_elements(f, DEFAULT_SEPARATOR)
+    }
+}
@@ -0,0 +1,336 @@
+use crate::queue::{Queue, QueueSingle, StQueue};
+use core::iter::{Chain, Once, once};
+
+/// A marker trait for statically-typed queues whose elements all have the same type `T`,
+/// such as `queue_of!(f64, f64, f64)`.
+///
+/// Homogeneous queues can be used as a regular collection of elements: they can be iterated
+/// over, accessed by index, and converted from and into arrays (see [`IntoArray`]).
+///
+/// Notice that every [`QueueSingle<T>`] is homogeneous; and a [`Queue<T, B>`] is homogeneous
+/// whenever its back `B` is homogeneous with the same element type `T`.
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::queue::*;
+/// use orx_meta::queue_of;
+///
+/// let mut queue: queue_of!(f64, f64, f64) = Queue::new(1.0).push(2.0).push(3.0);
+///
+/// assert_eq!(queue.iter().sum::<f64>(), 6.0);
+///
+/// for x in queue.iter_mut() {
+///     *x *= 2.0;
+/// }
+/// assert_eq!(queue.into_array(), [2.0, 4.0, 6.0]);
+///
+/// let queue = <queue_of!(f64, f64, f64)>::from_array([3.0, 2.0, 1.0]).unwrap();
+/// assert_eq!(queue.as_tuple(), (&3.0, &2.0, &1.0));
+///
+/// let doubled: Vec<_> = queue.into_iter().map(|x| x * 2.0).collect();
+/// assert_eq!(doubled, vec![6.0, 4.0, 2.0]);
+/// ```
+pub trait Homogeneous<T>: StQueue<Front = T> + IntoIterator<Item = T> + Sized {
+    /// Type of the iterator yielding references to elements of the queue.
+    type Iter<'a>: Iterator<Item = &'a T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    /// Type of the iterator yielding mutable references to elements of the queue.
+    type IterMut<'a>: Iterator<Item = &'a mut T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    /// Returns an iterator of references to elements of the queue from front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    ///
+    /// let queue = Queue::new(1).push(2).push(3);
+    ///
+    /// let mut iter = queue.iter();
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), Some(&3));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn iter(&self) -> Self::Iter<'_>;
+
+    /// Returns an iterator of mutable references to elements of the queue from front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    ///
+    /// let mut queue = Queue::new(1).push(2).push(3);
+    ///
+    /// for x in queue.iter_mut() {
+    ///     *x += 10;
+    /// }
+    /// assert_eq!(queue.as_tuple(), (&11, &12, &13));
+    /// ```
+    fn iter_mut(&mut self) -> Self::IterMut<'_>;
+
+    /// Returns a reference to the element at the given `index` from the front of the queue;
+    /// or None if the `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    ///
+    /// let queue = Queue::new(1).push(2).push(3);
+    ///
+    /// assert_eq!(queue.get(0), Some(&1));
+    /// assert_eq!(queue.get(2), Some(&3));
+    /// assert_eq!(queue.get(3), None);
+    /// ```
+    fn get(&self, index: usize) -> Option<&T> {
+        self.iter().nth(index)
+    }
+
+    /// Returns a mutable reference to the element at the given `index` from the front of the queue;
+    /// or None if the `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    ///
+    /// let mut queue = Queue::new(1).push(2).push(3);
+    ///
+    /// if let Some(x) = queue.get_mut(1) {
+    ///     *x = 20;
+    /// }
+    /// assert_eq!(queue.as_tuple(), (&1, &20, &3));
+    /// assert_eq!(queue.get_mut(3), None);
+    /// ```
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.iter_mut().nth(index)
+    }
+
+    /// Creates the queue from the first `Self::LEN` elements of the `iter`.
+    ///
+    /// Returns None if the iterator yields fewer than `Self::LEN` elements.
+    /// Remaining elements of the iterator, if any, are not consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    /// use orx_meta::queue_of;
+    ///
+    /// let mut iter = [1, 2, 3, 4].into_iter();
+    ///
+    /// let queue = <queue_of!(i32, i32, i32)>::try_from_iter(&mut iter);
+    /// assert_eq!(queue, Some(Queue::new(1).push(2).push(3)));
+    /// assert_eq!(iter.next(), Some(4));
+    ///
+    /// let queue = <queue_of!(i32, i32, i32)>::try_from_iter(&mut [1, 2].into_iter());
+    /// assert_eq!(queue, None);
+    /// ```
+    fn try_from_iter<I>(iter: &mut I) -> Option<Self>
+    where
+        I: Iterator<Item = T>;
+
+    /// Creates the queue from an array of its elements from front to back.
+    ///
+    /// Returns None if length of the array `N` is not equal to the length of the queue `Self::LEN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    /// use orx_meta::queue_of;
+    ///
+    /// let queue = <queue_of!(char, char, char)>::from_array(['a', 'b', 'c']);
+    /// assert_eq!(queue, Some(Queue::new('a').push('b').push('c')));
+    ///
+    /// let queue = <queue_of!(char, char, char)>::from_array(['a', 'b']);
+    /// assert_eq!(queue, None);
+    ///
+    /// let queue = <queue_of!(char, char, char)>::from_array(['a', 'b', 'c', 'd']);
+    /// assert_eq!(queue, None);
+    /// ```
+    fn from_array<const N: usize>(array: [T; N]) -> Option<Self> {
+        match N == Self::LEN {
+            true => Self::try_from_iter(&mut array.into_iter()),
+            false => None,
+        }
+    }
+}
+
+/// A homogeneous queue of `N` elements of type `T` which can be converted into the array `[T; N]`.
+///
+/// The trait is implemented for homogeneous queues with up to 32 elements.
+/// Since the length is a type parameter of the trait, converting the queue into an array of a
+/// different length is a type error, which is reported by `cargo check` as well.
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::queue::*;
+///
+/// let queue = Queue::new('a').push('b').push('c');
+/// assert_eq!(queue.into_array(), ['a', 'b', 'c']);
+/// ```
+///
+/// Lengths of the queue and array must match.
+///
+/// ```compile_fail
+/// use orx_meta::queue::*;
+///
+/// let queue = Queue::new('a').push('b').push('c');
+/// let array: [char; 2] = queue.into_array();
+/// ```
+pub trait IntoArray<T, const N: usize>: Homogeneous<T> {
+    /// Converts the queue into an array of its elements from front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    ///
+    /// let queue = Queue::new(1).push(2).push(3);
+    /// let array = queue.into_array();
+    /// assert_eq!(array, [1, 2, 3]);
+    /// ```
+    fn into_array(self) -> [T; N];
+}
+
+impl<T> IntoArray<T, 1> for QueueSingle<T> {
+    #[inline(always)]
+    fn into_array(self) -> [T; 1] {
+        [self.front]
+    }
+}
+
+macro_rules! impl_into_array {
+    ([$($x:ident)*] [] []) => {};
+    ([$($x:ident)*] [$n:literal $($ns:literal)*] [$next:ident $($xs:ident)*]) => {
+        impl<T, B> IntoArray<T, $n> for Queue<T, B>
+        where
+            B: IntoArray<T, { $n - 1 }>,
+        {
+            #[inline(always)]
+            fn into_array(self) -> [T; $n] {
+                let (front, back) = self.pop();
+                let [$($x),*] = back.into_array();
+                [front, $($x),*]
+            }
+        }
+
+        impl_into_array!([$($x)* $next] [$($ns)*] [$($xs)*]);
+    };
+}
+
+impl_into_array!(
+    [x1]
+    [2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32]
+    [x2 x3 x4 x5 x6 x7 x8 x9 x10 x11 x12 x13 x14 x15 x16 x17 x18 x19 x20 x21 x22 x23 x24 x25 x26 x27 x28 x29 x30 x31 x32]
+);
+
+// single
+
+impl<T> IntoIterator for QueueSingle<T> {
+    type Item = T;
+
+    type IntoIter = Once<T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        once(self.front)
+    }
+}
+
+impl<T> Homogeneous<T> for QueueSingle<T> {
+    type Iter<'a>
+        = Once<&'a T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    type IterMut<'a>
+        = Once<&'a mut T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    #[inline(always)]
+    fn iter(&self) -> Self::Iter<'_> {
+        once(&self.front)
+    }
+
+    #[inline(always)]
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        once(&mut self.front)
+    }
+
+    #[inline(always)]
+    fn try_from_iter<I>(iter: &mut I) -> Option<Self>
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.next().map(QueueSingle::new)
+    }
+}
+
+// multi
+
+impl<T, B> IntoIterator for Queue<T, B>
+where
+    B: Homogeneous<T>,
+{
+    type Item = T;
+
+    type IntoIter = Chain<Once<T>, B::IntoIter>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        let (front, back) = self.pop();
+        once(front).chain(back)
+    }
+}
+
+impl<T, B> Homogeneous<T> for Queue<T, B>
+where
+    B: Homogeneous<T>,
+{
+    type Iter<'a>
+        = Chain<Once<&'a T>, B::Iter<'a>>
+    where
+        Self: 'a,
+        T: 'a;
+
+    type IterMut<'a>
+        = Chain<Once<&'a mut T>, B::IterMut<'a>>
+    where
+        Self: 'a,
+        T: 'a;
+
+    #[inline(always)]
+    fn iter(&self) -> Self::Iter<'_> {
+        once(self.front()).chain(self.back().iter())
+    }
+
+    #[inline(always)]
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        let (front, back) = self.front_back_mut();
+        once(front).chain(back.iter_mut())
+    }
+
+    #[inline(always)]
+    fn try_from_iter<I>(iter: &mut I) -> Option<Self>
+    where
+        I: Iterator<Item = T>,
+    {
+        let front = iter.next()?;
+        B::try_from_iter(iter).map(|back| Queue::from_fb(front, back))
+    }
+}
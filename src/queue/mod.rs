@@ -1,14 +1,42 @@
 #[cfg(test)]
 mod tests;
 
+mod assert_layout_matches;
+mod base;
 mod builder;
+mod compose;
 mod define_queue;
+mod format;
+mod homogeneous;
 mod multi;
+mod parse;
+mod pipeline;
+mod queue_like;
 mod queue_of;
+mod refs;
+mod repr_c;
 mod single;
+mod slot_builder;
+#[doc(hidden)]
+pub mod slots;
 mod st_queue;
+mod transpose;
+mod zip;
 
-pub use builder::QueueBuilder;
+pub use base::{FromBase, IntoBase};
+pub use builder::{BuilderProgress, FillWithDefaults, QueueBuilder, QueueBuilding};
+#[doc(hidden)]
+pub use format::DebugUnbracketed;
+pub use format::{DebugElements, DisplayElements, DisplayWith};
+pub use homogeneous::{Homogeneous, IntoArray};
 pub use multi::Queue;
+pub use parse::{ParseQueue, ParseQueueError, SlotError};
+pub use pipeline::Pipeline;
+pub use queue_like::QueueLike;
+pub use refs::{AsMuts, AsRefs, Cloned, Copied};
+pub use repr_c::{ReprCQueue, ReprCQueueSingle};
 pub use single::QueueSingle;
+pub use slot_builder::QueueSlotBuilder;
 pub use st_queue::StQueue;
+pub use transpose::{TransposeOption, TransposeResult};
+pub use zip::{Unzip, Zip};
@@ -15,7 +15,7 @@ use crate::queue::{QueueSingle, StQueue};
 /// Note that `Queue::new(element)` gives a `QueueSingle` with one element. In order to create
 /// a queue of multiple elements, we need to push at least one more element, such as
 /// `Queue::new(elem1).push(elem2)`.
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct Queue<Front, Back>
 where
     Back: StQueue,
@@ -89,7 +89,7 @@ impl<F> Queue<F, QueueSingle<F>> {
     /// ```
     #[inline(always)]
     #[allow(clippy::new_ret_no_self)]
-    pub fn new(element: F) -> QueueSingle<F> {
+    pub const fn new(element: F) -> QueueSingle<F> {
         QueueSingle::new(element)
     }
 }
@@ -98,8 +98,26 @@ impl<F, B> Queue<F, B>
 where
     B: StQueue,
 {
+    /// Creates a queue with the given `front` element and the `back` queue of remaining elements.
+    ///
+    /// Unlike [`push`], this is a `const fn`; and hence, it can be used to create queues of
+    /// any length in const contexts such as `const` and `static` items.
+    ///
+    /// [`push`]: crate::queue::StQueue::push
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    /// use orx_meta::queue_of;
+    ///
+    /// const QUEUE: queue_of!(u32, bool, char) =
+    ///     Queue::from_fb(42, Queue::from_fb(true, QueueSingle::new('x')));
+    ///
+    /// assert_eq!(QUEUE, Queue::new(42).push(true).push('x'));
+    /// ```
     #[inline(always)]
-    pub(super) fn from_fb(front: F, back: B) -> Self {
+    pub const fn from_fb(front: F, back: B) -> Self {
         Self { f: front, b: back }
     }
 }
@@ -110,6 +128,26 @@ where
 {
     // ref
 
+    /// Returns a reference to the element in the front of the queue.
+    ///
+    /// This is the `const fn` counterpart of [`StQueue::front`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    /// use orx_meta::queue_of;
+    ///
+    /// const QUEUE: queue_of!(u32, bool) = Queue::from_fb(42, QueueSingle::new(true));
+    /// const FRONT: &u32 = QUEUE.front();
+    ///
+    /// assert_eq!(FRONT, &42);
+    /// ```
+    #[inline(always)]
+    pub const fn front(&self) -> &F {
+        &self.f
+    }
+
     /// Returns a reference to the queue including elements of this queue
     /// excluding the element in the front.
     ///
@@ -151,7 +189,7 @@ where
     /// assert_eq!(s, "foo");
     /// ```
     #[inline(always)]
-    pub fn back(&self) -> &B {
+    pub const fn back(&self) -> &B {
         &self.b
     }
 
@@ -177,7 +215,7 @@ where
     /// assert_eq!(queue.as_tuple(), (&84, &false, &'y'));
     /// ```
     #[inline(always)]
-    pub fn back_mut(&mut self) -> &mut B {
+    pub const fn back_mut(&mut self) -> &mut B {
         &mut self.b
     }
 
@@ -231,7 +269,7 @@ where
     /// assert_eq!(q.as_tuple(), (&6, &true, &'y'));
     /// ```
     #[inline(always)]
-    pub fn front_back_mut(&mut self) -> (&mut F, &mut B) {
+    pub const fn front_back_mut(&mut self) -> (&mut F, &mut B) {
         (&mut self.f, &mut self.b)
     }
 
@@ -241,6 +279,10 @@ where
     /// except for the element in the front.
     ///
     /// Equivalent to `queue.pop().1`.
+    ///
+    /// This method is not a `const fn`, see [`pop`] for the reason and the alternatives in const items.
+    ///
+    /// [`pop`]: Queue::pop
     #[inline(always)]
     pub fn into_back(self) -> B {
         self.b
@@ -253,6 +295,16 @@ where
     ///   for the front element. In other words, it is the queue obtained by
     ///   popping the front element.
     ///
+    /// This method is not a `const fn` since moving the fields out of a generic `self` is not allowed in
+    /// a `const fn` on stable Rust (E0493): the destructor of `self` cannot be evaluated at compile time.
+    /// In const items, queues can be created by [`new`] and [`from_fb`], and their elements can be
+    /// accessed by [`front`] and [`back`].
+    ///
+    /// [`new`]: Queue::new
+    /// [`from_fb`]: Queue::from_fb
+    /// [`front`]: Queue::front
+    /// [`back`]: Queue::back
+    ///
     /// # Examples
     ///
     /// ```
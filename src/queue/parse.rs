@@ -0,0 +1,226 @@
+use crate::queue::{Queue, QueueSingle, StQueue};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// Error of converting a value into an element of a queue being built, which points to the slot.
+///
+/// It is returned by the `try_push` methods of the [`QueueBuilder`].
+///
+/// [`QueueBuilder`]: crate::queue::QueueBuilder
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::queue::*;
+/// use orx_meta::queue_of;
+///
+/// type MyQueue = queue_of!(u32, u8);
+///
+/// let result = QueueBuilder::<MyQueue>::new()
+///     .push(42)
+///     .try_push(1000u32); // does not fit into u8
+///
+/// let error = result.err().unwrap();
+/// assert_eq!(error.position, 1);
+/// assert_eq!(error.type_name, "u8");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SlotError<E> {
+    /// Position of the slot, or index of the element, in the queue.
+    pub position: usize,
+    /// Name of the expected type of the element, which is obtained by `core::any::type_name`.
+    pub type_name: &'static str,
+    /// The conversion error.
+    pub error: E,
+}
+
+impl<E> SlotError<E> {
+    pub(super) fn new<T>(position: usize, error: E) -> Self {
+        Self {
+            position,
+            type_name: core::any::type_name::<T>(),
+            error,
+        }
+    }
+}
+
+impl<E: Display> Display for SlotError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "failed to convert the element at position {} into {}: {}",
+            self.position, self.type_name, self.error
+        )
+    }
+}
+
+impl<E> core::error::Error for SlotError<E>
+where
+    E: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Error of parsing a queue from a sequence of string values.
+///
+/// It is returned by [`QueueBuilder::try_from_iter`].
+///
+/// The parse error of an invalid value, `FromStr::Err` of the element type, is converted into `E`,
+/// which is therefore required to be convertible from the parse errors of all elements of the queue.
+///
+/// [`QueueBuilder::try_from_iter`]: crate::queue::QueueBuilder::try_from_iter
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ParseQueueError<'a, E> {
+    /// There exist fewer values than the number of elements of the queue.
+    Missing {
+        /// Position of the first element without a value.
+        position: usize,
+        /// Name of the type of the element without a value.
+        type_name: &'static str,
+    },
+    /// The value cannot be parsed into the element type.
+    Invalid {
+        /// Position of the element.
+        position: usize,
+        /// Name of the type of the element.
+        type_name: &'static str,
+        /// The value which could not be parsed.
+        value: &'a str,
+        /// The parse error of the element type.
+        error: E,
+    },
+    /// There exist more values than the number of elements of the queue.
+    Unexpected {
+        /// Position of the first unexpected value, which is equal to the length of the queue.
+        position: usize,
+        /// The first unexpected value.
+        value: &'a str,
+    },
+}
+
+impl<E> ParseQueueError<'_, E> {
+    /// Position of the slot, or index of the element, the error points to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    /// use orx_meta::queue_of;
+    ///
+    /// let result = QueueBuilder::<queue_of!(u32, u64)>::try_from_iter(["42", "x"]);
+    /// let error: ParseQueueError<core::num::ParseIntError> = result.err().unwrap();
+    /// assert_eq!(error.position(), 1);
+    /// ```
+    pub fn position(&self) -> usize {
+        match self {
+            Self::Missing { position, .. } => *position,
+            Self::Invalid { position, .. } => *position,
+            Self::Unexpected { position, .. } => *position,
+        }
+    }
+}
+
+impl<E: Display> Display for ParseQueueError<'_, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Missing {
+                position,
+                type_name,
+            } => write!(
+                f,
+                "missing value for the element at position {position} of type {type_name}"
+            ),
+            Self::Invalid {
+                position,
+                type_name,
+                value,
+                error,
+            } => write!(
+                f,
+                "failed to parse '{value}' into the element at position {position} of type {type_name}: {error}"
+            ),
+            Self::Unexpected { position, value } => write!(
+                f,
+                "unexpected value '{value}' at position {position} beyond the length of the queue"
+            ),
+        }
+    }
+}
+
+impl<E> core::error::Error for ParseQueueError<'_, E>
+where
+    E: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Invalid { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// A queue all elements of which can be parsed from string values by `FromStr`, and the parse errors of
+/// which can be converted into `E`.
+///
+/// This is the building block of [`QueueBuilder::try_from_iter`].
+///
+/// [`QueueBuilder::try_from_iter`]: crate::queue::QueueBuilder::try_from_iter
+pub trait ParseQueue<E>: StQueue + Sized {
+    /// Parses the elements of the queue from the next `Self::LEN` values of the `iter`, where the first
+    /// element is at the given `position` of the complete queue.
+    fn parse_from<'a, I>(iter: &mut I, position: usize) -> Result<Self, ParseQueueError<'a, E>>
+    where
+        I: Iterator<Item = &'a str>;
+}
+
+fn parse_element<'a, T, E, I>(iter: &mut I, position: usize) -> Result<T, ParseQueueError<'a, E>>
+where
+    T: FromStr,
+    T::Err: Into<E>,
+    I: Iterator<Item = &'a str>,
+{
+    let type_name = core::any::type_name::<T>();
+    match iter.next() {
+        Some(value) => value.parse().map_err(|e: T::Err| ParseQueueError::Invalid {
+            position,
+            type_name,
+            value,
+            error: e.into(),
+        }),
+        None => Err(ParseQueueError::Missing {
+            position,
+            type_name,
+        }),
+    }
+}
+
+impl<T, E> ParseQueue<E> for QueueSingle<T>
+where
+    T: FromStr,
+    T::Err: Into<E>,
+{
+    fn parse_from<'a, I>(iter: &mut I, position: usize) -> Result<Self, ParseQueueError<'a, E>>
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        parse_element(iter, position).map(QueueSingle::new)
+    }
+}
+
+impl<F, B, E> ParseQueue<E> for Queue<F, B>
+where
+    F: FromStr,
+    F::Err: Into<E>,
+    B: ParseQueue<E>,
+{
+    fn parse_from<'a, I>(iter: &mut I, position: usize) -> Result<Self, ParseQueueError<'a, E>>
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        let front = parse_element(iter, position)?;
+        let back = B::parse_from(iter, position + 1)?;
+        Ok(Queue::from_fb(front, back))
+    }
+}
@@ -0,0 +1,99 @@
+use crate::queue::{Queue, QueueSingle, StQueue};
+
+/// A statically-typed queue of functions which are applied one after the other.
+///
+/// A queue of functions `f1: A -> B`, `f2: B -> C`, ..., `fn: Y -> Z` is a `Pipeline<A>` with output type `Z`
+/// provided that the output type of each function matches the input type of the next one. This is validated at
+/// compile time; hence, mismatching stages do not compile.
+///
+/// Calling [`run`] applies the functions from front to back. Since the type of each stage is statically known,
+/// the calls can be fully inlined, which makes the pipeline a zero-cost alternative to `Vec<Box<dyn Fn>>` chains.
+///
+/// Pipelines can conveniently be created by the [`compose`] macro, or by chaining stages with [`then`] which
+/// validates each stage as it is added. This further allows the compiler to infer the input types of closures.
+///
+/// [`run`]: Pipeline::run
+/// [`then`]: Pipeline::then
+/// [`compose`]: crate::compose
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::compose;
+/// use orx_meta::queue::*;
+///
+/// let pipeline = compose!(
+///     |x: &str| x.len(),
+///     |n| n * 2,
+///     |n| format!("length x 2 = {n}"),
+/// );
+/// assert_eq!(pipeline.len(), 3);
+/// assert_eq!(pipeline.run("abc"), "length x 2 = 6");
+///
+/// // equivalently
+/// let pipeline = QueueSingle::new(|x: &str| x.len())
+///     .then(|n| n * 2)
+///     .then(|n| format!("length x 2 = {n}"));
+/// assert_eq!(pipeline.run("abcd"), "length x 2 = 8");
+/// ```
+///
+/// Output of each stage must match the input of the next stage.
+///
+/// ```compile_fail
+/// use orx_meta::compose;
+/// use orx_meta::queue::*;
+///
+/// let pipeline = compose!(|x: &str| x.len(), |c: char| c.is_alphabetic());
+/// ```
+pub trait Pipeline<In> {
+    /// Output type of the last function of the pipeline.
+    type Out;
+
+    /// Runs the pipeline on the `input` by applying the functions from front to back, and returns the
+    /// output of the last function.
+    fn run(&self, input: In) -> Self::Out;
+
+    /// Adds the function `f`, which takes the output of this pipeline as its input, as the last stage of the pipeline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    ///
+    /// let pipeline = QueueSingle::new(|x: u32| x + 1).then(|x| x as f32 / 2.0);
+    /// assert_eq!(pipeline.run(4), 2.5);
+    /// ```
+    #[inline(always)]
+    fn then<G, Next>(self, f: G) -> Self::PushBack<G>
+    where
+        Self: StQueue + Sized,
+        G: Fn(Self::Out) -> Next,
+    {
+        self.push(f)
+    }
+}
+
+impl<In, Out, F> Pipeline<In> for QueueSingle<F>
+where
+    F: Fn(In) -> Out,
+{
+    type Out = Out;
+
+    #[inline(always)]
+    fn run(&self, input: In) -> Self::Out {
+        (self.front)(input)
+    }
+}
+
+impl<In, Mid, F, B> Pipeline<In> for Queue<F, B>
+where
+    F: Fn(In) -> Mid,
+    B: StQueue + Pipeline<Mid>,
+{
+    type Out = B::Out;
+
+    #[inline(always)]
+    fn run(&self, input: In) -> Self::Out {
+        self.back().run((self.front())(input))
+    }
+}
@@ -0,0 +1,190 @@
+use crate::queue::{Queue, QueueSingle, ReprCQueue, ReprCQueueSingle, StQueue};
+
+/// A common trait implemented by all statically-typed queue families.
+///
+/// Each [`define_queue`] invocation creates its own queue trait, such as `StScreen`, which is unrelated
+/// to [`StQueue`] or to queue traits of other invocations. However, all queues created by the macro,
+/// as well as [`QueueSingle`], [`Queue`], [`ReprCQueueSingle`] and [`ReprCQueue`], implement `QueueLike`.
+/// Therefore, utilities such as counting, reflection or traversal can be written once over all queue families.
+///
+/// Similar to [`StQueue`], single element queues are their own `Back` type. Since there exists no back
+/// queue in this case, [`back`] returns None and [`pop`] returns None as the back queue.
+///
+/// [`define_queue`]: crate::define_queue
+/// [`back`]: QueueLike::back
+/// [`pop`]: QueueLike::pop
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::queue::*;
+///
+/// fn type_names<Q: QueueLike>(names: &mut Vec<&'static str>) {
+///     names.push(core::any::type_name::<Q::Front>());
+///     if Q::LEN > 1 {
+///         type_names::<Q::Back>(names);
+///     }
+/// }
+///
+/// orx_meta::define_queue!(
+///     elements => [ Clone ];
+///     queue => [ StClones ; ClonesSingle, Clones ];
+/// );
+///
+/// let mut names = vec![];
+/// type_names::<Queue<u32, QueueSingle<char>>>(&mut names);
+/// assert_eq!(names, ["u32", "char"]);
+///
+/// let mut names = vec![];
+/// type_names::<Clones<bool, Clones<u8, ClonesSingle<char>>>>(&mut names);
+/// assert_eq!(names, ["bool", "u8", "char"]);
+/// ```
+///
+/// Elements can be traversed by popping the queue in a generic context.
+///
+/// ```
+/// use orx_meta::queue::*;
+///
+/// fn count_back<Q: QueueLike>(queue: &Q) -> usize {
+///     match queue.back() {
+///         Some(back) => 1 + count_back(back),
+///         None => 1,
+///     }
+/// }
+///
+/// let queue = Queue::new(42).push(true).push('x');
+/// assert_eq!(count_back(&queue), 3);
+///
+/// let (front, back) = QueueLike::pop(queue);
+/// assert_eq!(front, 42);
+/// assert_eq!(back.map(|x| x.into_tuple()), Some((true, 'x')));
+///
+/// let (front, back) = QueueLike::pop(QueueSingle::new('y'));
+/// assert_eq!(front, 'y');
+/// assert!(back.is_none());
+/// ```
+pub trait QueueLike: Sized {
+    /// Type of the element at the front of the queue.
+    type Front;
+
+    /// Type of the queue that would be obtained by popping the `Front` element of the queue,
+    /// which is the queue itself for single element queues.
+    type Back: QueueLike;
+
+    /// Number of elements in the queue.
+    const LEN: usize;
+
+    /// Returns a reference to the element in the front of the queue.
+    fn front(&self) -> &Self::Front;
+
+    /// Returns a reference to the queue of elements except for the one in the front;
+    /// None if the queue contains exactly one element.
+    fn back(&self) -> Option<&Self::Back>;
+
+    /// Consumes the queue and returns the element in the front and the queue of remaining elements;
+    /// the latter is None if the queue contains exactly one element.
+    fn pop(self) -> (Self::Front, Option<Self::Back>);
+}
+
+// base
+
+impl<F> QueueLike for QueueSingle<F> {
+    type Front = F;
+
+    type Back = Self;
+
+    const LEN: usize = 1;
+
+    #[inline(always)]
+    fn front(&self) -> &F {
+        &self.front
+    }
+
+    #[inline(always)]
+    fn back(&self) -> Option<&Self> {
+        None
+    }
+
+    #[inline(always)]
+    fn pop(self) -> (F, Option<Self>) {
+        (self.front, None)
+    }
+}
+
+impl<F, B> QueueLike for Queue<F, B>
+where
+    B: StQueue + QueueLike,
+{
+    type Front = F;
+
+    type Back = B;
+
+    const LEN: usize = 1 + <B as QueueLike>::LEN;
+
+    #[inline(always)]
+    fn front(&self) -> &F {
+        Queue::front(self)
+    }
+
+    #[inline(always)]
+    fn back(&self) -> Option<&B> {
+        Some(Queue::back(self))
+    }
+
+    #[inline(always)]
+    fn pop(self) -> (F, Option<B>) {
+        let (f, b) = Queue::pop(self);
+        (f, Some(b))
+    }
+}
+
+// repr-c
+
+impl<F> QueueLike for ReprCQueueSingle<F> {
+    type Front = F;
+
+    type Back = Self;
+
+    const LEN: usize = 1;
+
+    #[inline(always)]
+    fn front(&self) -> &F {
+        &self.front
+    }
+
+    #[inline(always)]
+    fn back(&self) -> Option<&Self> {
+        None
+    }
+
+    #[inline(always)]
+    fn pop(self) -> (F, Option<Self>) {
+        (self.front, None)
+    }
+}
+
+impl<F, B> QueueLike for ReprCQueue<F, B>
+where
+    B: StQueue + QueueLike,
+{
+    type Front = F;
+
+    type Back = B;
+
+    const LEN: usize = 1 + <B as QueueLike>::LEN;
+
+    #[inline(always)]
+    fn front(&self) -> &F {
+        &self.front
+    }
+
+    #[inline(always)]
+    fn back(&self) -> Option<&B> {
+        Some(&self.back)
+    }
+
+    #[inline(always)]
+    fn pop(self) -> (F, Option<B>) {
+        (self.front, Some(self.back))
+    }
+}
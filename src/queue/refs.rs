@@ -0,0 +1,311 @@
+use crate::queue::{Queue, QueueSingle, ReprCQueue, ReprCQueueSingle, StQueue};
+
+/// Creates a queue of references to the elements of a statically-typed queue.
+///
+/// Calling `as_refs` on `Queue<A, Queue<B, QueueSingle<C>>>` gives us the queue
+/// `Queue<&A, Queue<&B, QueueSingle<&C>>>`, which can be passed around without moving the original queue.
+/// Unlike `as_tuple`, it is available for queues of arbitrary lengths.
+///
+/// Queues created by [`define_queue`] implement the trait whenever references to their elements satisfy
+/// the element bounds.
+///
+/// The elements can be cloned or copied back into a queue of values by [`Cloned`] or [`Copied`].
+///
+/// [`define_queue`]: crate::define_queue
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::queue::*;
+///
+/// fn total_len<'a>(queue: Queue<&'a String, QueueSingle<&'a Vec<u8>>>) -> usize {
+///     let (a, b) = queue.pop();
+///     a.len() + b.pop().len()
+/// }
+///
+/// let queue = Queue::new(String::from("abc")).push(vec![1, 2]);
+///
+/// assert_eq!(total_len(queue.as_refs()), 5);
+/// assert_eq!(queue.as_refs().into_tuple(), (&String::from("abc"), &vec![1, 2]));
+/// ```
+pub trait AsRefs<'q> {
+    /// Type of the queue of references to the elements.
+    type Refs;
+
+    /// Returns the queue of references to the elements of this queue.
+    fn as_refs(&'q self) -> Self::Refs;
+}
+
+/// Creates a queue of mutable references to the elements of a statically-typed queue.
+///
+/// Calling `as_muts` on `Queue<A, Queue<B, QueueSingle<C>>>` gives us the queue
+/// `Queue<&mut A, Queue<&mut B, QueueSingle<&mut C>>>`.
+/// Unlike `as_tuple_mut`, it is available for queues of arbitrary lengths.
+///
+/// Queues created by [`define_queue`] implement the trait whenever mutable references to their elements
+/// satisfy the element bounds.
+///
+/// [`define_queue`]: crate::define_queue
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::queue::*;
+///
+/// fn reset<'a>(queue: Queue<&'a mut u32, QueueSingle<&'a mut String>>) {
+///     let (a, b) = queue.pop();
+///     *a = 0;
+///     b.pop().clear();
+/// }
+///
+/// let mut queue = Queue::new(42).push(String::from("abc"));
+/// reset(queue.as_muts());
+/// assert_eq!(queue.into_tuple(), (0, String::new()));
+/// ```
+pub trait AsMuts<'q> {
+    /// Type of the queue of mutable references to the elements.
+    type Muts;
+
+    /// Returns the queue of mutable references to the elements of this queue.
+    fn as_muts(&'q mut self) -> Self::Muts;
+}
+
+/// Clones the elements of a queue of references into a queue of values.
+///
+/// Calling `cloned` on `Queue<&A, Queue<&B, QueueSingle<&C>>>` gives us the queue
+/// `Queue<A, Queue<B, QueueSingle<C>>>`. This is the inverse of [`AsRefs`] whenever all elements are `Clone`.
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::queue::*;
+///
+/// let queue = Queue::new(String::from("abc")).push(vec![1, 2]);
+///
+/// let refs = queue.as_refs();
+/// let cloned = refs.cloned();
+/// assert_eq!(cloned, queue);
+/// ```
+pub trait Cloned {
+    /// Type of the queue of cloned elements.
+    type Output;
+
+    /// Clones the referenced elements and returns the queue of values.
+    fn cloned(self) -> Self::Output;
+}
+
+/// Copies the elements of a queue of references into a queue of values.
+///
+/// Calling `copied` on `Queue<&A, Queue<&B, QueueSingle<&C>>>` gives us the queue
+/// `Queue<A, Queue<B, QueueSingle<C>>>`. This is the inverse of [`AsRefs`] whenever all elements are `Copy`.
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::queue::*;
+///
+/// let queue = Queue::new(42).push('x').push(true);
+///
+/// let copied = queue.as_refs().copied();
+/// assert_eq!(copied.into_tuple(), (42, 'x', true));
+/// ```
+pub trait Copied {
+    /// Type of the queue of copied elements.
+    type Output;
+
+    /// Copies the referenced elements and returns the queue of values.
+    fn copied(self) -> Self::Output;
+}
+
+// single
+
+impl<'q, F: 'q> AsRefs<'q> for QueueSingle<F> {
+    type Refs = QueueSingle<&'q F>;
+
+    #[inline(always)]
+    fn as_refs(&'q self) -> Self::Refs {
+        QueueSingle::new(&self.front)
+    }
+}
+
+impl<'q, F: 'q> AsMuts<'q> for QueueSingle<F> {
+    type Muts = QueueSingle<&'q mut F>;
+
+    #[inline(always)]
+    fn as_muts(&'q mut self) -> Self::Muts {
+        QueueSingle::new(&mut self.front)
+    }
+}
+
+impl<F: Clone> Cloned for QueueSingle<&F> {
+    type Output = QueueSingle<F>;
+
+    #[inline(always)]
+    fn cloned(self) -> Self::Output {
+        QueueSingle::new(self.front.clone())
+    }
+}
+
+impl<F: Copy> Copied for QueueSingle<&F> {
+    type Output = QueueSingle<F>;
+
+    #[inline(always)]
+    fn copied(self) -> Self::Output {
+        QueueSingle::new(*self.front)
+    }
+}
+
+// multi
+
+impl<'q, F, B> AsRefs<'q> for Queue<F, B>
+where
+    F: 'q,
+    B: StQueue + AsRefs<'q>,
+    B::Refs: StQueue,
+{
+    type Refs = Queue<&'q F, B::Refs>;
+
+    #[inline(always)]
+    fn as_refs(&'q self) -> Self::Refs {
+        Queue::from_fb(self.front(), self.back().as_refs())
+    }
+}
+
+impl<'q, F, B> AsMuts<'q> for Queue<F, B>
+where
+    F: 'q,
+    B: StQueue + AsMuts<'q>,
+    B::Muts: StQueue,
+{
+    type Muts = Queue<&'q mut F, B::Muts>;
+
+    #[inline(always)]
+    fn as_muts(&'q mut self) -> Self::Muts {
+        let (f, b) = self.front_back_mut();
+        Queue::from_fb(f, b.as_muts())
+    }
+}
+
+impl<F, B> Cloned for Queue<&F, B>
+where
+    F: Clone,
+    B: StQueue + Cloned,
+    B::Output: StQueue,
+{
+    type Output = Queue<F, B::Output>;
+
+    #[inline(always)]
+    fn cloned(self) -> Self::Output {
+        let (f, b) = self.pop();
+        Queue::from_fb(f.clone(), b.cloned())
+    }
+}
+
+impl<F, B> Copied for Queue<&F, B>
+where
+    F: Copy,
+    B: StQueue + Copied,
+    B::Output: StQueue,
+{
+    type Output = Queue<F, B::Output>;
+
+    #[inline(always)]
+    fn copied(self) -> Self::Output {
+        let (f, b) = self.pop();
+        Queue::from_fb(*f, b.copied())
+    }
+}
+
+// repr-c
+
+impl<'q, F: 'q> AsRefs<'q> for ReprCQueueSingle<F> {
+    type Refs = ReprCQueueSingle<&'q F>;
+
+    #[inline(always)]
+    fn as_refs(&'q self) -> Self::Refs {
+        ReprCQueueSingle::new(&self.front)
+    }
+}
+
+impl<'q, F: 'q> AsMuts<'q> for ReprCQueueSingle<F> {
+    type Muts = ReprCQueueSingle<&'q mut F>;
+
+    #[inline(always)]
+    fn as_muts(&'q mut self) -> Self::Muts {
+        ReprCQueueSingle::new(&mut self.front)
+    }
+}
+
+impl<F: Clone> Cloned for ReprCQueueSingle<&F> {
+    type Output = ReprCQueueSingle<F>;
+
+    #[inline(always)]
+    fn cloned(self) -> Self::Output {
+        ReprCQueueSingle::new(self.front.clone())
+    }
+}
+
+impl<F: Copy> Copied for ReprCQueueSingle<&F> {
+    type Output = ReprCQueueSingle<F>;
+
+    #[inline(always)]
+    fn copied(self) -> Self::Output {
+        ReprCQueueSingle::new(*self.front)
+    }
+}
+
+impl<'q, F, B> AsRefs<'q> for ReprCQueue<F, B>
+where
+    F: 'q,
+    B: StQueue + AsRefs<'q>,
+    B::Refs: StQueue,
+{
+    type Refs = ReprCQueue<&'q F, B::Refs>;
+
+    #[inline(always)]
+    fn as_refs(&'q self) -> Self::Refs {
+        ReprCQueue::from_fb(&self.front, self.back.as_refs())
+    }
+}
+
+impl<'q, F, B> AsMuts<'q> for ReprCQueue<F, B>
+where
+    F: 'q,
+    B: StQueue + AsMuts<'q>,
+    B::Muts: StQueue,
+{
+    type Muts = ReprCQueue<&'q mut F, B::Muts>;
+
+    #[inline(always)]
+    fn as_muts(&'q mut self) -> Self::Muts {
+        ReprCQueue::from_fb(&mut self.front, self.back.as_muts())
+    }
+}
+
+impl<F, B> Cloned for ReprCQueue<&F, B>
+where
+    F: Clone,
+    B: StQueue + Cloned,
+    B::Output: StQueue,
+{
+    type Output = ReprCQueue<F, B::Output>;
+
+    #[inline(always)]
+    fn cloned(self) -> Self::Output {
+        ReprCQueue::from_fb(self.front.clone(), self.back.cloned())
+    }
+}
+
+impl<F, B> Copied for ReprCQueue<&F, B>
+where
+    F: Copy,
+    B: StQueue + Copied,
+    B::Output: StQueue,
+{
+    type Output = ReprCQueue<F, B::Output>;
+
+    #[inline(always)]
+    fn copied(self) -> Self::Output {
+        ReprCQueue::from_fb(*self.front, self.back.copied())
+    }
+}
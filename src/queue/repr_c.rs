@@ -0,0 +1,261 @@
+use crate::queue::StQueue;
+
+/// A statically-typed queue containing exactly one element of type `Front` with a stable
+/// `#[repr(C)]` layout.
+///
+/// This is the `#[repr(C)]` counterpart of [`QueueSingle`]; see [`ReprCQueue`] for details.
+///
+/// [`QueueSingle`]: crate::queue::QueueSingle
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(C)]
+pub struct ReprCQueueSingle<Front> {
+    /// The only element of the queue.
+    pub front: Front,
+}
+
+impl<F> StQueue for ReprCQueueSingle<F> {
+    type PushBack<Elem> = ReprCQueue<F, ReprCQueueSingle<Elem>>;
+
+    type Front = F;
+
+    type Back = Self;
+
+    const LEN: usize = 1;
+
+    #[inline(always)]
+    fn push<Elem>(self, element: Elem) -> Self::PushBack<Elem> {
+        ReprCQueue::from_fb(self.front, ReprCQueueSingle::new(element))
+    }
+
+    #[inline(always)]
+    fn front(&self) -> &Self::Front {
+        &self.front
+    }
+
+    #[inline(always)]
+    fn front_mut(&mut self) -> &mut Self::Front {
+        &mut self.front
+    }
+
+    #[inline(always)]
+    fn into_front(self) -> Self::Front {
+        self.front
+    }
+}
+
+impl<F> ReprCQueueSingle<F> {
+    /// Creates a new statically-typed queue containing exactly one `element`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    ///
+    /// let queue = ReprCQueueSingle::new(42);
+    /// assert_eq!(queue.len(), 1);
+    /// assert_eq!(queue.front, 42);
+    /// ```
+    #[inline(always)]
+    pub const fn new(element: F) -> Self {
+        Self { front: element }
+    }
+
+    /// Pops and returns the element in the front of this queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    ///
+    /// let queue = ReprCQueueSingle::new(42);
+    /// assert_eq!(queue.pop(), 42);
+    /// ```
+    #[inline(always)]
+    pub fn pop(self) -> F {
+        self.front
+    }
+
+    /// Returns a reference to the element in the front of the queue.
+    ///
+    /// This is the `const fn` counterpart of [`StQueue::front`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    ///
+    /// const QUEUE: ReprCQueueSingle<u32> = ReprCQueueSingle::new(42);
+    /// const FRONT: &u32 = QUEUE.front();
+    ///
+    /// assert_eq!(FRONT, &42);
+    /// ```
+    #[inline(always)]
+    pub const fn front(&self) -> &F {
+        &self.front
+    }
+}
+
+/// A queue containing multiple (>= 2) elements with a stable `#[repr(C)]` layout.
+///
+/// Its fields are laid out in the following order:
+/// * `front: Front` is the element in the front of the queue;
+/// * `back: Back` is the queue of remaining elements except for the one in the front.
+///
+/// Therefore, layout of the queue is identical to that of a `#[repr(C)]` struct, or a C struct,
+/// with the same nested fields. This makes the queue suitable for FFI and memory-mapped buffers.
+///
+/// Notice that the queue is a nested struct, and nesting might introduce padding which does not
+/// exist in a flat struct with the same fields. The [`assert_layout_matches`] macro can be used
+/// to verify at compile time that the queue matches a flat `#[repr(C)]` struct.
+///
+/// [`assert_layout_matches`]: crate::assert_layout_matches
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::queue::*;
+/// use orx_meta::assert_layout_matches;
+///
+/// #[repr(C)]
+/// struct Vertex {
+///     x: f32,
+///     y: f32,
+///     z: f32,
+/// }
+///
+/// type VertexQueue = ReprCQueue<f32, ReprCQueue<f32, ReprCQueueSingle<f32>>>;
+///
+/// assert_layout_matches!(VertexQueue, Vertex { x, y, z });
+///
+/// let queue: VertexQueue = ReprCQueue::new(1.0).push(2.0).push(3.0);
+/// assert_eq!(queue.front, 1.0);
+/// assert_eq!(queue.back.front, 2.0);
+/// assert_eq!(queue.back.back.front, 3.0);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(C)]
+pub struct ReprCQueue<Front, Back>
+where
+    Back: StQueue,
+{
+    /// The element in the front of the queue.
+    pub front: Front,
+    /// The queue of remaining elements except for the one in the front.
+    pub back: Back,
+}
+
+impl<F, B> StQueue for ReprCQueue<F, B>
+where
+    B: StQueue,
+{
+    type PushBack<Elem> = ReprCQueue<F, B::PushBack<Elem>>;
+
+    type Front = F;
+
+    type Back = B;
+
+    const LEN: usize = 1 + B::LEN;
+
+    #[inline(always)]
+    fn push<Elem>(self, element: Elem) -> Self::PushBack<Elem> {
+        ReprCQueue::from_fb(self.front, self.back.push(element))
+    }
+
+    #[inline(always)]
+    fn front(&self) -> &F {
+        &self.front
+    }
+
+    #[inline(always)]
+    fn front_mut(&mut self) -> &mut F {
+        &mut self.front
+    }
+
+    #[inline(always)]
+    fn into_front(self) -> F {
+        self.front
+    }
+}
+
+impl<F> ReprCQueue<F, ReprCQueueSingle<F>> {
+    /// Creates a [`ReprCQueueSingle`] with exactly one `element`.
+    ///
+    /// Note that `ReprCQueue::new` is equivalent to `ReprCQueueSingle::new`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    ///
+    /// let queue: ReprCQueueSingle<u32> = ReprCQueue::new(42);
+    /// assert_eq!(queue.len(), 1);
+    ///
+    /// let queue: ReprCQueue<u32, ReprCQueueSingle<char>> = ReprCQueue::new(42).push('x');
+    /// assert_eq!(queue.len(), 2);
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::new_ret_no_self)]
+    pub const fn new(element: F) -> ReprCQueueSingle<F> {
+        ReprCQueueSingle::new(element)
+    }
+}
+
+impl<F, B> ReprCQueue<F, B>
+where
+    B: StQueue,
+{
+    /// Creates a queue with the given `front` element and the `back` queue of remaining elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    ///
+    /// const QUEUE: ReprCQueue<u8, ReprCQueueSingle<u8>> =
+    ///     ReprCQueue::from_fb(1, ReprCQueueSingle::new(2));
+    ///
+    /// assert_eq!(QUEUE, ReprCQueue::new(1).push(2));
+    /// ```
+    #[inline(always)]
+    pub const fn from_fb(front: F, back: B) -> Self {
+        Self { front, back }
+    }
+
+    /// Consumes the queue and returns the tuple of its front and back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    ///
+    /// let queue = ReprCQueue::new(42).push(true).push('x');
+    ///
+    /// let (num, queue) = queue.pop();
+    /// assert_eq!(num, 42);
+    /// assert_eq!(queue, ReprCQueue::new(true).push('x'));
+    /// ```
+    #[inline(always)]
+    pub fn pop(self) -> (F, B) {
+        (self.front, self.back)
+    }
+
+    /// Returns a reference to the element in the front of the queue.
+    ///
+    /// This is the `const fn` counterpart of [`StQueue::front`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    ///
+    /// const QUEUE: ReprCQueue<u32, ReprCQueueSingle<bool>> =
+    ///     ReprCQueue::from_fb(42, ReprCQueueSingle::new(true));
+    /// const FRONT: &u32 = QUEUE.front();
+    ///
+    /// assert_eq!(FRONT, &42);
+    /// ```
+    #[inline(always)]
+    pub const fn front(&self) -> &F {
+        &self.front
+    }
+}
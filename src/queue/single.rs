@@ -4,7 +4,7 @@ use crate::queue::{Queue, StQueue};
 ///
 /// See also the other [`StQueue`] implementation [`Queue`] which can be
 /// created by pushing a second element to this queue.
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct QueueSingle<Front> {
     pub(super) front: Front,
 }
@@ -62,7 +62,7 @@ impl<F> QueueSingle<F> {
     /// assert_eq!(queue.front(), &42);
     /// ```
     #[inline(always)]
-    pub fn new(element: F) -> Self {
+    pub const fn new(element: F) -> Self {
         Self { front: element }
     }
 
@@ -71,6 +71,10 @@ impl<F> QueueSingle<F> {
     /// Since this element contains only one element, there is no remaining queue once the
     /// front is popped. Therefore, the return type is only the element rather than a tuple.
     ///
+    /// This method is not a `const fn`, see [`Queue::pop`] for the reason and the alternatives in const items.
+    ///
+    /// [`Queue::pop`]: crate::queue::Queue::pop
+    ///
     /// # Examples
     ///
     /// ```
@@ -85,4 +89,23 @@ impl<F> QueueSingle<F> {
     pub fn pop(self) -> F {
         self.front
     }
+
+    /// Returns a reference to the element in the front of the queue.
+    ///
+    /// This is the `const fn` counterpart of [`StQueue::front`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    ///
+    /// const QUEUE: QueueSingle<u32> = QueueSingle::new(42);
+    /// const FRONT: &u32 = QUEUE.front();
+    ///
+    /// assert_eq!(FRONT, &42);
+    /// ```
+    #[inline(always)]
+    pub const fn front(&self) -> &F {
+        &self.front
+    }
 }
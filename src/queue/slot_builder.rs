@@ -0,0 +1,164 @@
+use crate::queue::StQueue;
+use crate::queue::slots::{FilledSlots, SetSlot, UnsetSlots};
+use core::marker::PhantomData;
+
+/// A type-safe builder for queues such that:
+///
+/// * elements can be set in any order, each by its type or by its field name,
+/// * each element can be set exactly once, and
+/// * `finish` can only be called when all elements of the `Target` type are set.
+///
+/// As opposed to [`QueueBuilder`] which requires pushing the elements in order, this builder keeps one
+/// slot for each element of the target queue. Each slot is initially unset, and becomes set once its
+/// value is provided. The [`QueueBuilding`] state could not be reused here since it tracks progress by
+/// its `Remaining` queue, the suffix of the target which is not pushed yet, and hence, can only represent
+/// the elements being provided from the front. The slot to set is found by the `Key` of the `set::<Key, _>(value)` call:
+/// * by type: `Key` is the element type itself, such as `set::<u32, _>(42)`; or
+/// * by field name: `Key` is the `Name` of an element of type `Field<Name, T>`, such as
+///   `set::<Capacity, _>(42)`, in which case the value is of type `T`.
+///
+/// The second generic argument of `set` is an index witness, which is always inferred and written as `_`.
+///
+/// Notice that the slot can be found only if the key matches exactly one unset slot. When the target
+/// contains multiple elements of the same type, they can be distinguished by field names; see [`Field`].
+///
+/// [`QueueBuilder`]: crate::queue::QueueBuilder
+/// [`QueueBuilding`]: crate::queue::QueueBuilding
+/// [`Field`]: crate::record::Field
+///
+/// # Examples
+///
+/// Elements can be set by their types in any order.
+///
+/// ```
+/// use orx_meta::queue::*;
+/// use orx_meta::queue_of;
+///
+/// type MyQueue = queue_of!(u32, bool, char, String);
+///
+/// let instance = QueueSlotBuilder::<MyQueue>::new()
+///     .set::<char, _>('x')
+///     .set::<String, _>("foo".to_string())
+///     .set::<u32, _>(42)
+///     .set::<bool, _>(true)
+///     .finish();
+/// assert_eq!(instance.as_tuple(), (&42, &true, &'x', &"foo".to_string()));
+/// ```
+///
+/// Records are best built by field names, which also allows multiple fields of the same type.
+///
+/// ```
+/// use orx_meta::queue::*;
+/// use orx_meta::record::*;
+/// use orx_meta::record_of;
+///
+/// struct Capacity;
+/// struct Workers;
+/// struct Name;
+///
+/// type Config = record_of!(Capacity: usize, Workers: usize, Name: String);
+///
+/// let config = QueueSlotBuilder::<Config>::new()
+///     .set::<Workers, _>(4)
+///     .set::<Name, _>("pool".to_string())
+///     .set::<Capacity, _>(1024)
+///     .finish();
+///
+/// assert_eq!(config.get::<Capacity>(), &1024);
+/// assert_eq!(config.get::<Workers>(), &4);
+/// assert_eq!(config.get::<Name>(), "pool");
+/// ```
+///
+/// ## Examples - Type Safety
+///
+/// Neither of the following wrong implementations compiles.
+///
+/// Here, the same slot is set twice:
+///
+/// ```compile_fail
+/// use orx_meta::queue::*;
+/// use orx_meta::queue_of;
+///
+/// type MyQueue = queue_of!(u32, bool);
+///
+/// let instance = QueueSlotBuilder::<MyQueue>::new()
+///     .set::<u32, _>(42)
+///     .set::<u32, _>(7) // already set!
+///     .finish();
+/// ```
+///
+/// And here, not all slots are set:
+///
+/// ```compile_fail
+/// use orx_meta::queue::*;
+/// use orx_meta::queue_of;
+///
+/// type MyQueue = queue_of!(u32, bool);
+///
+/// let instance = QueueSlotBuilder::<MyQueue>::new()
+///     .set::<u32, _>(42)
+///     .finish(); // forgot to set the bool
+/// ```
+pub struct QueueSlotBuilder<Target, Slots = <Target as UnsetSlots>::Slots>
+where
+    Target: UnsetSlots,
+    Slots: StQueue,
+{
+    target: PhantomData<Target>,
+    slots: Slots,
+}
+
+impl<Target> Default for QueueSlotBuilder<Target>
+where
+    Target: UnsetSlots,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Target> QueueSlotBuilder<Target>
+where
+    Target: UnsetSlots,
+{
+    /// Creates a new builder for the `Target` type defined as the generic argument, all slots of which are unset.
+    pub fn new() -> Self {
+        Self {
+            target: PhantomData,
+            slots: Target::unset_slots(),
+        }
+    }
+}
+
+impl<Target, Slots> QueueSlotBuilder<Target, Slots>
+where
+    Target: UnsetSlots,
+    Slots: StQueue,
+{
+    /// Sets the value of the unset slot matching the `Key`, which is either the type of the element or the
+    /// field name of the element.
+    ///
+    /// The index witness `I` is always inferred.
+    pub fn set<Key, I>(
+        self,
+        value: <Slots as SetSlot<Key, I>>::Value,
+    ) -> QueueSlotBuilder<Target, <Slots as SetSlot<Key, I>>::Output>
+    where
+        Slots: SetSlot<Key, I>,
+    {
+        QueueSlotBuilder {
+            target: PhantomData,
+            slots: self.slots.set_slot(value),
+        }
+    }
+
+    /// Completes the builder and returns the built target type.
+    ///
+    /// This method can only be called when all slots are set.
+    pub fn finish(self) -> Target
+    where
+        Slots: FilledSlots<Output = Target>,
+    {
+        self.slots.into_filled()
+    }
+}
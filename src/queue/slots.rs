@@ -0,0 +1,178 @@
+use crate::queue::{Queue, QueueSingle, StQueue};
+use crate::record::Field;
+use crate::type_list::{Here, There};
+use core::marker::PhantomData;
+
+/// A slot of the [`QueueSlotBuilder`] whose value of type `T` is not set yet.
+///
+/// [`QueueSlotBuilder`]: crate::queue::QueueSlotBuilder
+pub struct SlotUnset<T>(PhantomData<fn() -> T>);
+
+/// A slot of the [`QueueSlotBuilder`] with the value of type `T`.
+///
+/// [`QueueSlotBuilder`]: crate::queue::QueueSlotBuilder
+pub struct SlotSet<T>(T);
+
+/// Marker for a slot matched by the type of its element.
+pub struct ByType;
+
+/// Marker for a slot matched by the name of its [`Field`] element.
+///
+/// [`Field`]: crate::record::Field
+pub struct ByName;
+
+/// An unset slot which can be set with the given `Key` matched by the mode `M`, either [`ByType`] or [`ByName`].
+pub trait Slot<Key, M> {
+    /// Type of the value to set.
+    type Value;
+
+    /// Type of the slot after its value is set.
+    type Filled;
+
+    /// Creates the filled slot with the given `value`.
+    fn fill(value: Self::Value) -> Self::Filled;
+}
+
+impl<T> Slot<T, ByType> for SlotUnset<T> {
+    type Value = T;
+
+    type Filled = SlotSet<T>;
+
+    #[inline(always)]
+    fn fill(value: T) -> Self::Filled {
+        SlotSet(value)
+    }
+}
+
+impl<Name, T> Slot<Name, ByName> for SlotUnset<Field<Name, T>> {
+    type Value = T;
+
+    type Filled = SlotSet<Field<Name, T>>;
+
+    #[inline(always)]
+    fn fill(value: T) -> Self::Filled {
+        SlotSet(Field::new(value))
+    }
+}
+
+/// A queue of slots which has an unset slot matching the `Key`.
+///
+/// The generic parameter `I` is the index witness which lets the compiler find the matching slot.
+pub trait SetSlot<Key, I>: StQueue {
+    /// Type of the value to set.
+    type Value;
+
+    /// Type of the queue of slots after the matching slot is set.
+    type Output: StQueue;
+
+    /// Sets the value of the matching slot.
+    fn set_slot(self, value: Self::Value) -> Self::Output;
+}
+
+impl<Key, M, S> SetSlot<Key, (Here, M)> for QueueSingle<S>
+where
+    S: Slot<Key, M>,
+{
+    type Value = S::Value;
+
+    type Output = QueueSingle<S::Filled>;
+
+    #[inline(always)]
+    fn set_slot(self, value: Self::Value) -> Self::Output {
+        QueueSingle::new(S::fill(value))
+    }
+}
+
+impl<Key, M, S, B> SetSlot<Key, (Here, M)> for Queue<S, B>
+where
+    S: Slot<Key, M>,
+    B: StQueue,
+{
+    type Value = S::Value;
+
+    type Output = Queue<S::Filled, B>;
+
+    #[inline(always)]
+    fn set_slot(self, value: Self::Value) -> Self::Output {
+        Queue::from_fb(S::fill(value), self.into_back())
+    }
+}
+
+impl<Key, I, F, B> SetSlot<Key, There<I>> for Queue<F, B>
+where
+    B: SetSlot<Key, I>,
+{
+    type Value = B::Value;
+
+    type Output = Queue<F, B::Output>;
+
+    #[inline(always)]
+    fn set_slot(self, value: Self::Value) -> Self::Output {
+        let (f, b) = self.pop();
+        Queue::from_fb(f, b.set_slot(value))
+    }
+}
+
+/// A queue which can be built by the [`QueueSlotBuilder`] starting from the queue of its unset slots.
+///
+/// [`QueueSlotBuilder`]: crate::queue::QueueSlotBuilder
+pub trait UnsetSlots: StQueue {
+    /// Type of the queue of unset slots for each element of this queue.
+    type Slots: StQueue;
+
+    /// Creates the queue of unset slots.
+    fn unset_slots() -> Self::Slots;
+}
+
+impl<T> UnsetSlots for QueueSingle<T> {
+    type Slots = QueueSingle<SlotUnset<T>>;
+
+    #[inline(always)]
+    fn unset_slots() -> Self::Slots {
+        QueueSingle::new(SlotUnset(PhantomData))
+    }
+}
+
+impl<F, B> UnsetSlots for Queue<F, B>
+where
+    B: UnsetSlots,
+{
+    type Slots = Queue<SlotUnset<F>, B::Slots>;
+
+    #[inline(always)]
+    fn unset_slots() -> Self::Slots {
+        Queue::from_fb(SlotUnset(PhantomData), B::unset_slots())
+    }
+}
+
+/// A queue of slots all of which are set.
+pub trait FilledSlots: StQueue {
+    /// Type of the queue of the values of the slots.
+    type Output;
+
+    /// Converts the filled slots into the queue of their values.
+    fn into_filled(self) -> Self::Output;
+}
+
+impl<T> FilledSlots for QueueSingle<SlotSet<T>> {
+    type Output = QueueSingle<T>;
+
+    #[inline(always)]
+    fn into_filled(self) -> Self::Output {
+        QueueSingle::new(self.pop().0)
+    }
+}
+
+impl<F, B> FilledSlots for Queue<SlotSet<F>, B>
+where
+    B: FilledSlots,
+    B::Output: StQueue,
+{
+    type Output = Queue<F, B::Output>;
+
+    #[inline(always)]
+    fn into_filled(self) -> Self::Output {
+        let (f, b) = self.pop();
+        Queue::from_fb(f.0, b.into_filled())
+    }
+}
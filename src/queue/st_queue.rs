@@ -143,5 +143,13 @@ pub trait StQueue {
     ///
     /// assert_eq!(queue.into_front(), 'x');
     /// ```
+    ///
+    /// Trait methods cannot be `const fn`, and neither could this method be, as explained in [`Queue::pop`].
+    /// In const items, the front can be accessed by the inherent `const fn`s [`QueueSingle::front`] and
+    /// [`Queue::front`].
+    ///
+    /// [`Queue::pop`]: crate::queue::Queue::pop
+    /// [`QueueSingle::front`]: crate::queue::QueueSingle::front
+    /// [`Queue::front`]: crate::queue::Queue::front
     fn into_front(self) -> Self::Front;
 }
@@ -1,5 +1,5 @@
 use crate::{
-    queue::{Queue, QueueBuilder, QueueSingle},
+    queue::{BuilderProgress, Queue, QueueBuilder, QueueBuilding, QueueSingle},
     queue_of,
 };
 
@@ -30,3 +30,135 @@ fn nonempty_queue_builder() {
         .finish();
     assert_eq!(q.into_tuple(), (42, 'x', true, "foo".to_string()));
 }
+
+#[test]
+fn builder_skip() {
+    type Q1 = queue_of!(u32);
+    let q = QueueBuilder::<Q1>::new().skip().finish();
+    assert_eq!(q.into_tuple(), 0);
+
+    type Q4 = queue_of!(u32, char, bool, String);
+    let q = QueueBuilder::<Q4>::new()
+        .skip()
+        .push('x')
+        .skip()
+        .push("foo".to_string())
+        .finish();
+    assert_eq!(q.into_tuple(), (0, 'x', false, "foo".to_string()));
+}
+
+#[test]
+fn builder_finish_with_defaults() {
+    type Q1 = queue_of!(u32);
+    assert_eq!(
+        QueueBuilder::<Q1>::new()
+            .finish_with_defaults()
+            .into_tuple(),
+        0
+    );
+    let q = QueueBuilder::<Q1>::new().push(42).finish_with_defaults();
+    assert_eq!(q.into_tuple(), 42);
+
+    type Q4 = queue_of!(u32, char, bool, String);
+    let q = QueueBuilder::<Q4>::new().finish_with_defaults();
+    assert_eq!(q.into_tuple(), (0, '\0', false, String::new()));
+
+    let q = QueueBuilder::<Q4>::new().push(42).finish_with_defaults();
+    assert_eq!(q.into_tuple(), (42, '\0', false, String::new()));
+
+    let q = QueueBuilder::<Q4>::new()
+        .push(42)
+        .push('x')
+        .finish_with_defaults();
+    assert_eq!(q.into_tuple(), (42, 'x', false, String::new()));
+
+    let q = QueueBuilder::<Q4>::new()
+        .push(42)
+        .push('x')
+        .push(true)
+        .finish_with_defaults();
+    assert_eq!(q.into_tuple(), (42, 'x', true, String::new()));
+
+    let q = QueueBuilder::<Q4>::new()
+        .push(42)
+        .push('x')
+        .push(true)
+        .push("foo".to_string())
+        .finish_with_defaults();
+    assert_eq!(q.into_tuple(), (42, 'x', true, "foo".to_string()));
+}
+
+#[test]
+fn builder_finish_with_defaults_requires_default_only_for_remaining() {
+    #[derive(Debug, PartialEq)]
+    struct NoDefault(u32);
+
+    type Q = queue_of!(NoDefault, u32, String);
+    let q = QueueBuilder::<Q>::new()
+        .push(NoDefault(1))
+        .finish_with_defaults();
+    assert_eq!(q.into_tuple(), (NoDefault(1), 0, String::new()));
+}
+
+#[test]
+fn builder_progress() {
+    type Q = queue_of!(u32, char, bool);
+
+    const _: () = assert!(QueueBuilder::<Q>::PUSHED == 0);
+    const _: () = assert!(QueueBuilder::<Q>::REMAINING == 3);
+
+    let b = QueueBuilder::<Q>::new();
+    assert_eq!((b.pushed(), b.remaining()), (0, 3));
+    assert_eq!(b.next_type_name(), Some("u32"));
+
+    let b = b.push(42);
+    assert_eq!((b.pushed(), b.remaining()), (1, 2));
+    assert_eq!(b.next_type_name(), Some("char"));
+
+    let b = b.push('x');
+    assert_eq!((b.pushed(), b.remaining()), (2, 1));
+    assert_eq!(b.next_type_name(), Some("bool"));
+
+    let b = b.push(true);
+    assert_eq!((b.pushed(), b.remaining()), (3, 0));
+    assert_eq!(b.next_type_name(), None);
+
+    let q = b.finish();
+    assert_eq!(q.into_tuple(), (42, 'x', true));
+
+    type Q1 = queue_of!(String);
+    let b = QueueBuilder::<Q1>::new().push("foo".to_string());
+    assert_eq!((b.pushed(), b.remaining()), (1, 0));
+    assert_eq!(b.next_type_name(), None);
+}
+
+#[test]
+fn builder_progress_of_current_longer_than_target() {
+    type Building = QueueBuilding<queue_of!(u32), queue_of!(u32), queue_of!(u32, char)>;
+
+    const _: () = assert!(Building::REMAINING == 0);
+
+    let b = Building::default();
+    assert_eq!((b.pushed(), b.remaining()), (2, 0));
+    assert_eq!(b.next_type_name(), None);
+}
+
+#[test]
+fn builder_debug() {
+    type Q = queue_of!(u32, char, bool);
+
+    let b = QueueBuilder::<Q>::new();
+    assert_eq!(
+        format!("{b:?}"),
+        format!(
+            "QueueBuilder {{ target: {:?} }}",
+            core::any::type_name::<Q>()
+        )
+    );
+
+    let b = b.push(42).push('x');
+    assert_eq!(
+        format!("{b:?}"),
+        "QueueBuilding { current: [42, 'x'], remaining: 1 }"
+    );
+}
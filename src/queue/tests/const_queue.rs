@@ -0,0 +1,80 @@
+use crate::{queue::*, queue_of};
+
+const SINGLE: queue_of!(u32) = QueueSingle::new(42);
+
+const QUEUE: queue_of!(u32, char, bool) =
+    Queue::from_fb(42, Queue::from_fb('x', QueueSingle::new(true)));
+
+static STATIC_QUEUE: queue_of!(u32, &str) = Queue::from_fb(7, Queue::new("foo"));
+
+#[test]
+fn const_construction() {
+    assert_eq!(SINGLE, QueueSingle::new(42));
+    assert_eq!(QUEUE, Queue::new(42).push('x').push(true));
+    assert_eq!(STATIC_QUEUE.as_tuple(), (&7, &"foo"));
+}
+
+#[test]
+fn const_access() {
+    const FRONT: &u32 = QUEUE.front();
+    const BACK_FRONT: &char = QUEUE.back().front();
+    const LAST: &bool = QUEUE.back().back().front();
+
+    assert_eq!(FRONT, &42);
+    assert_eq!(BACK_FRONT, &'x');
+    assert_eq!(LAST, &true);
+    assert_eq!(SINGLE.front(), &42);
+}
+
+#[allow(dead_code)]
+mod flags {
+    pub trait Flag {
+        fn flag(&self) -> bool;
+    }
+
+    impl Flag for bool {
+        fn flag(&self) -> bool {
+            *self
+        }
+    }
+
+    impl Flag for u32 {
+        fn flag(&self) -> bool {
+            *self > 0
+        }
+    }
+
+    crate::define_queue!(
+        elements => [ Flag ];
+        queue => [ StFlags ; FlagsSingle, Flags ];
+        queue_of => flags_of;
+    );
+
+    impl<F: Flag> Flag for FlagsSingle<F> {
+        fn flag(&self) -> bool {
+            self.f.flag()
+        }
+    }
+
+    impl<F: Flag, B: StFlags> Flag for Flags<F, B> {
+        fn flag(&self) -> bool {
+            self.f.flag() && self.b.flag()
+        }
+    }
+
+    pub const FLAGS: flags_of!(bool, u32, bool) =
+        Flags::from_fb(true, Flags::from_fb(3, FlagsSingle::new(true)));
+}
+
+#[test]
+fn const_construction_defined_queue() {
+    use flags::*;
+
+    const FRONT: &bool = FLAGS.front();
+    const MIDDLE: &u32 = FLAGS.back().front();
+
+    assert!(FLAGS.flag());
+    assert!(*FRONT);
+    assert_eq!(MIDDLE, &3);
+    assert_eq!(FLAGS.into_tuple(), (true, 3, true));
+}
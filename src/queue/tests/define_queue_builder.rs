@@ -0,0 +1,159 @@
+#![allow(dead_code)]
+
+use crate::queue::BuilderProgress;
+use core::fmt::Debug;
+
+pub trait Weight<'a, C> {
+    fn weight(&self, ctx: &'a C) -> usize;
+}
+
+impl<'a> Weight<'a, usize> for u32 {
+    fn weight(&self, ctx: &'a usize) -> usize {
+        *self as usize * ctx
+    }
+}
+
+impl<'a> Weight<'a, usize> for char {
+    fn weight(&self, _: &'a usize) -> usize {
+        1
+    }
+}
+
+impl<'a> Weight<'a, usize> for u8 {
+    fn weight(&self, _: &'a usize) -> usize {
+        *self as usize
+    }
+}
+
+crate::define_queue!(
+    lt => ['a];
+    generics => [C];
+    elements => [Weight<'a, C> | Debug];
+    queue => [StWeights; WeightsSingle, Weights];
+    queue_of => weights_of;
+    builder => WeightsBuilder;
+);
+
+impl<'a, C, F> Weight<'a, C> for WeightsSingle<'a, C, F>
+where
+    F: Weight<'a, C> + Debug,
+{
+    fn weight(&self, ctx: &'a C) -> usize {
+        self.f.weight(ctx)
+    }
+}
+
+impl<'a, C, F, B> Weight<'a, C> for Weights<'a, C, F, B>
+where
+    F: Weight<'a, C> + Debug,
+    B: StWeights<'a, C>,
+{
+    fn weight(&self, ctx: &'a C) -> usize {
+        self.f.weight(ctx) + self.b.weight(ctx)
+    }
+}
+
+type C = usize;
+type Target<'a> = weights_of!(u32, char, u8);
+
+#[test]
+fn define_queue_builder_with_lifetime_and_generics() {
+    let ctx = 10;
+    let queue = WeightsBuilder::<usize, Target>::new()
+        .push(3)
+        .push('x')
+        .push(7)
+        .finish();
+    assert_eq!(queue.weight(&ctx), 30 + 1 + 7);
+}
+
+#[test]
+fn define_queue_builder_progress() {
+    assert_eq!(WeightsBuilder::<usize, Target>::PUSHED, 0);
+    assert_eq!(WeightsBuilder::<usize, Target>::REMAINING, 3);
+
+    let builder = WeightsBuilder::<usize, Target>::default();
+    assert_eq!(builder.next_type_name(), Some("u32"));
+
+    let builder = builder.push(3);
+    assert_eq!((builder.pushed(), builder.remaining()), (1, 2));
+    assert_eq!(builder.next_type_name(), Some("char"));
+
+    let builder = builder.push('x').push(7);
+    assert_eq!((builder.pushed(), builder.remaining()), (3, 0));
+    assert_eq!(builder.next_type_name(), None);
+}
+
+#[test]
+fn define_queue_builder_progress_of_current_longer_than_target() {
+    type Building<'a> =
+        QueueBuilding<'a, usize, weights_of!(u32), weights_of!(u32), weights_of!(u32, char)>;
+
+    const _: () = assert!(Building::REMAINING == 0);
+
+    let builder = Building::default();
+    assert_eq!((builder.pushed(), builder.remaining()), (2, 0));
+    assert_eq!(builder.next_type_name(), None);
+}
+
+#[test]
+fn define_queue_builder_progress_trait() {
+    fn progress<B: BuilderProgress>(builder: &B) -> (usize, usize, Option<&'static str>) {
+        (B::PUSHED, builder.remaining(), builder.next_type_name())
+    }
+
+    let builder = WeightsBuilder::<usize, Target>::new();
+    assert_eq!((builder.pushed(), builder.remaining()), (0, 3));
+    assert_eq!(progress(&builder), (0, 3, Some("u32")));
+
+    let builder = builder.push(3);
+    assert_eq!(progress(&builder), (1, 2, Some("char")));
+
+    let builder = builder.push('x').push(7);
+    assert_eq!(progress(&builder), (3, 0, None));
+}
+
+#[test]
+fn define_queue_builder_try_push_and_skip() {
+    let ctx = 10;
+    let queue = WeightsBuilder::<usize, Target>::new()
+        .skip()
+        .try_push(120u32)
+        .map(|b| b.push(7).finish());
+    assert_eq!(queue.map(|q| q.weight(&ctx)), Ok(1 + 7));
+
+    let error = WeightsBuilder::<usize, Target>::new().try_push(-1i32).err();
+    assert_eq!(error.map(|e| (e.position, e.type_name)), Some((0, "u32")));
+
+    let error = WeightsBuilder::<usize, Target>::new()
+        .push(3)
+        .skip()
+        .try_push(300u32)
+        .err();
+    assert_eq!(error.map(|e| (e.position, e.type_name)), Some((2, "u8")));
+}
+
+#[test]
+fn define_queue_builder_default_and_debug() {
+    let builder = WeightsBuilder::<usize, Target>::new();
+    assert_eq!(
+        format!("{builder:?}"),
+        format!(
+            "WeightsBuilder {{ target: {:?} }}",
+            core::any::type_name::<Target>()
+        )
+    );
+
+    let builder = builder.push(3).push('x');
+    assert_eq!(
+        format!("{builder:?}"),
+        "QueueBuilding { current: [3, 'x'], remaining: 1 }"
+    );
+
+    type Building<'a> =
+        QueueBuilding<'a, usize, Target<'a>, weights_of!(char, u8), WeightsSingle<'a, usize, u32>>;
+    let builder = Building::default();
+    assert_eq!(builder.pushed(), 1);
+    let queue = builder.push('y').push(4).finish();
+    assert_eq!(format!("{queue:?}"), "[0, 'y', 4]");
+}
@@ -0,0 +1,117 @@
+#![allow(dead_code)]
+
+pub trait Evaluate<const D: usize> {
+    fn evaluate(&self, x: &[i64; D]) -> i64;
+}
+
+pub struct Sum;
+
+impl<const D: usize> Evaluate<D> for Sum {
+    fn evaluate(&self, x: &[i64; D]) -> i64 {
+        x.iter().sum()
+    }
+}
+
+pub struct Scaled(i64);
+
+impl<const D: usize> Evaluate<D> for Scaled {
+    fn evaluate(&self, x: &[i64; D]) -> i64 {
+        self.0 * x[0]
+    }
+}
+
+crate::define_queue!(
+    consts => [const D: usize];
+    elements => [Evaluate<D>];
+    queue => [StKernel; KernelSingle, Kernel];
+    queue_of => kernel_of;
+    builder => KernelBuilder;
+);
+
+impl<const D: usize, F: Evaluate<D>> Evaluate<D> for KernelSingle<D, F> {
+    fn evaluate(&self, x: &[i64; D]) -> i64 {
+        self.f.evaluate(x)
+    }
+}
+
+impl<const D: usize, F: Evaluate<D>, B: StKernel<D>> Evaluate<D> for Kernel<D, F, B> {
+    fn evaluate(&self, x: &[i64; D]) -> i64 {
+        self.f.evaluate(x) + self.b.evaluate(x)
+    }
+}
+
+type Kernels<const D: usize> = kernel_of!(Sum, Scaled, Sum);
+
+#[test]
+fn define_queue_with_consts() {
+    let kernel: Kernel<3, Sum, KernelSingle<3, Scaled>> = Kernel::new(Sum).push(Scaled(10));
+    assert_eq!(kernel.evaluate(&[1, 2, 3]), 6 + 10);
+
+    let kernel: Kernel<2, _, _> = Kernel::new(Sum).push(Scaled(10));
+    assert_eq!(kernel.evaluate(&[1, 2]), 3 + 10);
+}
+
+#[test]
+fn define_queue_with_consts_queue_of_and_builder() {
+    let kernel = KernelBuilder::<2, Kernels<2>>::new()
+        .push(Sum)
+        .push(Scaled(2))
+        .push(Sum)
+        .finish();
+    assert_eq!(kernel.len(), 3);
+    assert_eq!(kernel.evaluate(&[3, 4]), 7 + 6 + 7);
+}
+
+mod with_lifetime_and_generics {
+    pub trait Lookup<'a, K, const N: usize> {
+        fn lookup(&self, keys: &'a [K; N]) -> usize;
+    }
+
+    pub struct CountOf<T>(T);
+
+    impl<'a, K: PartialEq, const N: usize> Lookup<'a, K, N> for CountOf<K> {
+        fn lookup(&self, keys: &'a [K; N]) -> usize {
+            keys.iter().filter(|x| **x == self.0).count()
+        }
+    }
+
+    crate::define_queue!(
+        lt => ['a];
+        generics => [K: PartialEq];
+        consts => [const N: usize];
+        elements => [Lookup<'a, K, N>];
+        queue => [StLookups; LookupsSingle, Lookups];
+        queue_of => lookups_of;
+        builder => LookupsBuilder;
+    );
+
+    impl<'a, K: PartialEq, const N: usize, F: Lookup<'a, K, N>> Lookup<'a, K, N>
+        for LookupsSingle<'a, K, N, F>
+    {
+        fn lookup(&self, keys: &'a [K; N]) -> usize {
+            self.f.lookup(keys)
+        }
+    }
+
+    impl<'a, K: PartialEq, const N: usize, F: Lookup<'a, K, N>, B: StLookups<'a, K, N>>
+        Lookup<'a, K, N> for Lookups<'a, K, N, F, B>
+    {
+        fn lookup(&self, keys: &'a [K; N]) -> usize {
+            self.f.lookup(keys) + self.b.lookup(keys)
+        }
+    }
+
+    type K = char;
+    const N: usize = 4;
+    type CharLookups<'a> = lookups_of!(CountOf<char>, CountOf<char>);
+
+    #[test]
+    fn define_queue_with_lifetime_generics_and_consts() {
+        let keys = ['a', 'b', 'a', 'c'];
+        let lookups = LookupsBuilder::<K, N, CharLookups>::new()
+            .push(CountOf('a'))
+            .push(CountOf('c'))
+            .finish();
+        assert_eq!(lookups.lookup(&keys), 3);
+    }
+}
@@ -0,0 +1,74 @@
+#![allow(dead_code)]
+
+mod custom_derives {
+    use std::collections::HashSet;
+
+    crate::define_queue!(
+        derive => [Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default];
+        vis => pub(crate);
+        queue => [
+            /// Queue of sortable keys.
+            StKey;
+            /// Key with a single element.
+            KeySingle,
+            /// Key with multiple elements.
+            #[must_use]
+            Key
+        ];
+        queue_of => key_of;
+        builder =>
+            /// Builder of sortable keys.
+            KeyBuilder;
+    );
+
+    type MyKey = key_of!(u32, String, char);
+
+    fn key(a: u32, b: &str, c: char) -> MyKey {
+        KeyBuilder::<MyKey>::new()
+            .push(a)
+            .push(b.to_string())
+            .push(c)
+            .finish()
+    }
+
+    #[test]
+    fn define_queue_with_hash_and_ord() {
+        let mut keys = [key(2, "a", 'x'), key(1, "b", 'y'), key(1, "a", 'z')];
+        keys.sort();
+        let firsts: Vec<_> = keys.iter().map(|x| x.clone().into_tuple()).collect();
+        assert_eq!(
+            firsts,
+            vec![
+                (1, "a".to_string(), 'z'),
+                (1, "b".to_string(), 'y'),
+                (2, "a".to_string(), 'x')
+            ]
+        );
+
+        let set: HashSet<_> = keys.iter().cloned().chain(keys.iter().cloned()).collect();
+        assert_eq!(set.len(), 3);
+    }
+
+    #[test]
+    fn define_queue_with_default() {
+        let default = MyKey::default();
+        assert_eq!(default.into_tuple(), (0, String::new(), '\0'));
+        assert!(key(0, "", '\0') <= MyKey::default());
+    }
+}
+
+mod no_derives {
+    crate::define_queue!(
+        derive => [];
+        queue => [StRaw; RawSingle, Raw];
+    );
+
+    pub struct NotClone;
+
+    #[test]
+    fn define_queue_without_derives() {
+        let raw = Raw::new(NotClone).push(42);
+        assert_eq!(raw.len(), 2);
+        assert_eq!(format!("{:?}", Raw::new(1).push(2)), "[1, 2]");
+    }
+}
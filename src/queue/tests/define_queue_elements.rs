@@ -0,0 +1,213 @@
+#![allow(dead_code)]
+
+use core::fmt::Debug;
+
+mod paths_and_auto_traits {
+    crate::define_queue!(
+        elements => [ core::fmt::Debug + Clone + Send + Sync + 'static ];
+        queue => [ StQueue ; QueueSingle, Queue ];
+        queue_of => queue_of;
+    );
+
+    fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+    #[test]
+    fn elements_with_paths_and_auto_traits() {
+        let queue: queue_of!(u32, String, char) = Queue::new(42).push("foo".to_string()).push('x');
+        assert_send_sync(&queue);
+        assert_eq!(queue.clone().into_tuple(), (42, "foo".to_string(), 'x'));
+    }
+}
+
+mod associated_type_bindings {
+    crate::define_queue!(
+        elements => [ Iterator<Item = u32> ];
+        queue => [ StIters ; ItersSingle, Iters ];
+    );
+
+    impl<F: Iterator<Item = u32>> Iterator for ItersSingle<F> {
+        type Item = u32;
+
+        fn next(&mut self) -> Option<u32> {
+            self.f.next()
+        }
+    }
+
+    impl<F: Iterator<Item = u32>, B: StIters> Iterator for Iters<F, B> {
+        type Item = u32;
+
+        fn next(&mut self) -> Option<u32> {
+            self.f.next().or_else(|| self.b.next())
+        }
+    }
+
+    #[test]
+    fn elements_with_associated_type_bindings() {
+        let iters = Iters::new(0..3)
+            .push([7, 8].into_iter())
+            .push(core::iter::once(42));
+        assert_eq!(iters.sum::<u32>(), 3 + 15 + 42);
+    }
+}
+
+mod higher_ranked {
+    pub trait Visit<'a> {
+        fn visit(&self, text: &'a str) -> &'a str;
+    }
+
+    pub struct Prefix(usize);
+
+    impl<'a> Visit<'a> for Prefix {
+        fn visit(&self, text: &'a str) -> &'a str {
+            &text[..self.0]
+        }
+    }
+
+    crate::define_queue!(
+        elements => [ for<'a> Visit<'a> ];
+        queue => [ StVisitors ; VisitorsSingle, Visitors ];
+    );
+
+    impl<'a, F: for<'b> Visit<'b>> Visit<'a> for VisitorsSingle<F> {
+        fn visit(&self, text: &'a str) -> &'a str {
+            self.f.visit(text)
+        }
+    }
+
+    impl<'a, F: for<'b> Visit<'b>, B: StVisitors> Visit<'a> for Visitors<F, B> {
+        fn visit(&self, text: &'a str) -> &'a str {
+            self.b.visit(self.f.visit(text))
+        }
+    }
+
+    #[test]
+    fn elements_with_higher_ranked_bounds() {
+        let visitors = Visitors::new(Prefix(5)).push(Prefix(3));
+        let text = String::from("abcdefgh");
+        assert_eq!(visitors.visit(&text), "abc");
+    }
+}
+
+mod pipe_separated {
+    use super::*;
+
+    pub trait Name {
+        fn name(&self) -> String;
+    }
+
+    impl Name for u32 {
+        fn name(&self) -> String {
+            format!("u32:{self}")
+        }
+    }
+
+    impl Name for char {
+        fn name(&self) -> String {
+            format!("char:{self}")
+        }
+    }
+
+    crate::define_queue!(
+        elements => [ Name | core::fmt::Debug + PartialEq | Clone ];
+        queue => [ StNames ; NamesSingle, Names ];
+        queue_of => names_of;
+        builder => NamesBuilder;
+    );
+
+    impl<F: Name + Debug + PartialEq + Clone> Name for NamesSingle<F> {
+        fn name(&self) -> String {
+            self.f.name()
+        }
+    }
+
+    impl<F: Name + Debug + PartialEq + Clone, B: StNames> Name for Names<F, B> {
+        fn name(&self) -> String {
+            format!("{} {}", self.f.name(), self.b.name())
+        }
+    }
+
+    #[test]
+    fn elements_with_mixed_separators() {
+        let names = NamesBuilder::<names_of!(u32, char)>::new()
+            .push(42)
+            .push('x')
+            .finish();
+        assert_eq!(names.name(), "u32:42 char:x");
+        assert_eq!(names.clone(), names);
+    }
+}
+
+mod unsized_back {
+    pub trait Weight {
+        fn weight(&self) -> u64;
+    }
+
+    impl Weight for u64 {
+        fn weight(&self) -> u64 {
+            *self
+        }
+    }
+
+    impl Weight for [u64] {
+        fn weight(&self) -> u64 {
+            self.iter().sum()
+        }
+    }
+
+    impl<const N: usize> Weight for [u64; N] {
+        fn weight(&self) -> u64 {
+            self.iter().sum()
+        }
+    }
+
+    crate::define_queue!(
+        lt => ['a];
+        elements => [ Weight + ?Sized ];
+        queue => [ StWeights ; WeightsSingle, Weights ];
+        queue_of => weights_of;
+    );
+
+    impl<'a, F: Weight + ?Sized> Weight for WeightsSingle<'a, F> {
+        fn weight(&self) -> u64 {
+            self.f.weight()
+        }
+    }
+
+    impl<'a, F: Weight, B: Weight + ?Sized> Weight for Weights<'a, F, B> {
+        fn weight(&self) -> u64 {
+            self.f.weight() + self.b.weight()
+        }
+    }
+
+    type Dyn<'a> = Weights<'a, u64, WeightsSingle<'a, dyn Weight>>;
+
+    fn total(weights: &[Box<Dyn<'_>>]) -> u64 {
+        weights.iter().map(|x| x.weight()).sum()
+    }
+
+    type Sized3<'a> = weights_of!(u64, [u64; 2], u64);
+
+    #[test]
+    fn sized_queues_with_unsized_bound() {
+        let weights: Sized3 = WeightsSingle::new(1).push([2, 3]).push(4);
+        assert_eq!(weights.weight(), 10);
+        assert_eq!(weights.len(), 3);
+        assert_eq!(weights.into_back().into_front(), [2, 3]);
+    }
+
+    #[test]
+    fn unsized_back_element() {
+        let weights: Box<Dyn> = Box::new(WeightsSingle::new(1).push(2));
+        assert_eq!(weights.weight(), 3);
+
+        let mixed: Vec<Box<Dyn>> = vec![weights, Box::new(WeightsSingle::new(1).push([2, 3, 4]))];
+        assert_eq!(total(&mixed), 13);
+
+        let mut weights = WeightsSingle::new(1).push([2, 3]);
+        let slices: &mut Weights<u64, WeightsSingle<[u64]>> = &mut weights;
+        slices.back_mut().front_mut()[1] = 5;
+        assert_eq!(slices.front(), &1);
+        assert_eq!(slices.back().front().len(), 2);
+        assert_eq!(slices.weight(), 8);
+    }
+}
@@ -0,0 +1,143 @@
+#![allow(dead_code)]
+
+mod generics_and_elements {
+    pub trait Num: Copy + core::ops::Add<Output = Self> {}
+
+    impl<N: Copy + core::ops::Add<Output = N>> Num for N {}
+
+    pub trait Scale<T> {
+        fn scale(&self, factor: T) -> T;
+    }
+
+    impl Scale<u64> for u64 {
+        fn scale(&self, factor: u64) -> u64 {
+            self * factor
+        }
+    }
+
+    crate::define_queue!(
+        generics => [N: Num];
+        elements => [Scale<N>];
+        queue => [StScales; ScalesSingle, Scales];
+    );
+
+    impl<N: Num, F: Scale<N>> Scale<N> for ScalesSingle<N, F> {
+        fn scale(&self, factor: N) -> N {
+            self.f.scale(factor)
+        }
+    }
+
+    impl<N: Num, F: Scale<N>, B: StScales<N>> Scale<N> for Scales<N, F, B> {
+        fn scale(&self, factor: N) -> N {
+            self.f.scale(factor) + self.b.scale(factor)
+        }
+    }
+
+    #[test]
+    fn generics_without_lifetimes_or_builder() {
+        let scales = Scales::new(1u64).push(2u64).push(3u64);
+        assert_eq!(scales.scale(10), 60);
+    }
+}
+
+mod any_order {
+    crate::define_queue!(
+        builder => PairBuilder;
+        queue_of => pair_of;
+        queue => [StPair; PairSingle, Pair];
+        elements => [Clone];
+        repr => C;
+    );
+
+    #[test]
+    fn options_in_any_order() {
+        let pair = PairBuilder::<pair_of!(u8, String)>::new()
+            .push(1)
+            .push("x".to_string())
+            .finish();
+        assert_eq!(pair.clone().into_tuple(), (1, "x".to_string()));
+        assert_eq!(core::mem::size_of::<pair_of!(u8, u16)>(), 4);
+    }
+}
+
+mod lifetime_without_elements {
+    crate::define_queue!(
+        lt => ['a];
+        queue => [StRefs; RefsSingle, Refs];
+        builder => RefsBuilder;
+    );
+
+    #[test]
+    fn lifetime_with_builder_only() {
+        let text = String::from("abc");
+        let refs: Refs<'_, &str, RefsSingle<'_, usize>> =
+            RefsBuilder::<Refs<'_, &str, RefsSingle<'_, usize>>>::new()
+                .push(text.as_str())
+                .push(text.len())
+                .finish();
+        assert_eq!(refs.into_tuple(), ("abc", 3));
+    }
+}
+
+mod where_clause {
+    use core::ops::Add;
+
+    pub trait Total<N> {
+        fn total(&self) -> N;
+    }
+
+    impl Total<u32> for u32 {
+        fn total(&self) -> u32 {
+            *self
+        }
+    }
+
+    impl Total<u32> for [u32; 2] {
+        fn total(&self) -> u32 {
+            self[0] + self[1]
+        }
+    }
+
+    crate::define_queue!(
+        generics => [N];
+        where => [N: Add<Output = N> + Copy];
+        elements => [Total<N>];
+        queue => [StTotals; TotalsSingle, Totals];
+        queue_of => totals_of;
+        builder => TotalsBuilder;
+    );
+
+    impl<N, F> Total<N> for TotalsSingle<N, F>
+    where
+        N: Add<Output = N> + Copy,
+        F: Total<N>,
+    {
+        fn total(&self) -> N {
+            self.f.total()
+        }
+    }
+
+    impl<N, F, B> Total<N> for Totals<N, F, B>
+    where
+        N: Add<Output = N> + Copy,
+        F: Total<N>,
+        B: StTotals<N>,
+    {
+        fn total(&self) -> N {
+            self.f.total() + self.b.total()
+        }
+    }
+
+    type N = u32;
+
+    #[test]
+    fn where_clause_relating_generic_parameters() {
+        let totals = TotalsBuilder::<u32, totals_of!(u32, [u32; 2], u32)>::new()
+            .push(1)
+            .push([2, 3])
+            .push(4)
+            .finish();
+        assert_eq!(totals.total(), 10);
+        assert_eq!(totals.into_tuple(), (1, [2, 3], 4));
+    }
+}
@@ -0,0 +1,199 @@
+#![allow(dead_code)]
+
+use crate::queue::{DisplayElements, Queue, QueueSingle, ReprCQueue, StQueue};
+use core::fmt::{Debug, Display};
+
+#[derive(Debug)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[test]
+fn flat_debug() {
+    let queue = Queue::new(42).push(true).push('x').push("foo");
+    assert_eq!(format!("{queue:?}"), r#"[42, true, 'x', "foo"]"#);
+    assert_eq!(format!("{:?}", QueueSingle::new(1.5)), "[1.5]");
+
+    let queue = Queue::new(1).push(Point { x: 2, y: 3 });
+    assert_eq!(
+        format!("{queue:#?}"),
+        "[\n    1,\n    Point {\n        x: 2,\n        y: 3,\n    },\n]"
+    );
+
+    let queue = ReprCQueue::new(1u8).push(2u16).push(3u32);
+    assert_eq!(format!("{queue:?}"), "[1, 2, 3]");
+}
+
+#[test]
+fn display_with_separator() {
+    let queue = Queue::new(42).push(true).push('x').push("foo");
+    assert_eq!(queue.to_string(), "42, true, x, foo");
+    assert_eq!(queue.display_with(" | ").to_string(), "42 | true | x | foo");
+    assert_eq!(queue.display_with("").to_string(), "42truexfoo");
+
+    assert_eq!(QueueSingle::new('x').to_string(), "x");
+    assert_eq!(QueueSingle::new('x').display_with("-").to_string(), "x");
+
+    let queue = ReprCQueue::new(1u8).push(2u16);
+    assert_eq!(queue.display_with("; ").to_string(), "1; 2");
+}
+
+mod define_queue_formatting {
+    use super::*;
+
+    pub trait Draw {}
+    impl Draw for u32 {}
+    impl Draw for char {}
+    impl Draw for &str {}
+
+    crate::define_queue!(
+        elements => [Draw];
+        format => [Debug, Display];
+        queue => [StScreen; ScreenSingle, Screen];
+    );
+
+    impl<F: Draw + Debug + Display> Draw for ScreenSingle<F> {}
+    impl<F: Draw + Debug + Display, B: StScreen> Draw for Screen<F, B> {}
+
+    #[test]
+    fn flat_debug_and_display_of_defined_queue() {
+        let screen = ScreenSingle::new(42u32).push('x').push("foo");
+        assert_eq!(format!("{screen:?}"), r#"[42, 'x', "foo"]"#);
+        assert_eq!(
+            format!("{screen:#?}"),
+            "[\n    42,\n    'x',\n    \"foo\",\n]"
+        );
+        assert_eq!(screen.to_string(), "42, x, foo");
+        assert_eq!(screen.display_with(" / ").to_string(), "42 / x / foo");
+    }
+}
+
+mod formatting_elements {
+    crate::define_queue!(
+        format => [Debug, Display];
+        queue => [StLabels; LabelsSingle, Labels];
+    );
+
+    fn describe<Q: StLabels>(queue: &Q) -> String {
+        format!("{queue:?} -> {}", queue.display_with(" "))
+    }
+
+    #[test]
+    fn queues_of_debug_and_display_elements() {
+        let labels = LabelsSingle::new(1).push("two").push('3');
+        assert_eq!(describe(&labels), r#"[1, "two", '3'] -> 1 two 3"#);
+
+        let nested = LabelsSingle::new(0).push(labels);
+        assert_eq!(format!("{nested:?}"), r#"[0, [1, "two", '3']]"#);
+        assert_eq!(nested.to_string(), "0, 1, two, 3");
+    }
+}
+
+mod debug_elements {
+    use super::*;
+
+    crate::define_queue!(
+        elements => [Debug];
+        queue => [StValues; ValuesSingle, Values];
+    );
+
+    fn describe<Q: StValues>(queue: &Q) -> String {
+        format!("{queue:?}")
+    }
+
+    #[test]
+    fn queues_of_debug_elements_without_format() {
+        let values = ValuesSingle::new(1).push("two").push('3');
+        assert_eq!(describe(&values), r#"[1, "two", '3']"#);
+        assert_eq!(format!("{:?}", values.front()), "1");
+    }
+
+    #[test]
+    fn pretty_debug_without_format_matches_base_queues() {
+        let point = || Point { x: 2, y: 3 };
+        let values = ValuesSingle::new(point())
+            .push(Queue::new("a\n[b]").push(' '))
+            .push(Some(4));
+        let base = Queue::new(point())
+            .push(Queue::new("a\n[b]").push(' '))
+            .push(Some(4));
+        assert_eq!(format!("{values:?}"), format!("{base:?}"));
+        assert_eq!(format!("{values:#?}"), format!("{base:#?}"));
+
+        let values = ValuesSingle::new(vec![1, 2]).push(point());
+        assert_eq!(
+            format!("{:#?}", [values]),
+            format!("{:#?}", [Queue::new(vec![1, 2]).push(point())])
+        );
+    }
+}
+
+mod element_trait_named_display {
+    mod ui {
+        pub trait Display {
+            fn render(&self) -> usize;
+        }
+
+        impl Display for u32 {
+            fn render(&self) -> usize {
+                *self as usize
+            }
+        }
+
+        impl Display for &str {
+            fn render(&self) -> usize {
+                self.len()
+            }
+        }
+    }
+
+    use ui::Display;
+
+    crate::define_queue!(
+        elements => [Display];
+        queue => [StWidgets; WidgetsSingle, Widgets];
+    );
+
+    impl<F: Display> Display for WidgetsSingle<F> {
+        fn render(&self) -> usize {
+            self.f.render()
+        }
+    }
+
+    impl<F: Display, B: StWidgets> Display for Widgets<F, B> {
+        fn render(&self) -> usize {
+            self.f.render() + self.b.render()
+        }
+    }
+
+    impl<F: Display + core::fmt::Display> core::fmt::Display for WidgetsSingle<F> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(f, "<{}>", self.f)
+        }
+    }
+
+    #[test]
+    fn elements_bounded_by_user_trait_named_display() {
+        let widgets = WidgetsSingle::new(3u32).push("four");
+        assert_eq!(widgets.render(), 7);
+        assert_eq!(format!("{widgets:?}"), r#"[3, "four"]"#);
+        assert_eq!(WidgetsSingle::new(42u32).to_string(), "<42>");
+    }
+}
+
+mod without_formatting {
+    crate::define_queue!(
+        derive => [Clone, Debug, PartialEq];
+        format => [];
+        queue => [StPlain; PlainSingle, Plain];
+    );
+
+    #[test]
+    fn derived_debug_of_defined_queue() {
+        let queue = PlainSingle::new(1).push('x');
+        let debug = format!("{queue:?}");
+        assert!(debug.starts_with("Plain { f: 1, b: PlainSingle { f: 'x'"));
+        assert_eq!(queue.clone(), queue);
+    }
+}
@@ -0,0 +1,130 @@
+#![allow(dead_code)]
+
+use crate::{
+    queue::{Queue, QueueSingle, ReprCQueue, StQueue},
+    queue_of,
+};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+#[test]
+fn base_queue_ord_is_lexicographic() {
+    let mut queues: [queue_of!(u32, char, bool); 4] = [
+        Queue::new(2).push('a').push(false),
+        Queue::new(1).push('b').push(false),
+        Queue::new(1).push('a').push(true),
+        Queue::new(1).push('a').push(false),
+    ];
+    queues.sort();
+    let tuples: Vec<_> = queues.iter().map(|x| x.into_tuple()).collect();
+    assert_eq!(
+        tuples,
+        [
+            (1, 'a', false),
+            (1, 'a', true),
+            (1, 'b', false),
+            (2, 'a', false)
+        ]
+    );
+
+    assert!(QueueSingle::new(1) < QueueSingle::new(2));
+    assert!(ReprCQueue::new(1).push(9) < ReprCQueue::new(2).push(0));
+}
+
+#[test]
+fn base_queue_as_keys() {
+    let mut map = BTreeMap::new();
+    map.insert(Queue::new("b").push(1), 'x');
+    map.insert(Queue::new("a").push(2), 'y');
+    map.insert(Queue::new("a").push(1), 'z');
+    let values: Vec<_> = map.values().copied().collect();
+    assert_eq!(values, ['z', 'y', 'x']);
+
+    let set: HashSet<_> = [Queue::new(1).push('a'), Queue::new(1).push('a')]
+        .into_iter()
+        .collect();
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn base_queue_default() {
+    type Q = queue_of!(u32, String, Option<char>);
+    assert_eq!(Q::default().into_tuple(), (0, String::new(), None));
+    assert!(!QueueSingle::<bool>::default().into_front());
+}
+
+mod defined_queue {
+    use super::*;
+
+    pub trait Key {}
+    impl Key for u32 {}
+    impl Key for char {}
+
+    crate::define_queue!(
+        elements => [Key];
+        derive => [Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default];
+        queue => [StKeys; KeysSingle, Keys];
+        queue_of => keys_of;
+    );
+
+    impl<F: Key> Key for KeysSingle<F> {}
+    impl<F: Key, B: StKeys> Key for Keys<F, B> {}
+
+    type K = keys_of!(u32, char);
+
+    #[test]
+    fn defined_queue_derives_hash_ord_and_default() {
+        let mut keys = [K::from((2, 'a')), K::from((1, 'b')), K::from((1, 'a'))];
+        keys.sort();
+        let tuples: Vec<_> = keys.iter().map(|x| x.into_tuple()).collect();
+        assert_eq!(tuples, [(1, 'a'), (1, 'b'), (2, 'a')]);
+
+        let set: HashSet<_> = keys.iter().chain(keys.iter()).copied().collect();
+        assert_eq!(set.len(), 3);
+
+        assert_eq!(K::default().into_tuple(), (0, '\0'));
+        assert!(K::default() < keys[0]);
+    }
+}
+
+mod defined_queue_default_derives {
+    use super::*;
+
+    pub trait Id {}
+    impl Id for u32 {}
+    impl Id for &str {}
+
+    crate::define_queue!(
+        elements => [Id];
+        queue => [StIds; IdsSingle, Ids];
+        queue_of => ids_of;
+    );
+
+    impl<F: Id> Id for IdsSingle<F> {}
+    impl<F: Id, B: StIds> Id for Ids<F, B> {}
+
+    type I = ids_of!(&'static str, u32);
+
+    #[test]
+    fn defined_queue_as_keys() {
+        let mut map = BTreeMap::new();
+        map.insert(I::from(("b", 1)), 'x');
+        map.insert(I::from(("a", 2)), 'y');
+        map.insert(I::from(("a", 1)), 'z');
+        let values: Vec<_> = map.values().copied().collect();
+        assert_eq!(values, ['z', 'y', 'x']);
+
+        let mut map = HashMap::new();
+        map.insert(I::from(("a", 1)), 'x');
+        map.insert(I::from(("a", 1)), 'y');
+        map.insert(IdsSingle::new(3).push("c").push(4).into_back(), 'z');
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(&I::from(("a", 1))), Some(&'y'));
+    }
+
+    #[test]
+    fn defined_queue_default() {
+        let ids: I = Default::default();
+        assert_eq!(ids.into_tuple(), ("", 0));
+        assert_eq!(IdsSingle::<u32>::default().into_front(), 0);
+    }
+}
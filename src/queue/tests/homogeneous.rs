@@ -0,0 +1,114 @@
+use crate::{queue::*, queue_of};
+
+#[test]
+fn iter() {
+    let q = QueueSingle::new(42);
+    assert_eq!(q.iter().copied().collect::<Vec<_>>(), vec![42]);
+
+    let q = QueueSingle::new(42).push(7).push(3);
+    assert_eq!(q.iter().copied().collect::<Vec<_>>(), vec![42, 7, 3]);
+    assert_eq!(q.iter().count(), q.len());
+}
+
+#[test]
+fn iter_mut() {
+    let mut q = QueueSingle::new(42).push(7).push(3);
+    for x in q.iter_mut() {
+        *x += 1;
+    }
+    assert_eq!(q.as_tuple(), (&43, &8, &4));
+}
+
+#[test]
+fn get() {
+    let mut q = QueueSingle::new(42);
+    assert_eq!(q.get(0), Some(&42));
+    assert_eq!(q.get(1), None);
+    assert_eq!(q.get_mut(1), None);
+
+    let mut q = QueueSingle::new(42).push(7).push(3);
+    assert_eq!(
+        (q.get(0), q.get(1), q.get(2), q.get(3)),
+        (Some(&42), Some(&7), Some(&3), None)
+    );
+
+    if let Some(x) = q.get_mut(2) {
+        *x = 30;
+    }
+    assert_eq!(q.as_tuple(), (&42, &7, &30));
+}
+
+#[test]
+fn into_iter() {
+    let q = QueueSingle::new("a".to_string())
+        .push("b".to_string())
+        .push("c".to_string());
+
+    let mut joined = String::new();
+    for x in q {
+        joined.push_str(&x);
+    }
+    assert_eq!(joined, "abc");
+}
+
+#[test]
+fn into_array() {
+    let q = QueueSingle::new(42);
+    assert_eq!(q.into_array(), [42]);
+
+    let q = QueueSingle::new(42).push(7).push(3).push(1);
+    assert_eq!(q.into_array(), [42, 7, 3, 1]);
+}
+
+#[test]
+fn into_array_in_generic_code() {
+    fn sum<Q: IntoArray<u32, 3>>(queue: Q) -> u32 {
+        let [a, b, c] = queue.into_array();
+        a + b + c
+    }
+
+    assert_eq!(sum(Queue::new(1).push(2).push(3)), 6);
+}
+
+#[test]
+fn from_array() {
+    let q: Option<queue_of!(u32)> = Homogeneous::from_array([42]);
+    assert_eq!(q.map(|q| q.into_tuple()), Some(42));
+
+    let q: Option<queue_of!(String, String, String)> =
+        Homogeneous::from_array(["a".to_string(), "b".to_string(), "c".to_string()]);
+    assert_eq!(
+        q.map(|q| q.into_tuple()),
+        Some(("a".to_string(), "b".to_string(), "c".to_string()))
+    );
+}
+
+#[test]
+fn from_array_of_different_length() {
+    type Q = queue_of!(u32, u32, u32);
+
+    assert_eq!(Q::from_array([1, 2]), None);
+    assert_eq!(Q::from_array([1, 2, 3, 4]), None);
+    assert_eq!(
+        Q::from_array([1, 2, 3]),
+        Some(Queue::new(1).push(2).push(3))
+    );
+}
+
+#[test]
+fn try_from_iter() {
+    type Q = queue_of!(u32, u32, u32);
+
+    assert_eq!(Q::try_from_iter(&mut [1, 2].into_iter()), None);
+    assert_eq!(
+        Q::try_from_iter(&mut [1, 2, 3].into_iter()),
+        Some(Queue::new(1).push(2).push(3))
+    );
+
+    let mut iter = [1, 2, 3, 4, 5].into_iter();
+    assert_eq!(
+        Q::try_from_iter(&mut iter).map(|q| q.into_array()),
+        Some([1, 2, 3])
+    );
+    assert_eq!(iter.collect::<Vec<_>>(), vec![4, 5]);
+}
@@ -0,0 +1,110 @@
+#![allow(dead_code)]
+
+use crate::{
+    queue::{FromBase, IntoBase, Queue, QueueBuilder, QueueSingle, StQueue},
+    queue_of,
+};
+use core::fmt::Debug;
+
+pub trait Draw {
+    fn draw(&self) -> usize;
+}
+
+impl Draw for u32 {
+    fn draw(&self) -> usize {
+        *self as usize
+    }
+}
+
+impl Draw for char {
+    fn draw(&self) -> usize {
+        1
+    }
+}
+
+crate::define_queue!(
+    elements => [Draw];
+    queue => [StScreen; ScreenSingle, Screen];
+    queue_of => screen_of;
+);
+
+impl<F: Draw> Draw for ScreenSingle<F> {
+    fn draw(&self) -> usize {
+        self.f.draw()
+    }
+}
+
+impl<F: Draw, B: StScreen> Draw for Screen<F, B> {
+    fn draw(&self) -> usize {
+        self.f.draw() + self.b.draw()
+    }
+}
+
+crate::define_queue!(
+    lt => ['a];
+    generics => [C];
+    elements => [Debug];
+    queue => [StViews; ViewsSingle, Views];
+    queue_of => views_of;
+);
+
+type C = ();
+
+#[test]
+fn base_queue_is_its_own_base() {
+    let queue = Queue::new(1).push('x').push(true);
+    let base: queue_of!(i32, char, bool) = queue.into_base();
+    assert_eq!(base, Queue::new(1).push('x').push(true));
+    assert_eq!(
+        <queue_of!(i32, char, bool)>::from_base(base).into_tuple(),
+        (1, 'x', true)
+    );
+
+    let single = QueueSingle::new(42);
+    assert_eq!(single.into_base(), QueueSingle::new(42));
+}
+
+#[test]
+fn define_queue_into_base() {
+    let screen = ScreenSingle::new(3u32).push('x').push(4u32);
+    assert_eq!(screen.into_base().into_tuple(), (3, 'x', 4));
+
+    let base: queue_of!(u32, char, u32) = screen.into();
+    assert_eq!(base.into_tuple(), (3, 'x', 4));
+
+    let base: QueueSingle<char> = ScreenSingle::new('y').into();
+    assert_eq!(base.into_front(), 'y');
+}
+
+#[test]
+fn define_queue_from_base() {
+    type Target = queue_of!(u32, char, u32);
+    let base = QueueBuilder::<Target>::new()
+        .push(3)
+        .push('x')
+        .push(4)
+        .finish();
+
+    let screen = <screen_of!(u32, char, u32)>::from_base(base);
+    assert_eq!(screen, ScreenSingle::new(3u32).push('x').push(4u32));
+
+    let screen: screen_of!(u32, char, u32) = base.into();
+    assert_eq!(screen.draw(), 3 + 1 + 4);
+
+    let screen: ScreenSingle<char> = QueueSingle::new('y').into();
+    assert_eq!(screen.f, 'y');
+}
+
+#[test]
+fn define_queue_round_trip_with_lifetime_and_generics() {
+    type V<'a> = views_of!(&'a str, i32, bool);
+
+    let text = String::from("hello");
+    let base = Queue::new(text.as_str()).push(-1).push(true);
+
+    let views: V<'_> = base.into();
+    assert_eq!(views.f, "hello");
+
+    let base = views.into_base();
+    assert_eq!(base.into_tuple(), ("hello", -1, true));
+}
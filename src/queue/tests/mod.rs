@@ -2,3 +2,21 @@ mod alias_with_queue_of;
 mod as_queue;
 mod as_tuple;
 mod builder;
+mod const_queue;
+mod define_queue_builder;
+mod define_queue_consts;
+mod define_queue_derive;
+mod define_queue_elements;
+mod define_queue_options;
+mod format;
+mod hash_ord_default;
+mod homogeneous;
+mod into_base;
+mod parse;
+mod pipeline;
+mod queue_like;
+mod refs;
+mod repr_c;
+mod slot_builder;
+mod transpose;
+mod zip;
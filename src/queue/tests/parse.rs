@@ -0,0 +1,181 @@
+use crate::{
+    queue::{ParseQueueError, Queue, QueueBuilder, QueueSingle},
+    queue_of,
+};
+use core::char::ParseCharError;
+use core::num::{ParseFloatError, ParseIntError};
+use core::str::ParseBoolError;
+use std::error::Error;
+
+#[derive(Debug, PartialEq)]
+enum FieldError {
+    Int(ParseIntError),
+    Float(ParseFloatError),
+    Bool(ParseBoolError),
+    Char(ParseCharError),
+}
+
+impl From<ParseIntError> for FieldError {
+    fn from(value: ParseIntError) -> Self {
+        Self::Int(value)
+    }
+}
+
+impl From<ParseFloatError> for FieldError {
+    fn from(value: ParseFloatError) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<ParseBoolError> for FieldError {
+    fn from(value: ParseBoolError) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<ParseCharError> for FieldError {
+    fn from(value: ParseCharError) -> Self {
+        Self::Char(value)
+    }
+}
+
+#[test]
+fn try_push_converts_each_slot() {
+    type Q = queue_of!(u8, i16, u32);
+
+    let q = QueueBuilder::<Q>::new()
+        .try_push(1u64)
+        .and_then(|b| b.try_push(-2i32))
+        .and_then(|b| b.try_push(3i64))
+        .map(|b| b.finish());
+    assert_eq!(q.map(|x| x.into_tuple()), Ok((1, -2, 3)));
+
+    let q = QueueBuilder::<queue_of!(char)>::new()
+        .try_push(120u32)
+        .map(|b| b.finish());
+    assert_eq!(q.map(|x| x.into_tuple()), Ok('x'));
+}
+
+#[test]
+fn try_push_reports_failing_slot() {
+    type Q = queue_of!(u8, i16, char);
+
+    let error = QueueBuilder::<Q>::new().try_push(256u32).err();
+    assert_eq!(error.map(|e| (e.position, e.type_name)), Some((0, "u8")));
+
+    let error = QueueBuilder::<Q>::new().push(1).try_push(40000i32).err();
+    assert_eq!(error.map(|e| (e.position, e.type_name)), Some((1, "i16")));
+
+    let error = QueueBuilder::<Q>::new()
+        .push(1)
+        .push(2)
+        .try_push(0xD800u32)
+        .err();
+    assert_eq!(error.map(|e| (e.position, e.type_name)), Some((2, "char")));
+}
+
+#[test]
+fn try_from_iter_parses_all_slots() {
+    type Q = queue_of!(u32, bool, char, f32);
+
+    let q: Result<_, ParseQueueError<FieldError>> =
+        QueueBuilder::<Q>::try_from_iter(["42", "true", "x", "1.5"]);
+    assert_eq!(q.map(|x| x.into_tuple()), Ok((42, true, 'x', 1.5)));
+
+    let q: Result<_, ParseQueueError<ParseIntError>> =
+        QueueBuilder::<queue_of!(u32)>::try_from_iter(["7"]);
+    assert_eq!(q.map(|x| x.into_tuple()), Ok(7));
+}
+
+#[test]
+fn try_from_iter_errors() {
+    type Q = queue_of!(u32, bool, char);
+
+    let e = QueueBuilder::<Q>::try_from_iter(["42", "no", "x"]);
+    let error = "no".parse::<bool>().err().map(FieldError::from);
+    assert_eq!(
+        e.err(),
+        error.map(|error| ParseQueueError::Invalid {
+            position: 1,
+            type_name: "bool",
+            value: "no",
+            error,
+        })
+    );
+
+    let e = QueueBuilder::<Q>::try_from_iter(["42", "true", "xy"]);
+    let error = "xy".parse::<char>().err().map(FieldError::from);
+    assert_eq!(
+        e.err(),
+        error.map(|error| ParseQueueError::Invalid {
+            position: 2,
+            type_name: "char",
+            value: "xy",
+            error,
+        })
+    );
+
+    let e: Result<_, ParseQueueError<FieldError>> = QueueBuilder::<Q>::try_from_iter([]);
+    assert_eq!(
+        e,
+        Err(ParseQueueError::Missing {
+            position: 0,
+            type_name: "u32"
+        })
+    );
+
+    let e: Result<_, ParseQueueError<FieldError>> =
+        QueueBuilder::<Q>::try_from_iter(["42", "true"]);
+    assert_eq!(
+        e,
+        Err(ParseQueueError::Missing {
+            position: 2,
+            type_name: "char"
+        })
+    );
+
+    let e: Result<_, ParseQueueError<FieldError>> =
+        QueueBuilder::<Q>::try_from_iter(["42", "true", "x", "y", "z"]);
+    assert_eq!(
+        e,
+        Err(ParseQueueError::Unexpected {
+            position: 3,
+            value: "y"
+        })
+    );
+    assert_eq!(e.err().map(|x| x.position()), Some(3));
+}
+
+#[test]
+fn parse_queue_error_display() {
+    type Q = queue_of!(u32, bool);
+
+    let e = QueueBuilder::<Q>::try_from_iter::<Box<dyn Error>, _>(["x", "true"]).err();
+    assert_eq!(
+        e.map(|x| x.to_string()).as_deref(),
+        Some(
+            "failed to parse 'x' into the element at position 0 of type u32: invalid digit found in string"
+        )
+    );
+
+    let e = QueueBuilder::<Q>::new().try_push(-1i32).err();
+    assert_eq!(
+        e.map(|x| x.to_string()).as_deref(),
+        Some(
+            "failed to convert the element at position 0 into u32: out of range integral type conversion attempted"
+        )
+    );
+}
+
+#[test]
+fn parse_queue_error_source() {
+    type Q = queue_of!(f32, f64);
+
+    let e = QueueBuilder::<Q>::try_from_iter::<ParseFloatError, _>(["4.2", "y"]).err();
+    assert_eq!(e.as_ref().map(|x| x.position()), Some(1));
+    let source = e.as_ref().and_then(|x| x.source()).map(|x| x.to_string());
+    assert_eq!(source.as_deref(), Some("invalid float literal"));
+
+    let e = QueueBuilder::<Q>::try_from_iter::<ParseFloatError, _>(["4.2"]).err();
+    assert!(e.as_ref().and_then(|x| x.source()).is_none());
+}
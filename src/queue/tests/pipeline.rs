@@ -0,0 +1,64 @@
+use crate::{
+    compose,
+    queue::{Pipeline, Queue, QueueSingle, StQueue},
+};
+
+fn double(x: u64) -> u64 {
+    x * 2
+}
+
+fn describe<P: Pipeline<u64, Out = String>>(pipeline: &P, inputs: &[u64]) -> Vec<String> {
+    inputs.iter().map(|x| pipeline.run(*x)).collect()
+}
+
+#[test]
+fn pipeline_of_single_function() {
+    let pipeline = QueueSingle::new(|x: u32| x + 1);
+    assert_eq!(pipeline.run(1), 2);
+
+    let pipeline = compose!(|x: &str| x.to_uppercase());
+    assert_eq!(pipeline.run("abc"), "ABC");
+}
+
+#[test]
+fn pipeline_built_with_push_and_then() {
+    let pipeline = Queue::new(double)
+        .push(|x: u64| x + 1)
+        .push(|x: u64| x.to_string());
+    assert_eq!(pipeline.len(), 3);
+    assert_eq!(pipeline.run(20), "41");
+
+    let pipeline = QueueSingle::new(double)
+        .then(|x| x + 1)
+        .then(|x| x.to_string());
+    assert_eq!(describe(&pipeline, &[0, 1, 2]), ["1", "3", "5"]);
+}
+
+#[test]
+fn long_pipeline() {
+    let pipeline = compose!(
+        double,
+        double,
+        |x| x + 1,
+        double,
+        |x| x as i64 - 10,
+        |x| x.abs(),
+        |x| x as u64,
+        double,
+        double,
+        |x| x.to_string(),
+        |x| format!("<{x}>"),
+    );
+    assert_eq!(pipeline.len(), 11);
+    assert_eq!(pipeline.run(1), "<0>");
+    assert_eq!(pipeline.run(3), "<64>");
+    assert_eq!(describe(&pipeline, &[0, 2]), ["<32>", "<32>"]);
+}
+
+#[test]
+fn pipeline_borrowing_environment() {
+    let offsets = [10, 20, 30];
+    let pipeline = compose!(|i: usize| offsets[i], |x| x + offsets.len());
+    assert_eq!(pipeline.run(0), 13);
+    assert_eq!(pipeline.run(2), 33);
+}
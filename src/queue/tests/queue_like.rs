@@ -0,0 +1,99 @@
+#![allow(dead_code)]
+
+use crate::queue::{Queue, QueueLike, QueueSingle, ReprCQueue, ReprCQueueSingle, StQueue};
+
+fn type_names<Q: QueueLike>(names: &mut Vec<&'static str>) {
+    names.push(core::any::type_name::<Q::Front>());
+    if Q::LEN > 1 {
+        type_names::<Q::Back>(names);
+    }
+}
+
+fn fronts<Q>(queue: &Q, values: &mut Vec<u32>)
+where
+    Q: QueueLike,
+    Q::Front: Copy + Into<u32>,
+    <Q::Back as QueueLike>::Front: Copy + Into<u32>,
+{
+    values.push((*queue.front()).into());
+    if let Some(back) = queue.back() {
+        values.push((*back.front()).into());
+    }
+}
+
+crate::define_queue!(
+    elements => [ Copy ];
+    queue => [ StCopies; CopiesSingle, Copies ];
+    queue_of => copies_of;
+);
+
+crate::define_queue!(
+    lt => ['a];
+    generics => [C];
+    queue => [ StCtx; CtxSingle, Ctx ];
+);
+
+#[test]
+fn queue_like_base() {
+    assert_eq!(<QueueSingle<u8> as QueueLike>::LEN, 1);
+    assert_eq!(
+        <Queue<u8, Queue<char, QueueSingle<bool>>> as QueueLike>::LEN,
+        3
+    );
+
+    let mut names = vec![];
+    type_names::<Queue<u32, Queue<char, QueueSingle<bool>>>>(&mut names);
+    assert_eq!(names, ["u32", "char", "bool"]);
+
+    let queue = Queue::new(1u8).push(2u16);
+    let mut values = vec![];
+    fronts(&queue, &mut values);
+    assert_eq!(values, [1, 2]);
+
+    let (front, back) = QueueLike::pop(queue);
+    assert_eq!(front, 1);
+    assert_eq!(back.map(|x| x.into_front()), Some(2));
+
+    let single = QueueSingle::new('x');
+    assert!(QueueLike::back(&single).is_none());
+    assert_eq!(QueueLike::front(&single), &'x');
+    assert_eq!(QueueLike::pop(single), ('x', None));
+}
+
+#[test]
+fn queue_like_repr_c() {
+    type Q = ReprCQueue<u32, ReprCQueue<char, ReprCQueueSingle<bool>>>;
+    assert_eq!(<Q as QueueLike>::LEN, 3);
+
+    let mut names = vec![];
+    type_names::<Q>(&mut names);
+    assert_eq!(names, ["u32", "char", "bool"]);
+
+    let queue = ReprCQueue::new(3u8).push(4u16);
+    let mut values = vec![];
+    fronts(&queue, &mut values);
+    assert_eq!(values, [3, 4]);
+}
+
+#[test]
+fn queue_like_define_queue() {
+    type Q = copies_of!(u32, char, bool);
+    assert_eq!(<Q as QueueLike>::LEN, 3);
+
+    let mut names = vec![];
+    type_names::<Q>(&mut names);
+    assert_eq!(names, ["u32", "char", "bool"]);
+
+    let queue = CopiesSingle::new(5u8).push(6u16);
+    let mut values = vec![];
+    fronts(&queue, &mut values);
+    assert_eq!(values, [5, 6]);
+
+    let (front, back) = QueueLike::pop(queue);
+    assert_eq!(front, 5);
+    assert_eq!(back.map(|x| x.into_front()), Some(6));
+
+    let mut names = vec![];
+    type_names::<Ctx<'static, usize, &'static str, CtxSingle<'static, usize, i64>>>(&mut names);
+    assert_eq!(names, ["&str", "i64"]);
+}
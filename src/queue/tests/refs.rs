@@ -0,0 +1,166 @@
+#![allow(dead_code)]
+
+use crate::queue::{AsMuts, AsRefs, Cloned, Copied, Queue, ReprCQueue, StQueue};
+
+#[test]
+fn as_refs_of_long_queue() {
+    let queue = Queue::new(0u8)
+        .push(1u16)
+        .push(2u32)
+        .push(3u64)
+        .push(4i8)
+        .push(5i16)
+        .push(6i32)
+        .push(7i64)
+        .push('8')
+        .push(String::from("9"));
+    assert_eq!(queue.len(), 10);
+
+    let refs = queue.as_refs();
+    assert_eq!(refs.len(), 10);
+    assert_eq!(refs.front(), &&0);
+    assert_eq!(
+        refs.back()
+            .back()
+            .back()
+            .back()
+            .back()
+            .back()
+            .back()
+            .back()
+            .back()
+            .front(),
+        &&String::from("9")
+    );
+
+    let cloned = refs.cloned();
+    assert_eq!(cloned, queue);
+}
+
+#[test]
+fn as_muts_and_copied() {
+    let mut queue = Queue::new(1).push(2.5).push('x');
+
+    let muts = queue.as_muts();
+    let (a, muts) = muts.pop();
+    *a += 10;
+    let (b, c) = muts.pop();
+    *b *= 2.0;
+    *c.pop() = 'y';
+
+    let copied = queue.as_refs().copied();
+    assert_eq!(copied, queue);
+    assert_eq!(copied.pop().0, 11);
+}
+
+#[test]
+fn repr_c_refs() {
+    let mut queue = ReprCQueue::new(1u32).push(String::from("a"));
+
+    let refs = queue.as_refs();
+    assert_eq!(refs.front, &1);
+    assert_eq!(refs.cloned(), queue);
+
+    let muts = queue.as_muts();
+    *muts.front = 2;
+    muts.back.front.push('b');
+    assert_eq!(queue, ReprCQueue::new(2).push(String::from("ab")));
+}
+
+mod defined_queue {
+    use super::*;
+
+    pub trait Area {
+        fn area(&self) -> f64;
+    }
+
+    impl<T: Area> Area for &T {
+        fn area(&self) -> f64 {
+            (*self).area()
+        }
+    }
+
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    pub struct Square(f64);
+
+    impl Area for Square {
+        fn area(&self) -> f64 {
+            self.0 * self.0
+        }
+    }
+
+    crate::define_queue!(
+        elements => [Area];
+        queue => [StAreas; AreasSingle, Areas];
+        queue_of => areas_of;
+    );
+
+    impl<F: Area> Area for AreasSingle<F> {
+        fn area(&self) -> f64 {
+            self.f.area()
+        }
+    }
+
+    impl<F: Area, B: StAreas> Area for Areas<F, B> {
+        fn area(&self) -> f64 {
+            self.f.area() + self.b.area()
+        }
+    }
+
+    fn total<Q: StAreas>(queue: Q) -> f64 {
+        queue.area()
+    }
+
+    #[test]
+    fn defined_queue_refs() {
+        let queue = AreasSingle::new(Square(1.0))
+            .push(Square(2.0))
+            .push(Square(3.0));
+
+        let refs: areas_of!(&Square, &Square, &Square) = queue.as_refs();
+        assert_eq!(total(refs) as u32, 14);
+
+        assert_eq!(refs.cloned(), queue);
+        assert_eq!(refs.copied(), queue);
+    }
+
+    crate::define_queue!(
+        lt => ['a];
+        generics => [C];
+        elements => [core::fmt::Debug];
+        queue => [StViews; ViewsSingle, Views];
+    );
+
+    #[test]
+    fn defined_queue_muts_with_lifetime_and_generics() {
+        let mut queue: Views<'static, (), u32, ViewsSingle<'static, (), String>> =
+            ViewsSingle::new(1).push(String::from("a"));
+
+        let muts = queue.as_muts();
+        let (a, b) = muts.pop();
+        *a = 2;
+        b.into_front().push('b');
+
+        assert_eq!(format!("{:?}", queue.as_refs()), r#"[2, "ab"]"#);
+    }
+}
+
+mod defined_queue_with_lifetime_q {
+    use crate::queue::{AsRefs, Cloned};
+
+    crate::define_queue!(
+        lt => ['q];
+        queue => [StBorrows; BorrowsSingle, Borrows];
+    );
+
+    #[test]
+    fn defined_queue_refs_with_lifetime_named_q() {
+        let text = String::from("abc");
+        let queue: Borrows<'_, u32, BorrowsSingle<'_, &str>> =
+            BorrowsSingle::new(7).push(text.as_str());
+
+        let refs = queue.as_refs();
+        assert_eq!(refs.into_tuple(), (&7, &"abc"));
+        assert_eq!(refs.cloned(), queue);
+    }
+}
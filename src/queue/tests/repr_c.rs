@@ -0,0 +1,86 @@
+use crate::{assert_layout_matches, queue::*};
+use core::mem::{align_of, offset_of, size_of};
+
+#[repr(C)]
+struct Vertex {
+    x: i32,
+    y: i32,
+    z: i32,
+    id: u32,
+}
+
+type VertexQueue = ReprCQueue<i32, ReprCQueue<i32, ReprCQueue<i32, ReprCQueueSingle<u32>>>>;
+
+assert_layout_matches!(VertexQueue, Vertex { x, y, z, id });
+
+#[test]
+fn push_and_pop() {
+    let q = ReprCQueue::new(1i32);
+    assert_eq!(q.len(), 1);
+
+    let q: VertexQueue = q.push(2).push(3).push(42);
+    assert_eq!(q.len(), 4);
+    assert_eq!(q.front(), &1);
+    assert_eq!(q.back.back.back.front, 42);
+
+    let (x, q) = q.pop();
+    assert_eq!(x, 1);
+    assert_eq!(q, ReprCQueue::new(2).push(3).push(42));
+
+    let (y, q) = q.pop();
+    assert_eq!(y, 2);
+    let (z, q) = q.pop();
+    assert_eq!(z, 3);
+    assert_eq!(q.pop(), 42);
+}
+
+#[test]
+fn layout() {
+    assert_eq!(size_of::<VertexQueue>(), size_of::<Vertex>());
+    assert_eq!(align_of::<VertexQueue>(), align_of::<Vertex>());
+    assert_eq!(offset_of!(VertexQueue, front), offset_of!(Vertex, x));
+    assert_eq!(offset_of!(VertexQueue, back.front), offset_of!(Vertex, y));
+    assert_eq!(
+        offset_of!(VertexQueue, back.back.front),
+        offset_of!(Vertex, z)
+    );
+    assert_eq!(
+        offset_of!(VertexQueue, back.back.back.front),
+        offset_of!(Vertex, id)
+    );
+}
+
+#[allow(dead_code)]
+mod rgb {
+    crate::define_queue!(
+        repr => C;
+        queue => [ StRgb ; RgbSingle, Rgb ];
+        queue_of => rgb_of;
+    );
+
+    #[repr(C)]
+    pub struct Color {
+        r: u8,
+        g: u8,
+        b: u8,
+    }
+
+    crate::assert_layout_matches!(rgb_of!(u8, u8, u8) { f, b }, Color { r, g, b });
+
+    pub fn offsets() -> [usize; 3] {
+        type Q = rgb_of!(u8, u8, u8);
+        [
+            core::mem::offset_of!(Q, f),
+            core::mem::offset_of!(Q, b.f),
+            core::mem::offset_of!(Q, b.b.f),
+        ]
+    }
+}
+
+#[test]
+fn layout_defined_queue() {
+    use rgb::StRgb;
+    let q = rgb::Rgb::new(1u8).push(2u8).push(3u8);
+    assert_eq!(size_of_val(&q), size_of::<rgb::Color>());
+    assert_eq!(rgb::offsets(), [0, 1, 2]);
+}
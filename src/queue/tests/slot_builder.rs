@@ -0,0 +1,58 @@
+use crate::queue::*;
+use crate::record::Record;
+use crate::{queue_of, record_of};
+
+#[test]
+fn set_by_type_in_any_order() {
+    type Q1 = queue_of!(u32);
+    let q = QueueSlotBuilder::<Q1>::new().set::<u32, _>(42).finish();
+    assert_eq!(q.into_tuple(), 42);
+
+    type Q3 = queue_of!(u32, char, bool);
+    let q = QueueSlotBuilder::<Q3>::new()
+        .set::<bool, _>(true)
+        .set::<u32, _>(42)
+        .set::<char, _>('x')
+        .finish();
+    assert_eq!(q.into_tuple(), (42, 'x', true));
+
+    let q = QueueSlotBuilder::<Q3>::default()
+        .set::<char, _>('y')
+        .set::<bool, _>(false)
+        .set::<u32, _>(7)
+        .finish();
+    assert_eq!(q.into_tuple(), (7, 'y', false));
+}
+
+#[test]
+fn set_by_name() {
+    struct Capacity;
+    struct Workers;
+    struct Label;
+
+    type Config = record_of!(Capacity: usize, Workers: usize, Label: String);
+
+    let config: Config = QueueSlotBuilder::<Config>::new()
+        .set::<Label, _>("x".to_string())
+        .set::<Workers, _>(4)
+        .set::<Capacity, _>(16)
+        .finish();
+
+    assert_eq!(config.get::<Capacity>(), &16);
+    assert_eq!(config.get::<Workers>(), &4);
+    assert_eq!(config.get::<Label>(), "x");
+}
+
+#[test]
+fn set_by_type_and_name() {
+    struct Port;
+
+    type Q = Queue<crate::record::Field<Port, u16>, QueueSingle<String>>;
+
+    let q = QueueSlotBuilder::<Q>::new()
+        .set::<String, _>("localhost".to_string())
+        .set::<Port, _>(8080)
+        .finish();
+    assert_eq!(q.get::<Port>(), &8080);
+    assert_eq!(q.back().front(), "localhost");
+}
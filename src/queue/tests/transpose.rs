@@ -0,0 +1,81 @@
+use crate::{
+    queue::{Queue, QueueBuilder, QueueSingle, StQueue, TransposeOption, TransposeResult},
+    queue_of,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum StageError {
+    Parse(usize),
+    Validate(usize),
+}
+
+#[test]
+fn transpose_option() {
+    let queue = Queue::new(Some(1)).push(Some('x')).push(Some("y"));
+    assert_eq!(
+        queue.transpose_option().map(|x| x.into_tuple()),
+        Some((1, 'x', "y"))
+    );
+
+    let queue = Queue::new(Some(1)).push(Some('x')).push(None::<&str>);
+    assert_eq!(queue.transpose_option(), None);
+
+    assert_eq!(
+        QueueSingle::new(Some(3)).transpose_option(),
+        Some(QueueSingle::new(3))
+    );
+    assert_eq!(QueueSingle::new(None::<u8>).transpose_option(), None);
+}
+
+#[test]
+fn transpose_result_short_circuits() {
+    type Q = queue_of!(Result<u32, StageError>, Result<char, StageError>, Result<bool, StageError>);
+
+    let queue: Q = QueueBuilder::<Q>::new()
+        .push(Ok(1))
+        .push(Ok('x'))
+        .push(Ok(true))
+        .finish();
+    assert_eq!(
+        queue.transpose_result().map(|x| x.into_tuple()),
+        Ok((1, 'x', true))
+    );
+
+    let queue: Q = QueueBuilder::<Q>::new()
+        .push(Ok(1))
+        .push(Err(StageError::Parse(1)))
+        .push(Err(StageError::Validate(2)))
+        .finish();
+    assert_eq!(queue.transpose_result(), Err(StageError::Parse(1)));
+
+    let single = QueueSingle::new(Err::<u8, _>(StageError::Validate(0)));
+    assert_eq!(single.transpose_result(), Err(StageError::Validate(0)));
+}
+
+#[test]
+fn transpose_result_accumulates_errors() {
+    let queue = Queue::new(Err::<u32, _>(StageError::Parse(0)))
+        .push(Ok('x'))
+        .push(Err::<i64, _>(StageError::Validate(2)))
+        .push(Ok(true))
+        .push(Err::<&str, _>(StageError::Parse(4)));
+
+    let result = queue.transpose_result_all::<Vec<_>>();
+    assert_eq!(
+        result,
+        Err(vec![
+            StageError::Parse(0),
+            StageError::Validate(2),
+            StageError::Parse(4)
+        ])
+    );
+
+    let mut errors = vec![StageError::Parse(99)];
+    let queue = Queue::new(Ok::<_, StageError>(1)).push(Err::<char, _>(StageError::Parse(1)));
+    assert_eq!(queue.transpose_result_into(&mut errors), None);
+    assert_eq!(errors, [StageError::Parse(99), StageError::Parse(1)]);
+
+    let queue = Queue::new(Ok::<_, StageError>(1)).push(Ok('x'));
+    let result = queue.transpose_result_all::<Vec<_>>();
+    assert_eq!(result.map(|x| x.into_tuple()), Ok((1, 'x')));
+}
@@ -0,0 +1,94 @@
+use crate::queue::*;
+
+#[test]
+fn zip() {
+    let q = QueueSingle::new(42).zip(QueueSingle::new('x'));
+    assert_eq!(q.into_tuple(), (42, 'x'));
+
+    let a = QueueSingle::new(42).push('x').push(true);
+    let b = QueueSingle::new("foo").push(1.5).push(7u8);
+    let q = a.zip(b);
+    assert_eq!(q.len(), 3);
+    assert_eq!(q.into_tuple(), ((42, "foo"), ('x', 1.5), (true, 7u8)));
+}
+
+#[test]
+fn unzip() {
+    let (a, b) = QueueSingle::new((42, 'x')).unzip();
+    assert_eq!(a.into_tuple(), 42);
+    assert_eq!(b.into_tuple(), 'x');
+
+    let q = QueueSingle::new((42, "foo"))
+        .push(('x', 1.5))
+        .push((true, 7u8));
+    let (a, b) = q.unzip();
+    assert_eq!(a.into_tuple(), (42, 'x', true));
+    assert_eq!(b.into_tuple(), ("foo", 1.5, 7u8));
+}
+
+#[test]
+fn zip_unzip_round_trip() {
+    let a = QueueSingle::new(1).push("two".to_string()).push(3.0);
+    let b = QueueSingle::new('a').push(vec![2]).push(Some(3));
+    let (a2, b2) = a.clone().zip(b.clone()).unzip();
+    assert_eq!(a2, a);
+    assert_eq!(b2, b);
+}
+
+#[allow(dead_code)]
+mod weights {
+    pub trait Weight {
+        fn weight(&self) -> u32;
+    }
+
+    impl Weight for u32 {
+        fn weight(&self) -> u32 {
+            *self
+        }
+    }
+
+    impl Weight for char {
+        fn weight(&self) -> u32 {
+            *self as u32
+        }
+    }
+
+    impl<X: Weight, Y: Weight> Weight for (X, Y) {
+        fn weight(&self) -> u32 {
+            self.0.weight() + self.1.weight()
+        }
+    }
+
+    crate::define_queue!(
+        elements => [ Weight ];
+        queue => [ StWeights ; WeightsSingle, Weights ];
+    );
+
+    impl<F: Weight> Weight for WeightsSingle<F> {
+        fn weight(&self) -> u32 {
+            self.f.weight()
+        }
+    }
+
+    impl<F: Weight, B: StWeights> Weight for Weights<F, B> {
+        fn weight(&self) -> u32 {
+            self.f.weight() + self.b.weight()
+        }
+    }
+}
+
+#[test]
+fn zip_defined_queue() {
+    use weights::*;
+
+    let a = Weights::new(1u32).push('a').push(3u32);
+    let b = Weights::new('b').push(2u32).push(4u32);
+
+    let zipped = a.zip(b);
+    assert_eq!(zipped.weight(), 1 + 98 + 97 + 2 + 3 + 4);
+    assert_eq!(zipped.as_tuple(), (&(1, 'b'), &('a', 2), &(3, 4)));
+
+    let (a, b) = zipped.unzip();
+    assert_eq!(a.into_tuple(), (1, 'a', 3));
+    assert_eq!(b.into_tuple(), ('b', 2, 4));
+}
@@ -0,0 +1,163 @@
+use crate::queue::{Queue, QueueSingle, StQueue};
+
+/// Transposes a queue of `Option`s into an `Option` of a queue.
+///
+/// Transposing `Queue<Option<A>, Queue<Option<B>, QueueSingle<Option<C>>>>` gives us
+/// `Option<Queue<A, Queue<B, QueueSingle<C>>>>`, which is `Some` if all elements are `Some`, and `None` otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::queue::*;
+///
+/// let queue = Queue::new(Some(42)).push(Some('x')).push(Some(true));
+/// let transposed = queue.transpose_option();
+/// assert_eq!(transposed, Some(Queue::new(42).push('x').push(true)));
+///
+/// let queue = Queue::new(Some(42)).push(None::<char>).push(Some(true));
+/// assert_eq!(queue.transpose_option(), None);
+/// ```
+pub trait TransposeOption {
+    /// Type of the queue of the values of the options.
+    type Output;
+
+    /// Returns the queue of the values if all elements are `Some`; None otherwise.
+    fn transpose_option(self) -> Option<Self::Output>;
+}
+
+/// Transposes a queue of `Result`s with a common error type `E` into a `Result` of a queue.
+///
+/// Transposing `Queue<Result<A, E>, Queue<Result<B, E>, QueueSingle<Result<C, E>>>>` gives us
+/// `Result<Queue<A, Queue<B, QueueSingle<C>>>, E>`.
+///
+/// * [`transpose_result`] short-circuits and returns the first error from front to back.
+/// * [`transpose_result_all`] returns all errors from front to back collected into any `Default + Extend<E>`
+///   collection, such as a `Vec<E>`.
+///
+/// [`transpose_result`]: TransposeResult::transpose_result
+/// [`transpose_result_all`]: TransposeResult::transpose_result_all
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::queue::*;
+///
+/// let queue = Queue::new("42".parse::<u32>()).push("7".parse::<u32>());
+/// assert_eq!(queue.transpose_result(), Ok(Queue::new(42).push(7)));
+///
+/// let queue = Queue::new(Ok::<u32, &str>(42))
+///     .push(Err::<bool, _>("b"))
+///     .push(Ok('x'))
+///     .push(Err::<f32, _>("d"));
+///
+/// assert_eq!(queue.clone().transpose_result(), Err("b"));
+///
+/// let errors = queue.transpose_result_all::<Vec<_>>();
+/// assert_eq!(errors, Err(vec!["b", "d"]));
+/// ```
+pub trait TransposeResult<E>: Sized {
+    /// Type of the queue of the values of the results.
+    type Output;
+
+    /// Returns the queue of the values if all elements are `Ok`; the first error otherwise.
+    fn transpose_result(self) -> Result<Self::Output, E>;
+
+    /// Returns the queue of the values if all elements are `Ok`; otherwise, adds all errors to `errors`
+    /// from front to back and returns None.
+    fn transpose_result_into<C: Extend<E>>(self, errors: &mut C) -> Option<Self::Output>;
+
+    /// Returns the queue of the values if all elements are `Ok`; the collection of all errors otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::queue::*;
+    ///
+    /// let queue = Queue::new(Err::<u32, _>('a')).push(Ok(true)).push(Err::<u8, _>('c'));
+    /// let errors: Result<_, String> = queue.transpose_result_all();
+    /// assert_eq!(errors, Err(String::from("ac")));
+    ///
+    /// let queue = Queue::new(Ok::<_, char>(1)).push(Ok(true));
+    /// let values: Result<_, String> = queue.transpose_result_all();
+    /// assert_eq!(values, Ok(Queue::new(1).push(true)));
+    /// ```
+    fn transpose_result_all<C: Default + Extend<E>>(self) -> Result<Self::Output, C> {
+        let mut errors = C::default();
+        self.transpose_result_into(&mut errors).ok_or(errors)
+    }
+}
+
+// single
+
+impl<F> TransposeOption for QueueSingle<Option<F>> {
+    type Output = QueueSingle<F>;
+
+    #[inline(always)]
+    fn transpose_option(self) -> Option<Self::Output> {
+        self.front.map(QueueSingle::new)
+    }
+}
+
+impl<F, E> TransposeResult<E> for QueueSingle<Result<F, E>> {
+    type Output = QueueSingle<F>;
+
+    #[inline(always)]
+    fn transpose_result(self) -> Result<Self::Output, E> {
+        self.front.map(QueueSingle::new)
+    }
+
+    #[inline(always)]
+    fn transpose_result_into<C: Extend<E>>(self, errors: &mut C) -> Option<Self::Output> {
+        match self.front {
+            Ok(f) => Some(QueueSingle::new(f)),
+            Err(e) => {
+                errors.extend(core::iter::once(e));
+                None
+            }
+        }
+    }
+}
+
+// multi
+
+impl<F, B> TransposeOption for Queue<Option<F>, B>
+where
+    B: StQueue + TransposeOption,
+    B::Output: StQueue,
+{
+    type Output = Queue<F, B::Output>;
+
+    #[inline(always)]
+    fn transpose_option(self) -> Option<Self::Output> {
+        let (f, b) = self.pop();
+        Some(Queue::from_fb(f?, b.transpose_option()?))
+    }
+}
+
+impl<F, B, E> TransposeResult<E> for Queue<Result<F, E>, B>
+where
+    B: StQueue + TransposeResult<E>,
+    B::Output: StQueue,
+{
+    type Output = Queue<F, B::Output>;
+
+    #[inline(always)]
+    fn transpose_result(self) -> Result<Self::Output, E> {
+        let (f, b) = self.pop();
+        Ok(Queue::from_fb(f?, b.transpose_result()?))
+    }
+
+    #[inline(always)]
+    fn transpose_result_into<C: Extend<E>>(self, errors: &mut C) -> Option<Self::Output> {
+        let (f, b) = self.pop();
+        let f = match f {
+            Ok(f) => Some(f),
+            Err(e) => {
+                errors.extend(core::iter::once(e));
+                None
+            }
+        };
+        let b = b.transpose_result_into(errors);
+        Some(Queue::from_fb(f?, b?))
+    }
+}
@@ -0,0 +1,134 @@
+use crate::queue::{Queue, QueueSingle, StQueue};
+
+/// Elementwise zipping of two statically-typed queues of equal lengths.
+///
+/// Zipping `Queue<A1, Queue<A2, QueueSingle<A3>>>` with `Queue<B1, Queue<B2, QueueSingle<B3>>>`
+/// gives us the queue of pairs `Queue<(A1, B1), Queue<(A2, B2), QueueSingle<(A3, B3)>>>`.
+///
+/// The trait is implemented only for queues of equal lengths; therefore, attempting to zip
+/// queues of different lengths does not compile.
+///
+/// Zipping is reverted by [`Unzip`].
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::queue::*;
+///
+/// let names = Queue::new("home").push("about").push("login");
+/// let widths = Queue::new(3).push(5.0).push('x');
+///
+/// let zipped = names.zip(widths);
+/// assert_eq!(zipped.as_tuple(), (&("home", 3), &("about", 5.0), &("login", 'x')));
+///
+/// let (names, widths) = zipped.unzip();
+/// assert_eq!(names.as_tuple(), (&"home", &"about", &"login"));
+/// assert_eq!(widths.as_tuple(), (&3, &5.0, &'x'));
+/// ```
+///
+/// Lengths of the zipped queues must be equal.
+///
+/// ```compile_fail
+/// use orx_meta::queue::*;
+///
+/// let names = Queue::new("home").push("about").push("login");
+/// let widths = Queue::new(3).push(5.0);
+///
+/// let zipped = names.zip(widths);
+/// ```
+pub trait Zip<Other> {
+    /// Type of the queue of pairs obtained by zipping this queue with `Other`.
+    type Zipped;
+
+    /// Zips this queue with the `other` queue of the same length, and returns the queue of
+    /// pairs of elements at the same positions.
+    fn zip(self, other: Other) -> Self::Zipped;
+}
+
+/// Elementwise unzipping of a statically-typed queue of pairs into two queues of equal lengths.
+///
+/// Unzipping `Queue<(A1, B1), Queue<(A2, B2), QueueSingle<(A3, B3)>>>` gives us the pair of
+/// queues `Queue<A1, Queue<A2, QueueSingle<A3>>>` and `Queue<B1, Queue<B2, QueueSingle<B3>>>`.
+///
+/// This is the inverse of [`Zip`].
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::queue::*;
+///
+/// let queue = Queue::new((1, 'a')).push((true, "b"));
+///
+/// let (left, right) = queue.unzip();
+/// assert_eq!(left.as_tuple(), (&1, &true));
+/// assert_eq!(right.as_tuple(), (&'a', &"b"));
+/// ```
+pub trait Unzip {
+    /// Type of the queue of first elements of the pairs.
+    type Left;
+
+    /// Type of the queue of second elements of the pairs.
+    type Right;
+
+    /// Unzips this queue of pairs into the queue of first elements and the queue of second elements.
+    fn unzip(self) -> (Self::Left, Self::Right);
+}
+
+// single
+
+impl<F, G> Zip<QueueSingle<G>> for QueueSingle<F> {
+    type Zipped = QueueSingle<(F, G)>;
+
+    #[inline(always)]
+    fn zip(self, other: QueueSingle<G>) -> Self::Zipped {
+        QueueSingle::new((self.front, other.front))
+    }
+}
+
+impl<A, B> Unzip for QueueSingle<(A, B)> {
+    type Left = QueueSingle<A>;
+
+    type Right = QueueSingle<B>;
+
+    #[inline(always)]
+    fn unzip(self) -> (Self::Left, Self::Right) {
+        let (a, b) = self.front;
+        (QueueSingle::new(a), QueueSingle::new(b))
+    }
+}
+
+// multi
+
+impl<F, B, G, C> Zip<Queue<G, C>> for Queue<F, B>
+where
+    B: StQueue + Zip<C>,
+    C: StQueue,
+    B::Zipped: StQueue,
+{
+    type Zipped = Queue<(F, G), B::Zipped>;
+
+    #[inline(always)]
+    fn zip(self, other: Queue<G, C>) -> Self::Zipped {
+        let (f, b) = self.pop();
+        let (g, c) = other.pop();
+        Queue::from_fb((f, g), b.zip(c))
+    }
+}
+
+impl<A, B, Q> Unzip for Queue<(A, B), Q>
+where
+    Q: StQueue + Unzip,
+    Q::Left: StQueue,
+    Q::Right: StQueue,
+{
+    type Left = Queue<A, Q::Left>;
+
+    type Right = Queue<B, Q::Right>;
+
+    #[inline(always)]
+    fn unzip(self) -> (Self::Left, Self::Right) {
+        let ((a, b), q) = self.pop();
+        let (left, right) = q.unzip();
+        (Queue::from_fb(a, left), Queue::from_fb(b, right))
+    }
+}
@@ -0,0 +1,128 @@
+use core::marker::PhantomData;
+
+/// A value of type `T` tagged with the field name `Name`.
+///
+/// Field names are zero-sized marker types, such as `struct Port;`, which are never instantiated.
+/// A queue of fields is a record; i.e., an incremental ad-hoc struct whose elements can be accessed
+/// by their names rather than their positions. See [`HasField`] for details.
+///
+/// [`HasField`]: crate::record::HasField
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::record::*;
+///
+/// struct Port;
+///
+/// let mut port = Field::<Port, _>::new(8080);
+/// assert_eq!(port.value(), &8080);
+///
+/// *port.value_mut() += 1;
+/// assert_eq!(port.into_value(), 8081);
+/// ```
+pub struct Field<Name, T> {
+    value: T,
+    name: PhantomData<fn() -> Name>,
+}
+
+impl<Name, T> Field<Name, T> {
+    /// Creates a new field with the given `value`.
+    #[inline(always)]
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            name: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the value of the field.
+    #[inline(always)]
+    pub const fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns a mutable reference to the value of the field.
+    #[inline(always)]
+    pub const fn value_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+
+    /// Consumes the field and returns its value.
+    #[inline(always)]
+    pub fn into_value(self) -> T {
+        self.value
+    }
+
+    /// Returns the name of the field, which is the type name of `Name`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::record::*;
+    ///
+    /// struct Port;
+    ///
+    /// let port = Field::<Port, _>::new(8080);
+    /// assert!(port.name().ends_with("Port"));
+    /// ```
+    #[inline(always)]
+    pub fn name(&self) -> &'static str {
+        core::any::type_name::<Name>()
+    }
+}
+
+impl<Name, T: Clone> Clone for Field<Name, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<Name, T: Copy> Copy for Field<Name, T> {}
+
+impl<Name, T: PartialEq> PartialEq for Field<Name, T> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<Name, T: Eq> Eq for Field<Name, T> {}
+
+impl<Name, T: PartialOrd> PartialOrd for Field<Name, T> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<Name, T: Ord> Ord for Field<Name, T> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<Name, T: core::hash::Hash> core::hash::Hash for Field<Name, T> {
+    #[inline(always)]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl<Name, T: Default> Default for Field<Name, T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<Name, T: core::fmt::Debug> core::fmt::Debug for Field<Name, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Field")
+            .field("name", &self.name())
+            .field("value", &self.value)
+            .finish()
+    }
+}
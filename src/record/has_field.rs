@@ -0,0 +1,198 @@
+use crate::queue::{Queue, QueueSingle, StQueue};
+use crate::record::Field;
+use crate::type_list::{Here, There};
+
+/// A record, or a queue of [`Field`]s, which has a field with the name `Name`.
+///
+/// The second generic parameter `I` is an index witness, either [`Here`] or [`There`], which
+/// lets the compiler find the position of the field without overlapping implementations. It is
+/// always inferred, and is written as `_` in use sites.
+///
+/// Fields are conveniently accessed by the `field` and `field_mut` methods of the [`Record`]
+/// extension trait, such as `record.get::<Name>()`, where the index witness does not appear.
+/// Accessing a field which does not exist in the record does not compile.
+///
+/// Notice that the index witness can be inferred only if the field name appears exactly once in
+/// the record. Otherwise, the inference is ambiguous and the code does not compile.
+///
+/// [`Here`]: crate::type_list::Here
+/// [`There`]: crate::type_list::There
+/// [`Record`]: crate::record::Record
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::record::*;
+/// use orx_meta::record;
+///
+/// struct Host;
+/// struct Port;
+/// struct Verbose;
+///
+/// let mut config = record! {
+///     Host: "localhost",
+///     Port: 8080,
+///     Verbose: false,
+/// };
+///
+/// assert_eq!(config.get::<Host>(), &"localhost");
+/// assert_eq!(config.get::<Port>(), &8080);
+///
+/// *config.get_mut::<Verbose>() = true;
+/// assert_eq!(config.get::<Verbose>(), &true);
+/// ```
+///
+/// Accessing a field which does not exist does not compile.
+///
+/// ```compile_fail
+/// use orx_meta::record::*;
+/// use orx_meta::record;
+///
+/// struct Host;
+/// struct Port;
+/// struct Timeout;
+///
+/// let config = record! { Host: "localhost", Port: 8080 };
+/// let timeout = config.get::<Timeout>();
+/// ```
+pub trait HasField<Name, I> {
+    /// Type of the value of the field.
+    type Value;
+
+    /// Returns a reference to the value of the field `Name`.
+    fn field(&self) -> &Self::Value;
+
+    /// Returns a mutable reference to the value of the field `Name`.
+    fn field_mut(&mut self) -> &mut Self::Value;
+}
+
+impl<Name, T> HasField<Name, Here> for QueueSingle<Field<Name, T>> {
+    type Value = T;
+
+    #[inline(always)]
+    fn field(&self) -> &T {
+        self.front().value()
+    }
+
+    #[inline(always)]
+    fn field_mut(&mut self) -> &mut T {
+        self.front_mut().value_mut()
+    }
+}
+
+impl<Name, T, B> HasField<Name, Here> for Queue<Field<Name, T>, B>
+where
+    B: StQueue,
+{
+    type Value = T;
+
+    #[inline(always)]
+    fn field(&self) -> &T {
+        self.front().value()
+    }
+
+    #[inline(always)]
+    fn field_mut(&mut self) -> &mut T {
+        self.front_mut().value_mut()
+    }
+}
+
+impl<Name, F, B, I> HasField<Name, There<I>> for Queue<F, B>
+where
+    B: StQueue + HasField<Name, I>,
+{
+    type Value = B::Value;
+
+    #[inline(always)]
+    fn field(&self) -> &Self::Value {
+        self.back().field()
+    }
+
+    #[inline(always)]
+    fn field_mut(&mut self) -> &mut Self::Value {
+        self.back_mut().field_mut()
+    }
+}
+
+/// Extension methods accessing the fields of records by their names.
+///
+/// The generic parameter `I` is the index witness of [`HasField`]. Since it is a parameter of the
+/// trait rather than of its methods, it is inferred at method calls and fields are accessed
+/// only by their names, such as `record.get::<Name>()`.
+///
+/// This trait is automatically implemented for all statically-typed queues.
+///
+/// Notice that a record with a single field is also a [`Homogeneous`] queue, which has its own
+/// `get` method by index. When both traits are in scope, such as by glob importing both the `queue`
+/// and `record` modules, the call is ambiguous for single field records. Then, the traits can be
+/// imported in separate scopes, or the field can be accessed by `Record::get::<Name>(&record)`.
+///
+/// [`Homogeneous`]: crate::queue::Homogeneous
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::record::*;
+/// use orx_meta::record;
+///
+/// struct Host;
+/// struct Port;
+///
+/// let mut config = record! { Host: "localhost", Port: 8080 };
+///
+/// *config.get_mut::<Port>() += 1;
+/// assert_eq!(config.get::<Host>(), &"localhost");
+/// assert_eq!(config.get::<Port>(), &8081);
+///
+/// let port = record! { Port: 80 };
+/// assert_eq!(Record::get::<Port>(&port), &80);
+/// ```
+pub trait Record<I>: StQueue {
+    /// Returns a reference to the value of the field with the given `Name` of this record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::record::*;
+    /// use orx_meta::record;
+    ///
+    /// struct Host;
+    /// struct Port;
+    ///
+    /// let config = record! { Host: "localhost", Port: 8080 };
+    /// assert_eq!(config.get::<Host>(), &"localhost");
+    /// assert_eq!(config.get::<Port>(), &8080);
+    /// ```
+    #[inline(always)]
+    fn get<Name>(&self) -> &<Self as HasField<Name, I>>::Value
+    where
+        Self: HasField<Name, I>,
+    {
+        self.field()
+    }
+
+    /// Returns a mutable reference to the value of the field with the given `Name` of this record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::record::*;
+    /// use orx_meta::record;
+    ///
+    /// struct Host;
+    /// struct Port;
+    ///
+    /// let mut config = record! { Host: "localhost", Port: 8080 };
+    /// *config.get_mut::<Host>() = "127.0.0.1";
+    /// assert_eq!(config.get::<Host>(), &"127.0.0.1");
+    /// ```
+    #[inline(always)]
+    fn get_mut<Name>(&mut self) -> &mut <Self as HasField<Name, I>>::Value
+    where
+        Self: HasField<Name, I>,
+    {
+        self.field_mut()
+    }
+}
+
+impl<Q: StQueue, I> Record<I> for Q {}
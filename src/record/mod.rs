@@ -0,0 +1,9 @@
+#[cfg(test)]
+mod tests;
+
+mod field;
+mod has_field;
+mod record_macro;
+
+pub use field::Field;
+pub use has_field::{HasField, Record};
@@ -0,0 +1,95 @@
+/// Creates a record, which is a statically-typed queue of named [`Field`]s, with the given
+/// `Name: value` pairs.
+///
+/// Field names are types, usually zero-sized marker types such as `struct Port;`, which must be in scope.
+///
+/// Values of the fields can then be accessed by their names, such as `record.get::<Port>()`;
+/// see [`Record`] and [`HasField`] for details. The type of a record can be written by the [`record_of`] macro.
+///
+/// [`Field`]: crate::record::Field
+/// [`HasField`]: crate::record::HasField
+/// [`Record`]: crate::record::Record
+/// [`record_of`]: crate::record_of
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::record::*;
+/// use orx_meta::{record, record_of};
+///
+/// struct Host;
+/// struct Port;
+/// struct Retries;
+///
+/// type Config = record_of!(Host: String, Port: u16, Retries: usize);
+///
+/// let config: Config = record! {
+///     Host: "localhost".to_string(),
+///     Port: 8080,
+///     Retries: 3,
+/// };
+///
+/// assert_eq!(config.get::<Host>(), "localhost");
+/// assert_eq!(config.get::<Port>(), &8080);
+/// assert_eq!(config.get::<Retries>(), &3);
+/// ```
+#[macro_export]
+macro_rules! record {
+    (@push $queue:expr ;) => {
+        $queue
+    };
+
+    (@push $queue:expr ; $name:ty : $value:expr $(, $rest_name:ty : $rest_value:expr)*) => {
+        $crate::record!(
+            @push $crate::queue::StQueue::push($queue, $crate::record::Field::<$name, _>::new($value)) ;
+            $($rest_name : $rest_value),*
+        )
+    };
+
+    ($name:ty : $value:expr $(, $rest_name:ty : $rest_value:expr)* $(,)?) => {
+        $crate::record!(
+            @push $crate::queue::QueueSingle::new($crate::record::Field::<$name, _>::new($value)) ;
+            $($rest_name : $rest_value),*
+        )
+    };
+}
+
+/// Creates the type of the record, which is a statically-typed queue of named [`Field`]s, with the given
+/// `Name: Type` pairs.
+///
+/// For instance, `record_of!(Host: String, Port: u16)` is equivalent to
+/// `Queue<Field<Host, String>, QueueSingle<Field<Port, u16>>>`.
+///
+/// Records can be created by the [`record`] macro.
+///
+/// [`Field`]: crate::record::Field
+/// [`record`]: crate::record!
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::queue::*;
+/// use orx_meta::record::*;
+/// use orx_meta::{record, record_of};
+///
+/// struct Host;
+/// struct Port;
+///
+/// type Config = record_of!(Host: String, Port: u16);
+///
+/// let config: Queue<Field<Host, String>, QueueSingle<Field<Port, u16>>> = record! {
+///     Host: "localhost".to_string(),
+///     Port: 8080,
+/// };
+/// let config: Config = config;
+/// ```
+#[macro_export]
+macro_rules! record_of {
+    ($name:ty : $t:ty $(,)?) => {
+        $crate::queue::QueueSingle<$crate::record::Field<$name, $t>>
+    };
+
+    ($name:ty : $t:ty, $($rest_name:ty : $rest_t:ty),+ $(,)?) => {
+        $crate::queue::Queue<$crate::record::Field<$name, $t>, $crate::record_of!($($rest_name : $rest_t),+)>
+    };
+}
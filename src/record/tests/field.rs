@@ -0,0 +1,67 @@
+use crate::queue::{Homogeneous, StQueue};
+use crate::record::*;
+use crate::{record, record_of};
+use std::collections::{BTreeMap, HashSet};
+
+struct Host;
+struct Port;
+
+type Address = record_of!(Host: &'static str, Port: u16);
+
+fn address(host: &'static str, port: u16) -> Address {
+    record! { Host: host, Port: port }
+}
+
+#[test]
+fn fields_as_keys() {
+    let mut map = BTreeMap::new();
+    map.insert(Field::<Port, u16>::new(3), 'x');
+    map.insert(Field::new(1), 'y');
+    map.insert(Field::new(2), 'z');
+    let values: Vec<_> = map.values().copied().collect();
+    assert_eq!(values, ['y', 'z', 'x']);
+
+    let set: HashSet<_> = [Field::<Host, _>::new("a"), Field::new("a"), Field::new("b")]
+        .into_iter()
+        .collect();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn field_default() {
+    let field = Field::<Port, u16>::default();
+    assert_eq!(field.value(), &0);
+    assert!(field < Field::new(1));
+}
+
+#[test]
+fn records_as_keys() {
+    let mut map = BTreeMap::new();
+    map.insert(address("b", 1), 'x');
+    map.insert(address("a", 2), 'y');
+    map.insert(address("a", 1), 'z');
+    let values: Vec<_> = map.values().copied().collect();
+    assert_eq!(values, ['z', 'y', 'x']);
+
+    let set: HashSet<_> = [address("a", 1), address("a", 1), address("a", 2)]
+        .into_iter()
+        .collect();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn record_default() {
+    let record = Address::default();
+    assert_eq!(record.get::<Host>(), &"");
+    assert_eq!(record.get::<Port>(), &0);
+    assert!(record < address("", 1));
+}
+
+#[test]
+fn single_field_record_with_homogeneous_in_scope() {
+    let mut record = record! { Port: 8080u16 };
+    *Record::get_mut::<Port>(&mut record) += 1;
+    assert_eq!(Record::get::<Port>(&record), &8081);
+    assert_eq!(Homogeneous::get(&record, 0).map(|x| *x.value()), Some(8081));
+    assert_eq!(record.len(), 1);
+}
@@ -0,0 +1,89 @@
+use crate::queue::{QueueSingle, StQueue};
+use crate::record::*;
+use crate::{record, record_of};
+
+struct Host;
+struct Port;
+struct Retries;
+struct Verbose;
+
+type Config = record_of!(Host: String, Port: u16, Retries: usize, Verbose: bool);
+
+fn config() -> Config {
+    record! {
+        Host: "localhost".to_string(),
+        Port: 8080,
+        Retries: 3,
+        Verbose: false,
+    }
+}
+
+#[test]
+fn record_of_single() {
+    let record: record_of!(Port: u16) = record! { Port: 8080 };
+    let record: QueueSingle<Field<Port, u16>> = record;
+    assert_eq!(record.len(), 1);
+    assert_eq!(record.get::<Port>(), &8080);
+}
+
+#[test]
+fn get() {
+    let config = config();
+    assert_eq!(config.len(), 4);
+    assert_eq!(config.get::<Host>(), "localhost");
+    assert_eq!(config.get::<Port>(), &8080);
+    assert_eq!(config.get::<Retries>(), &3);
+    assert_eq!(config.get::<Verbose>(), &false);
+}
+
+#[test]
+fn get_mut() {
+    let mut config = config();
+    config.get_mut::<Host>().push_str(":80");
+    *config.get_mut::<Port>() = 80;
+    *config.get_mut::<Retries>() += 1;
+    *config.get_mut::<Verbose>() = true;
+
+    assert_eq!(config.get::<Host>(), "localhost:80");
+    assert_eq!(config.get::<Port>(), &80);
+    assert_eq!(config.get::<Retries>(), &4);
+    assert_eq!(config.get::<Verbose>(), &true);
+}
+
+#[test]
+fn generic_access() {
+    fn port<R: HasField<Port, I, Value = u16>, I>(record: &R) -> u16 {
+        *record.field()
+    }
+
+    assert_eq!(port(&config()), 8080);
+    assert_eq!(port(&record! { Port: 42u16 }), 42);
+    assert_eq!(port(&record! { Port: 42u16, Host: "x" }), 42);
+}
+
+#[test]
+fn generic_access_by_name() {
+    fn retry<R: Record<I> + HasField<Retries, I, Value = usize>, I>(record: &mut R) -> usize {
+        *record.get_mut::<Retries>() += 1;
+        *record.get::<Retries>()
+    }
+
+    let mut config = config();
+    assert_eq!(retry(&mut config), 4);
+    assert_eq!(config.get::<Retries>(), &4);
+
+    let mut record = record! { Retries: 0usize };
+    assert_eq!(retry(&mut record), 1);
+}
+
+#[test]
+fn record_is_a_queue() {
+    let config = config().push(Field::<&str, _>::new(1.5));
+    assert_eq!(config.len(), 5);
+    assert_eq!(config.front().value(), "localhost");
+    assert!(config.front().name().ends_with("Host"));
+
+    let (host, config) = config.pop();
+    assert_eq!(host.into_value(), "localhost");
+    assert_eq!(config.get::<Port>(), &8080);
+}
@@ -0,0 +1,2 @@
+mod field;
+mod has_field;
@@ -0,0 +1,56 @@
+use crate::tree::{StTree, SubTree, TreeLeaf, TreeNode};
+
+/// A statically-typed tree containing no elements.
+///
+/// This is the starting point of building an [`StTree`] by pushing elements.
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::tree::*;
+///
+/// let tree = TreeEmpty::new();
+/// assert_eq!(tree.len(), 0);
+///
+/// let tree = TreeEmpty::new().push(42).push(true).push('x');
+/// assert_eq!(tree.len(), 3);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct TreeEmpty;
+
+impl TreeEmpty {
+    /// Creates a new empty tree.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl SubTree for TreeEmpty {
+    const LEN: usize = 0;
+
+    const DEPTH: usize = 0;
+
+    type CarriedInto<H: StTree, T: SubTree> = TreeNode<H, T>;
+
+    #[inline(always)]
+    fn carried_into<H: StTree, T: SubTree>(self, left: H, tree: T) -> Self::CarriedInto<H, T> {
+        TreeNode::from_lr(left, tree)
+    }
+}
+
+impl StTree for TreeEmpty {
+    type PushBack<Elem> = Self::Carry<TreeLeaf<Elem>>;
+
+    type Carry<T: SubTree> = TreeNode<TreeEmpty, T>;
+
+    #[inline(always)]
+    fn push<Elem>(self, element: Elem) -> Self::PushBack<Elem> {
+        self.carry(TreeLeaf::new(element))
+    }
+
+    #[inline(always)]
+    fn carry<T: SubTree>(self, tree: T) -> Self::Carry<T> {
+        TreeNode::from_lr(self, tree)
+    }
+}
@@ -0,0 +1,146 @@
+use crate::tree::{SubTree, TreeEmpty, TreeLeaf, TreeNode};
+
+/// A marker trait for statically-typed trees whose elements all have the same type `T`.
+///
+/// Elements of homogeneous trees can be visited one by one in the order they are pushed.
+///
+/// Notice that every [`TreeEmpty`] is homogeneous for any `T`, every [`TreeLeaf<T>`] is homogeneous,
+/// and a [`TreeNode<L, R>`] is homogeneous whenever both of its subtrees are homogeneous with the
+/// same element type `T`.
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::tree::*;
+///
+/// let mut tree = TreeEmpty::new().push(1).push(2).push(3).push(4).push(5);
+///
+/// let mut sum = 0;
+/// tree.for_each(|x| sum += x);
+/// assert_eq!(sum, 15);
+///
+/// tree.for_each_mut(|x| *x *= 10);
+///
+/// let mut elements = vec![];
+/// tree.for_each(|x| elements.push(*x));
+/// assert_eq!(elements, [10, 20, 30, 40, 50]);
+/// ```
+pub trait HomogeneousTree<T>: SubTree {
+    /// Calls the closure `f` on references to the elements of the tree in the order they are pushed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::tree::*;
+    ///
+    /// let tree = TreeEmpty::new().push('a').push('b').push('c');
+    ///
+    /// let mut s = String::new();
+    /// tree.for_each(|c| s.push(*c));
+    /// assert_eq!(s, "abc");
+    /// ```
+    fn for_each<F>(&self, mut f: F)
+    where
+        F: FnMut(&T),
+    {
+        self.for_each_ref(&mut f);
+    }
+
+    /// Calls the closure `f` on mutable references to the elements of the tree in the order they are pushed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::tree::*;
+    ///
+    /// let mut tree = TreeEmpty::new().push(1).push(2).push(3);
+    /// tree.for_each_mut(|x| *x += 1);
+    ///
+    /// let mut elements = vec![];
+    /// tree.for_each(|x| elements.push(*x));
+    /// assert_eq!(elements, [2, 3, 4]);
+    /// ```
+    fn for_each_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        self.for_each_mut_ref(&mut f);
+    }
+
+    /// Calls the closure `f` on references to the elements of the tree in the order they are pushed.
+    ///
+    /// *This is the building block of [`for_each`] which allows recursion by a reference to the closure.*
+    ///
+    /// [`for_each`]: crate::tree::HomogeneousTree::for_each
+    fn for_each_ref<F>(&self, f: &mut F)
+    where
+        F: FnMut(&T);
+
+    /// Calls the closure `f` on mutable references to the elements of the tree in the order they are pushed.
+    ///
+    /// *This is the building block of [`for_each_mut`] which allows recursion by a reference to the closure.*
+    ///
+    /// [`for_each_mut`]: crate::tree::HomogeneousTree::for_each_mut
+    fn for_each_mut_ref<F>(&mut self, f: &mut F)
+    where
+        F: FnMut(&mut T);
+}
+
+impl<T> HomogeneousTree<T> for TreeEmpty {
+    #[inline(always)]
+    fn for_each_ref<F>(&self, _: &mut F)
+    where
+        F: FnMut(&T),
+    {
+    }
+
+    #[inline(always)]
+    fn for_each_mut_ref<F>(&mut self, _: &mut F)
+    where
+        F: FnMut(&mut T),
+    {
+    }
+}
+
+impl<T> HomogeneousTree<T> for TreeLeaf<T> {
+    #[inline(always)]
+    fn for_each_ref<F>(&self, f: &mut F)
+    where
+        F: FnMut(&T),
+    {
+        f(self.element())
+    }
+
+    #[inline(always)]
+    fn for_each_mut_ref<F>(&mut self, f: &mut F)
+    where
+        F: FnMut(&mut T),
+    {
+        f(self.element_mut())
+    }
+}
+
+impl<T, L, R> HomogeneousTree<T> for TreeNode<L, R>
+where
+    L: HomogeneousTree<T>,
+    R: HomogeneousTree<T>,
+{
+    #[inline(always)]
+    fn for_each_ref<F>(&self, f: &mut F)
+    where
+        F: FnMut(&T),
+    {
+        self.left().for_each_ref(f);
+        self.right().for_each_ref(f);
+    }
+
+    #[inline(always)]
+    fn for_each_mut_ref<F>(&mut self, f: &mut F)
+    where
+        F: FnMut(&mut T),
+    {
+        let (left, right) = self.left_right_mut();
+        left.for_each_mut_ref(f);
+        right.for_each_mut_ref(f);
+    }
+}
@@ -0,0 +1,58 @@
+use crate::tree::{StTree, SubTree, TreeEmpty, TreeNode};
+
+/// A statically-typed tree containing exactly one element of type `T`.
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::tree::*;
+///
+/// let mut leaf = TreeLeaf::new(42);
+/// assert_eq!(leaf.element(), &42);
+///
+/// *leaf.element_mut() += 1;
+/// assert_eq!(leaf.into_element(), 43);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TreeLeaf<T> {
+    element: T,
+}
+
+impl<T> TreeLeaf<T> {
+    /// Creates a new leaf containing the `element`.
+    #[inline(always)]
+    pub const fn new(element: T) -> Self {
+        Self { element }
+    }
+
+    /// Returns a reference to the element of the leaf.
+    #[inline(always)]
+    pub const fn element(&self) -> &T {
+        &self.element
+    }
+
+    /// Returns a mutable reference to the element of the leaf.
+    #[inline(always)]
+    pub const fn element_mut(&mut self) -> &mut T {
+        &mut self.element
+    }
+
+    /// Consumes the leaf and returns its element.
+    #[inline(always)]
+    pub fn into_element(self) -> T {
+        self.element
+    }
+}
+
+impl<T> SubTree for TreeLeaf<T> {
+    const LEN: usize = 1;
+
+    const DEPTH: usize = 1;
+
+    type CarriedInto<H: StTree, U: SubTree> = TreeNode<H::Carry<TreeNode<Self, U>>, TreeEmpty>;
+
+    #[inline(always)]
+    fn carried_into<H: StTree, U: SubTree>(self, left: H, tree: U) -> Self::CarriedInto<H, U> {
+        TreeNode::from_lr(left.carry(TreeNode::from_lr(self, tree)), TreeEmpty)
+    }
+}
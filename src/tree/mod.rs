@@ -0,0 +1,16 @@
+#[cfg(test)]
+mod tests;
+
+mod empty;
+mod homogeneous;
+mod leaf;
+mod node;
+mod st_tree;
+mod sub_tree;
+
+pub use empty::TreeEmpty;
+pub use homogeneous::HomogeneousTree;
+pub use leaf::TreeLeaf;
+pub use node::TreeNode;
+pub use st_tree::StTree;
+pub use sub_tree::SubTree;
@@ -0,0 +1,122 @@
+use crate::tree::{StTree, SubTree, TreeEmpty, TreeLeaf};
+
+/// A statically-typed tree containing the elements of its `Left` subtree followed by the
+/// elements of its `Right` subtree.
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::tree::*;
+///
+/// let tree = TreeNode::from_lr(TreeLeaf::new(42), TreeLeaf::new('x'));
+/// assert_eq!(tree.len(), 2);
+/// assert_eq!(tree.left().element(), &42);
+/// assert_eq!(tree.right().element(), &'x');
+///
+/// let (left, right) = tree.into_lr();
+/// assert_eq!(left.into_element(), 42);
+/// assert_eq!(right.into_element(), 'x');
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TreeNode<Left, Right>
+where
+    Left: SubTree,
+    Right: SubTree,
+{
+    left: Left,
+    right: Right,
+}
+
+impl<L, R> TreeNode<L, R>
+where
+    L: SubTree,
+    R: SubTree,
+{
+    /// Creates a new node with the given `left` and `right` subtrees.
+    #[inline(always)]
+    pub const fn from_lr(left: L, right: R) -> Self {
+        Self { left, right }
+    }
+
+    /// Returns a reference to the left subtree.
+    #[inline(always)]
+    pub const fn left(&self) -> &L {
+        &self.left
+    }
+
+    /// Returns a reference to the right subtree.
+    #[inline(always)]
+    pub const fn right(&self) -> &R {
+        &self.right
+    }
+
+    /// Returns a mutable reference to the left subtree.
+    #[inline(always)]
+    pub const fn left_mut(&mut self) -> &mut L {
+        &mut self.left
+    }
+
+    /// Returns a mutable reference to the right subtree.
+    #[inline(always)]
+    pub const fn right_mut(&mut self) -> &mut R {
+        &mut self.right
+    }
+
+    /// Returns a pair of mutable references to the left and right subtrees.
+    #[inline(always)]
+    pub const fn left_right_mut(&mut self) -> (&mut L, &mut R) {
+        (&mut self.left, &mut self.right)
+    }
+
+    /// Consumes the node and returns the tuple of its left and right subtrees.
+    #[inline(always)]
+    pub fn into_lr(self) -> (L, R) {
+        (self.left, self.right)
+    }
+}
+
+impl<L, R> SubTree for TreeNode<L, R>
+where
+    L: SubTree,
+    R: SubTree,
+{
+    const LEN: usize = L::LEN + R::LEN;
+
+    const DEPTH: usize = 1 + match L::DEPTH > R::DEPTH {
+        true => L::DEPTH,
+        false => R::DEPTH,
+    };
+
+    type CarriedInto<H: StTree, T: SubTree> = TreeNode<H::Carry<TreeNode<Self, T>>, TreeEmpty>;
+
+    #[inline(always)]
+    fn carried_into<H: StTree, T: SubTree>(self, left: H, tree: T) -> Self::CarriedInto<H, T> {
+        TreeNode::from_lr(left.carry(TreeNode::from_lr(self, tree)), TreeEmpty)
+    }
+}
+
+// The tree is a binary counter of perfect subtrees: the left subtree holds the larger and older
+// subtrees, while the right subtree is either empty or the smallest and newest perfect subtree.
+// Carrying a subtree into a non-empty right subtree of the same size merges them and carries the
+// merged subtree further to the left.
+
+impl<H, D> StTree for TreeNode<H, D>
+where
+    H: StTree,
+    D: SubTree,
+{
+    type PushBack<Elem> = Self::Carry<TreeLeaf<Elem>>;
+
+    type Carry<T: SubTree> = D::CarriedInto<H, T>;
+
+    #[inline(always)]
+    fn push<Elem>(self, element: Elem) -> Self::PushBack<Elem> {
+        self.carry(TreeLeaf::new(element))
+    }
+
+    #[inline(always)]
+    fn carry<T: SubTree>(self, tree: T) -> Self::Carry<T> {
+        let (left, right) = self.into_lr();
+        right.carried_into(left, tree)
+    }
+}
@@ -0,0 +1,123 @@
+use crate::tree::SubTree;
+
+/// A statically-typed tree of heterogeneous elements which is built by pushing elements to its back.
+///
+/// It provides the same functionality as the statically-typed queue [`StQueue`] with a different
+/// representation. A queue with n elements is a right-nested type with a nesting depth of n, such as
+/// `Queue<A, Queue<B, Queue<C, QueueSingle<D>>>>`. Compile times, error messages and the recursion
+/// limit become a concern as n gets large.
+///
+/// On the other hand, the tree is kept as a binary counter of perfect binary subtrees. Pushing an
+/// element is analogous to incrementing the counter, where subtrees of equal sizes are merged.
+/// Therefore, nesting depth of the tree grows logarithmically with its length. The compile times of
+/// both representations can be compared by building the `compile_queue_200` and `compile_tree_200`
+/// bench targets with `--timings`, as described in the latter.
+///
+/// There exist two implementations:
+/// * [`TreeEmpty`] which is the starting point containing no elements, and
+/// * [`TreeNode`] whose left subtree is the tree of older elements and right subtree is either
+///   empty or the perfect subtree of the most recent elements.
+///
+/// Every tree is also a [`SubTree`]; and elements are ordered by in-order traversal, which is the
+/// order they are pushed.
+///
+/// [`StQueue`]: crate::queue::StQueue
+/// [`TreeEmpty`]: crate::tree::TreeEmpty
+/// [`TreeNode`]: crate::tree::TreeNode
+///
+/// # Examples
+///
+/// Composition of heterogeneous elements is achieved by implementing the common behavior for
+/// the three building blocks of trees.
+///
+/// ```
+/// use orx_meta::tree::*;
+///
+/// pub trait Draw {
+///     fn draw(&self, out: &mut Vec<String>);
+/// }
+///
+/// struct Button(&'static str);
+/// impl Draw for Button {
+///     fn draw(&self, out: &mut Vec<String>) {
+///         out.push(format!("button:{}", self.0));
+///     }
+/// }
+///
+/// struct Label(&'static str);
+/// impl Draw for Label {
+///     fn draw(&self, out: &mut Vec<String>) {
+///         out.push(format!("label:{}", self.0));
+///     }
+/// }
+///
+/// // identity
+/// impl Draw for TreeEmpty {
+///     fn draw(&self, _: &mut Vec<String>) {}
+/// }
+///
+/// // element
+/// impl<D: Draw> Draw for TreeLeaf<D> {
+///     fn draw(&self, out: &mut Vec<String>) {
+///         self.element().draw(out);
+///     }
+/// }
+///
+/// // composition
+/// impl<L: SubTree + Draw, R: SubTree + Draw> Draw for TreeNode<L, R> {
+///     fn draw(&self, out: &mut Vec<String>) {
+///         self.left().draw(out);
+///         self.right().draw(out);
+///     }
+/// }
+///
+/// let screen = TreeEmpty::new()
+///     .push(Button("home"))
+///     .push(Label("hello"))
+///     .push(Button("about"))
+///     .push(Label("world"))
+///     .push(Button("login"));
+///
+/// let mut out = vec![];
+/// screen.draw(&mut out);
+/// assert_eq!(
+///     out,
+///     ["button:home", "label:hello", "button:about", "label:world", "button:login"]
+/// );
+/// ```
+pub trait StTree: SubTree {
+    /// Type of the tree obtained by adding an element of type `Elem` to this tree.
+    type PushBack<Elem>: StTree;
+
+    /// Type of the tree obtained by carrying the subtree `T` into this tree, which must be a perfect
+    /// binary subtree with the same size as the most recent perfect subtree of this tree, if any.
+    ///
+    /// *This is the building block of [`push`]; and it is not intended to be used directly.*
+    ///
+    /// [`push`]: crate::tree::StTree::push
+    type Carry<T: SubTree>: StTree;
+
+    /// Pushes the `element` and returns the resulting tree.
+    ///
+    /// *Type of the resulting tree is known by the generic associated type `Self::PushBack<Elem>`.*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::tree::*;
+    ///
+    /// let tree = TreeEmpty::new().push(42);
+    /// assert_eq!(tree.len(), 1);
+    ///
+    /// let tree = tree.push(true).push('x');
+    /// assert_eq!(tree.len(), 3);
+    /// ```
+    fn push<Elem>(self, element: Elem) -> Self::PushBack<Elem>;
+
+    /// Carries the perfect binary subtree `tree` into this tree.
+    ///
+    /// *This is the building block of [`push`]; and it is not intended to be used directly.*
+    ///
+    /// [`push`]: crate::tree::StTree::push
+    fn carry<T: SubTree>(self, tree: T) -> Self::Carry<T>;
+}
@@ -0,0 +1,86 @@
+use crate::tree::StTree;
+
+/// A statically-typed binary tree of heterogeneous elements, which is one of:
+/// * [`TreeEmpty`] containing no elements,
+/// * [`TreeLeaf`] containing exactly one element, or
+/// * [`TreeNode`] containing the elements of its left subtree followed by those of its right subtree.
+///
+/// Elements of the tree are ordered by in-order traversal; i.e., elements of the left subtree
+/// come before those of the right subtree.
+///
+/// Subtrees are the building blocks of the [`StTree`] which is built by pushing elements one by one.
+///
+/// [`TreeEmpty`]: crate::tree::TreeEmpty
+/// [`TreeLeaf`]: crate::tree::TreeLeaf
+/// [`TreeNode`]: crate::tree::TreeNode
+/// [`StTree`]: crate::tree::StTree
+pub trait SubTree {
+    /// Number of elements in the tree.
+    const LEN: usize;
+
+    /// Nesting depth of the tree type, which is 0 for an empty tree and 1 for a leaf.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::tree::*;
+    ///
+    /// assert_eq!(TreeEmpty::DEPTH, 0);
+    /// assert_eq!(TreeLeaf::<u32>::DEPTH, 1);
+    /// assert_eq!(TreeNode::<TreeLeaf<u32>, TreeEmpty>::DEPTH, 2);
+    /// ```
+    const DEPTH: usize;
+
+    /// Type of the tree obtained by carrying the subtree `T` into the tree `TreeNode<H, Self>`.
+    ///
+    /// *This is the building block of [`push`]; and it is not intended to be used directly.*
+    ///
+    /// [`push`]: crate::tree::StTree::push
+    type CarriedInto<H: StTree, T: SubTree>: StTree;
+
+    /// Carries the subtree `tree` into the tree `TreeNode<H, Self>` with the given `left` tree and
+    /// this subtree on the right.
+    ///
+    /// *This is the building block of [`push`]; and it is not intended to be used directly.*
+    ///
+    /// [`push`]: crate::tree::StTree::push
+    fn carried_into<H: StTree, T: SubTree>(self, left: H, tree: T) -> Self::CarriedInto<H, T>;
+
+    /// Returns the number of elements in the tree.
+    ///
+    /// *This method is provided for convention. Length of the tree is actually known by the constant `Self::LEN`.*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::tree::*;
+    ///
+    /// let tree = TreeEmpty::new();
+    /// assert_eq!(tree.len(), 0);
+    ///
+    /// let tree = tree.push(42).push(true).push('x');
+    /// assert_eq!(tree.len(), 3);
+    /// ```
+    #[inline(always)]
+    fn len(&self) -> usize {
+        Self::LEN
+    }
+
+    /// Returns true if the tree does not contain any elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::tree::*;
+    ///
+    /// let tree = TreeEmpty::new();
+    /// assert!(tree.is_empty());
+    ///
+    /// let tree = tree.push(42);
+    /// assert!(!tree.is_empty());
+    /// ```
+    #[inline(always)]
+    fn is_empty(&self) -> bool {
+        Self::LEN == 0
+    }
+}
@@ -0,0 +1,39 @@
+use crate::tree::*;
+
+#[test]
+fn for_each() {
+    let tree = TreeEmpty::new();
+    let mut count = 0;
+    HomogeneousTree::<i32>::for_each(&tree, |_| count += 1);
+    assert_eq!(count, 0);
+
+    let tree = TreeEmpty::new().push(1).push(2).push(3).push(4);
+    let mut sum = 0;
+    tree.for_each(|x| sum += x);
+    assert_eq!(sum, 10);
+}
+
+#[test]
+fn for_each_mut() {
+    let mut tree = TreeEmpty::new()
+        .push("a".to_string())
+        .push("b".to_string())
+        .push("c".to_string());
+    tree.for_each_mut(|x| x.push('!'));
+
+    let mut joined = String::new();
+    tree.for_each(|x| joined.push_str(x));
+    assert_eq!(joined, "a!b!c!");
+}
+
+#[test]
+fn subtrees() {
+    let mut leaf = TreeLeaf::new(3);
+    leaf.for_each_mut(|x| *x += 1);
+    assert_eq!(leaf.into_element(), 4);
+
+    let node = TreeNode::from_lr(TreeLeaf::new(1), TreeEmpty);
+    let mut elements = vec![];
+    node.for_each(|x| elements.push(*x));
+    assert_eq!(elements, [1]);
+}
@@ -0,0 +1,2 @@
+mod homogeneous;
+mod st_tree;
@@ -0,0 +1,95 @@
+use crate::tree::*;
+
+macro_rules! push8 {
+    ($tree:expr, $x:expr) => {
+        $tree
+            .push($x)
+            .push($x)
+            .push($x)
+            .push($x)
+            .push($x)
+            .push($x)
+            .push($x)
+            .push($x)
+    };
+}
+
+macro_rules! push64 {
+    ($tree:expr, $x:expr) => {
+        push8!(
+            push8!(
+                push8!(
+                    push8!(push8!(push8!(push8!(push8!($tree, $x), $x), $x), $x), $x),
+                    $x
+                ),
+                $x
+            ),
+            $x
+        )
+    };
+}
+
+fn elements<T: HomogeneousTree<usize>>(tree: &T) -> Vec<usize> {
+    let mut elements = vec![];
+    tree.for_each(|x| elements.push(*x));
+    elements
+}
+
+#[test]
+fn push_len() {
+    let tree = TreeEmpty::new();
+    assert_eq!(tree.len(), 0);
+    assert!(tree.is_empty());
+
+    let tree = tree.push(42);
+    assert_eq!(tree.len(), 1);
+    assert!(!tree.is_empty());
+
+    let tree = tree.push('x').push(true).push("foo");
+    assert_eq!(tree.len(), 4);
+
+    let tree = tree.push(1.5);
+    assert_eq!(tree.len(), 5);
+}
+
+#[test]
+fn push_keeps_order() {
+    let tree = TreeEmpty::new().push(0usize);
+    assert_eq!(elements(&tree), [0]);
+
+    let tree = tree.push(1).push(2);
+    assert_eq!(elements(&tree), [0, 1, 2]);
+
+    let tree = tree.push(3).push(4).push(5).push(6).push(7).push(8);
+    assert_eq!(elements(&tree), (0..9).collect::<Vec<_>>());
+}
+
+#[test]
+fn shape() {
+    let tree = TreeEmpty::new().push(0).push(1).push(2);
+
+    let expected = TreeNode::from_lr(
+        TreeNode::from_lr(
+            TreeEmpty,
+            TreeNode::from_lr(TreeLeaf::new(0), TreeLeaf::new(1)),
+        ),
+        TreeLeaf::new(2),
+    );
+    assert_eq!(tree, expected);
+}
+
+#[test]
+fn depth_is_logarithmic() {
+    fn depth<T: SubTree>(_: &T) -> usize {
+        T::DEPTH
+    }
+
+    let tree = push64!(push64!(push64!(TreeEmpty::new(), 0u8), 0), 0);
+    let tree = push8!(tree, 0);
+    assert_eq!(tree.len(), 200);
+    assert!(depth(&tree) <= 2 * 8);
+
+    let mut count = 0;
+    tree.for_each(|x| count += *x as usize + 1);
+    assert_eq!(count, 200);
+}
@@ -0,0 +1,97 @@
+use crate::type_list::{TNil, TypeList};
+use core::marker::PhantomData;
+
+/// A non-empty type list with the type `Head` in the front followed by the type list `Tail`.
+///
+/// The list only carries types and no values; it is a zero-sized type regardless of its elements.
+///
+/// It is often more convenient to create type lists with the [`type_list`] macro.
+///
+/// [`type_list`]: crate::type_list!
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::type_list::*;
+///
+/// type L = TCons<u32, TCons<bool, TCons<char, TNil>>>;
+/// assert_eq!(L::LEN, 3);
+/// assert_eq!(core::mem::size_of::<L>(), 0);
+/// ```
+pub struct TCons<Head, Tail>
+where
+    Tail: TypeList,
+{
+    phantom: PhantomData<fn() -> (Head, Tail)>,
+}
+
+impl<H, T> TCons<H, T>
+where
+    T: TypeList,
+{
+    /// Creates a value of the zero-sized type list.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<H, T> Default for TCons<H, T>
+where
+    T: TypeList,
+{
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<H, T> Clone for TCons<H, T>
+where
+    T: TypeList,
+{
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<H, T> Copy for TCons<H, T> where T: TypeList {}
+
+impl<H, T> PartialEq for TCons<H, T>
+where
+    T: TypeList,
+{
+    #[inline(always)]
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<H, T> Eq for TCons<H, T> where T: TypeList {}
+
+impl<H, T> core::fmt::Debug for TCons<H, T>
+where
+    T: TypeList,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(core::any::type_name::<Self>())
+    }
+}
+
+impl<H, T> TypeList for TCons<H, T>
+where
+    T: TypeList,
+{
+    type PushBack<Elem> = TCons<H, T::PushBack<Elem>>;
+
+    type Append<Other: TypeList> = TCons<H, T::Append<Other>>;
+
+    type ReverseOnto<Acc: TypeList> = T::ReverseOnto<TCons<H, Acc>>;
+
+    type Reverse = Self::ReverseOnto<TNil>;
+
+    const LEN: usize = 1 + T::LEN;
+}
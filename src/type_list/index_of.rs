@@ -0,0 +1,128 @@
+use crate::type_list::{TCons, TypeList};
+use core::marker::PhantomData;
+
+/// Index witness stating that the searched type is the head of the list.
+///
+/// Index witnesses are inferred by the compiler and are never written explicitly; see [`IndexOf`].
+pub struct Here;
+
+/// Index witness stating that the searched type is in the tail of the list at the position
+/// witnessed by `I`.
+///
+/// Index witnesses are inferred by the compiler and are never written explicitly; see [`IndexOf`].
+pub struct There<I>(PhantomData<I>);
+
+/// A type list which contains the type `T` at the position `INDEX`.
+///
+/// The second generic parameter `I` is an index witness, either [`Here`] or [`There`], which
+/// lets the compiler find the position of `T` without overlapping implementations. It is always
+/// inferred, and is written as `_` in use sites.
+///
+/// Notice that the index witness can be inferred only if `T` appears exactly once in the list.
+/// Otherwise, the inference is ambiguous and the code does not compile.
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::type_list::*;
+/// use orx_meta::type_list;
+///
+/// type L = type_list!(u32, bool, char);
+///
+/// fn index_of<T, L: IndexOf<T, I>, I>() -> usize {
+///     L::INDEX
+/// }
+///
+/// assert_eq!(index_of::<u32, L, _>(), 0);
+/// assert_eq!(index_of::<bool, L, _>(), 1);
+/// assert_eq!(index_of::<char, L, _>(), 2);
+/// ```
+///
+/// Searching a type which does not exist in the list does not compile.
+///
+/// ```compile_fail
+/// use orx_meta::type_list::*;
+/// use orx_meta::type_list;
+///
+/// type L = type_list!(u32, bool, char);
+///
+/// fn index_of<T, L: IndexOf<T, I>, I>() -> usize {
+///     L::INDEX
+/// }
+///
+/// assert_eq!(index_of::<String, L, _>(), 0);
+/// ```
+pub trait IndexOf<T, I>: TypeList {
+    /// Position of the type `T` in the list.
+    const INDEX: usize;
+}
+
+impl<T, Tail> IndexOf<T, Here> for TCons<T, Tail>
+where
+    Tail: TypeList,
+{
+    const INDEX: usize = 0;
+}
+
+impl<T, H, Tail, I> IndexOf<T, There<I>> for TCons<H, Tail>
+where
+    Tail: IndexOf<T, I>,
+{
+    const INDEX: usize = 1 + Tail::INDEX;
+}
+
+/// A type list which contains the type `T`.
+///
+/// This is automatically implemented for all type lists implementing [`IndexOf<T, I>`], and it can
+/// be used as a bound to require existence of a type, such as a capability marker, in the list.
+/// The index witness `I` is always inferred.
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::type_list::*;
+/// use orx_meta::type_list;
+///
+/// struct CanRead;
+/// struct CanWrite;
+/// struct CanDelete;
+///
+/// struct Handle<Capabilities: TypeList>(core::marker::PhantomData<Capabilities>);
+///
+/// impl<C: TypeList> Handle<C> {
+///     fn write<I>(&self) -> &'static str
+///     where
+///         C: Contains<CanWrite, I>,
+///     {
+///         "written"
+///     }
+/// }
+///
+/// let handle = Handle::<type_list!(CanRead, CanWrite)>(Default::default());
+/// assert_eq!(handle.write(), "written");
+/// ```
+///
+/// ```compile_fail
+/// use orx_meta::type_list::*;
+/// use orx_meta::type_list;
+///
+/// struct CanRead;
+/// struct CanWrite;
+///
+/// struct Handle<Capabilities: TypeList>(core::marker::PhantomData<Capabilities>);
+///
+/// impl<C: TypeList> Handle<C> {
+///     fn write<I>(&self) -> &'static str
+///     where
+///         C: Contains<CanWrite, I>,
+///     {
+///         "written"
+///     }
+/// }
+///
+/// let handle = Handle::<type_list!(CanRead)>(Default::default());
+/// handle.write(); // CanWrite does not exist in the list
+/// ```
+pub trait Contains<T, I>: TypeList {}
+
+impl<L, T, I> Contains<T, I> for L where L: IndexOf<T, I> {}
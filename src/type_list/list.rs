@@ -0,0 +1,54 @@
+/// A type-level list of types which carries no values.
+///
+/// There exist two implementations:
+/// * [`TNil`] which is the empty list, and
+/// * [`TCons`] which is a non-empty list with a head type followed by a tail list.
+///
+/// Type lists are useful when metaprogramming only needs types, such as a list of component types to
+/// instantiate or a list of marker types representing capabilities. Non-empty type lists can be mapped
+/// to the corresponding statically-typed queue of values by [`NonEmptyTypeList::Values`].
+///
+/// See also [`Contains`] and [`IndexOf`] to query the types of a list.
+///
+/// [`TNil`]: crate::type_list::TNil
+/// [`TCons`]: crate::type_list::TCons
+/// [`NonEmptyTypeList::Values`]: crate::type_list::NonEmptyTypeList::Values
+/// [`Contains`]: crate::type_list::Contains
+/// [`IndexOf`]: crate::type_list::IndexOf
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::type_list::*;
+/// use orx_meta::type_list;
+///
+/// type L1 = type_list!(u32, bool);
+/// type L2 = type_list!(char, String);
+///
+/// assert_eq!(L1::LEN, 2);
+///
+/// let _: type_list!(u32, bool, char) = <<L1 as TypeList>::PushBack<char>>::new();
+/// let _: type_list!(u32, bool, char, String) = <<L1 as TypeList>::Append<L2>>::new();
+/// let _: type_list!(bool, u32) = <<L1 as TypeList>::Reverse>::new();
+/// ```
+pub trait TypeList {
+    /// Type of the list obtained by adding `Elem` to the back of this list.
+    type PushBack<Elem>: TypeList;
+
+    /// Type of the list obtained by appending all types of the `Other` list to the back of this list.
+    type Append<Other: TypeList>: TypeList;
+
+    /// Type of the list obtained by pushing the types of this list one by one to the front of the
+    /// `Acc` list; i.e., reverse of this list followed by `Acc`.
+    ///
+    /// *This is the building block of [`Reverse`].*
+    ///
+    /// [`Reverse`]: crate::type_list::TypeList::Reverse
+    type ReverseOnto<Acc: TypeList>: TypeList;
+
+    /// Type of the list containing types of this list in reverse order.
+    type Reverse: TypeList;
+
+    /// Number of types in the list.
+    const LEN: usize;
+}
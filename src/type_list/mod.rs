@@ -0,0 +1,15 @@
+#[cfg(test)]
+mod tests;
+
+mod cons;
+mod index_of;
+mod list;
+mod nil;
+mod type_list_macro;
+mod values;
+
+pub use cons::TCons;
+pub use index_of::{Contains, Here, IndexOf, There};
+pub use list::TypeList;
+pub use nil::TNil;
+pub use values::NonEmptyTypeList;
@@ -0,0 +1,28 @@
+use crate::type_list::{TCons, TypeList};
+
+/// The empty type list.
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::type_list::*;
+///
+/// assert_eq!(TNil::LEN, 0);
+///
+/// type L = <TNil as TypeList>::PushBack<u32>;
+/// assert_eq!(L::LEN, 1);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct TNil;
+
+impl TypeList for TNil {
+    type PushBack<Elem> = TCons<Elem, TNil>;
+
+    type Append<Other: TypeList> = Other;
+
+    type ReverseOnto<Acc: TypeList> = Acc;
+
+    type Reverse = TNil;
+
+    const LEN: usize = 0;
+}
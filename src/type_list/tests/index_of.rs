@@ -0,0 +1,35 @@
+use crate::type_list;
+use crate::type_list::*;
+
+fn index_of<T, L: IndexOf<T, I>, I>() -> usize {
+    L::INDEX
+}
+
+fn contains<T, L: Contains<T, I>, I>() -> bool {
+    L::LEN > 0
+}
+
+#[test]
+fn index() {
+    type L = type_list!(u32, bool, char, String);
+    assert_eq!(index_of::<u32, L, _>(), 0);
+    assert_eq!(index_of::<bool, L, _>(), 1);
+    assert_eq!(index_of::<char, L, _>(), 2);
+    assert_eq!(index_of::<String, L, _>(), 3);
+}
+
+#[test]
+fn index_after_reverse() {
+    type L = <type_list!(u32, bool, char) as TypeList>::Reverse;
+    assert_eq!(index_of::<u32, L, _>(), 2);
+    assert_eq!(index_of::<char, L, _>(), 0);
+}
+
+#[test]
+fn contains_marker() {
+    struct Read;
+    struct Write;
+    type L = type_list!(Read, Write);
+    assert!(contains::<Read, L, _>());
+    assert!(contains::<Write, L, _>());
+}
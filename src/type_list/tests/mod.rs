@@ -0,0 +1,3 @@
+mod index_of;
+mod type_list;
+mod values;
@@ -0,0 +1,61 @@
+use crate::type_list;
+use crate::type_list::*;
+use core::any::TypeId;
+
+fn same<A: 'static, B: 'static>() -> bool {
+    TypeId::of::<A>() == TypeId::of::<B>()
+}
+
+#[test]
+fn len() {
+    assert_eq!(TNil::LEN, 0);
+    assert_eq!(<type_list!(u32)>::LEN, 1);
+    assert_eq!(<type_list!(u32, bool, char, String)>::LEN, 4);
+    assert_eq!(
+        core::mem::size_of::<type_list!(u32, bool, char, String)>(),
+        0
+    );
+}
+
+#[test]
+fn push_back() {
+    assert!(same::<<TNil as TypeList>::PushBack<u32>, type_list!(u32)>());
+    assert!(same::<
+        <type_list!(u32, bool) as TypeList>::PushBack<char>,
+        type_list!(u32, bool, char),
+    >());
+}
+
+#[test]
+fn append() {
+    assert!(same::<<TNil as TypeList>::Append<TNil>, TNil>());
+    assert!(same::<
+        <TNil as TypeList>::Append<type_list!(u32)>,
+        type_list!(u32),
+    >());
+    assert!(same::<
+        <type_list!(u32) as TypeList>::Append<TNil>,
+        type_list!(u32),
+    >());
+    assert!(same::<
+        <type_list!(u32, bool) as TypeList>::Append<type_list!(char, String)>,
+        type_list!(u32, bool, char, String),
+    >());
+}
+
+#[test]
+fn reverse() {
+    assert!(same::<<TNil as TypeList>::Reverse, TNil>());
+    assert!(same::<
+        <type_list!(u32) as TypeList>::Reverse,
+        type_list!(u32),
+    >());
+    assert!(same::<
+        <type_list!(u32, bool, char, String) as TypeList>::Reverse,
+        type_list!(String, char, bool, u32),
+    >());
+    assert!(same::<
+        <<type_list!(u32, bool, char) as TypeList>::Reverse as TypeList>::Reverse,
+        type_list!(u32, bool, char),
+    >());
+}
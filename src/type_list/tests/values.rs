@@ -0,0 +1,34 @@
+use crate::queue::*;
+use crate::type_list::*;
+use crate::{queue_of, type_list};
+
+#[test]
+fn values() {
+    let x: <type_list!(u32) as NonEmptyTypeList>::Values = QueueSingle::new(42);
+    let _: queue_of!(u32) = x;
+
+    type L = type_list!(u32, bool, char);
+    let x: <L as NonEmptyTypeList>::Values = Queue::new(42).push(true).push('x');
+    let _: queue_of!(u32, bool, char) = x;
+}
+
+#[test]
+fn builder() {
+    type L = type_list!(u32, bool, char, String);
+
+    let values = L::builder()
+        .push(42)
+        .push(true)
+        .push('x')
+        .push("foo".to_string())
+        .finish();
+    assert_eq!(values.as_tuple(), (&42, &true, &'x', &"foo".to_string()));
+
+    let values = <<L as TypeList>::Reverse>::builder()
+        .push("foo".to_string())
+        .push('x')
+        .push(true)
+        .push(42)
+        .finish();
+    assert_eq!(values.len(), 4);
+}
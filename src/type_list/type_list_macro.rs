@@ -0,0 +1,31 @@
+/// Creates the type list of the given comma-separated types.
+///
+/// Type lists are recursive types such as `TCons<u32, TCons<bool, TCons<char, TNil>>>`, which are
+/// not convenient to hand-write. `type_list!(u32, bool, char)` is an alias for this type.
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::type_list::*;
+/// use orx_meta::type_list;
+///
+/// type L1 = TCons<u32, TCons<bool, TCons<char, TNil>>>;
+/// type L2 = type_list!(u32, bool, char);
+///
+/// // L1 and L2 are aliases for the same type
+/// let list: L2 = L1::new();
+/// assert_eq!(L2::LEN, 3);
+///
+/// type Empty = type_list!();
+/// assert_eq!(Empty::LEN, 0);
+/// ```
+#[macro_export]
+macro_rules! type_list {
+    () => {
+        $crate::type_list::TNil
+    };
+
+    ($t:ty $(, $rest:ty)* $(,)?) => {
+        $crate::type_list::TCons<$t, $crate::type_list!($($rest),*)>
+    };
+}
@@ -0,0 +1,60 @@
+use crate::queue::{Queue, QueueBuilder, QueueSingle, StQueue};
+use crate::type_list::{TCons, TNil, TypeList};
+
+/// A non-empty type list which can be mapped to the statically-typed queue holding one value of each of
+/// its types.
+///
+/// For instance, `Values` of the type list `type_list!(u32, bool, char)` is the queue type
+/// `queue_of!(u32, bool, char)`; i.e., `Queue<u32, Queue<bool, QueueSingle<char>>>`.
+///
+/// This allows to drive a [`QueueBuilder`] from a type list.
+///
+/// # Examples
+///
+/// ```
+/// use orx_meta::queue::*;
+/// use orx_meta::type_list::*;
+/// use orx_meta::{queue_of, type_list};
+///
+/// type Components = type_list!(u32, bool, char);
+///
+/// let values: <Components as NonEmptyTypeList>::Values = Queue::new(42).push(true).push('x');
+/// let values: queue_of!(u32, bool, char) = values;
+///
+/// let built = Components::builder().push(42).push(true).push('x').finish();
+/// assert_eq!(built, values);
+/// ```
+pub trait NonEmptyTypeList: TypeList {
+    /// Type of the statically-typed queue containing one value of each type of the list.
+    type Values: StQueue;
+
+    /// Creates a builder of the statically-typed queue `Self::Values`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_meta::type_list::*;
+    /// use orx_meta::type_list;
+    ///
+    /// let values = <type_list!(u32, String)>::builder()
+    ///     .push(42)
+    ///     .push("foo".to_string())
+    ///     .finish();
+    /// assert_eq!(values.as_tuple(), (&42, &"foo".to_string()));
+    /// ```
+    fn builder() -> QueueBuilder<Self::Values> {
+        QueueBuilder::new()
+    }
+}
+
+impl<H> NonEmptyTypeList for TCons<H, TNil> {
+    type Values = QueueSingle<H>;
+}
+
+impl<H, H2, T> NonEmptyTypeList for TCons<H, TCons<H2, T>>
+where
+    T: TypeList,
+    TCons<H2, T>: NonEmptyTypeList,
+{
+    type Values = Queue<H, <TCons<H2, T> as NonEmptyTypeList>::Values>;
+}